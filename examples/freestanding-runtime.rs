@@ -0,0 +1,34 @@
+//! Freestanding Runtime
+//!
+//! This example shows a freestanding linux application that uses the runtime
+//! of `r_linux::rt` rather than providing its own `_start` entry-point. The
+//! `entrypoint!()` macro provides `_start`, parses the initial stack, and
+//! exits with the return value of `main()`. This application exits with the
+//! number of its arguments.
+//!
+//! Like `freestanding-syscall`, this example is guarded by the `freestanding`
+//! flag and must be compiled with `-nostartfiles`, and we need to provide a
+//! panic-handler (and with unwinding, the exception handler personality
+//! routine).
+
+#![cfg_attr(feature = "unstable", feature(lang_items))]
+
+#![no_main]
+#![no_std]
+
+#[cfg(feature = "unstable")]
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {
+    loop {}
+}
+
+#[panic_handler]
+fn panic_handler(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+fn main(env: &r_linux::rt::Env) -> i32 {
+    env.args().len() as i32
+}
+
+r_linux::entrypoint!(main);
@@ -0,0 +1,588 @@
+//! Directory Capabilities
+//!
+//! A directory file-descriptor grants access to the file-system tree beneath
+//! it. The `Dir` type wraps such a file-descriptor and resolves all paths
+//! relative to it, never allowing path resolution to leave the directory.
+//! That is, absolute paths, `..` components, and symbolic links that would
+//! escape the directory are rejected by the kernel with `EXDEV`. Hence, a
+//! `Dir` can be handed to untrusted code (or used with untrusted paths) to
+//! grant access to a sub-tree of the file-system only.
+//!
+//! Path resolution is confined via `RESOLVE_BENEATH` of `openat2(2)`. System
+//! calls that do not support it are performed relative to the parent
+//! directory of the last path component, which is opened via `openat2(2)`
+//! first. Note that this requires linux-5.6 or newer.
+//!
+//! Paths longer than `PATH_MAX` (including the terminating NUL byte) are
+//! rejected with `ENAMETOOLONG`, as the kernel would do.
+//!
+//! Directory entries can be read via `ReadDir`, which uses a caller-provided
+//! buffer and never allocates memory.
+
+use core::ffi::CStr;
+use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
+use crate::syscall::{api, errno::Errno};
+
+// Maximum length of a path, including the terminating NUL byte.
+const PATH_MAX: usize = 4096;
+
+/// Directory Capability
+///
+/// This type owns a directory file-descriptor and confines all path
+/// resolution to the directory. See the module documentation for details.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Dir {
+    fd: OwnedFd,
+}
+
+// Split a path into its parent directory and last path component. The
+// parent is copied into `buf` and NUL-terminated. Trailing slashes are kept
+// with the last component, and an empty parent is replaced by `.`.
+fn split<'a>(
+    path: &'a CStr,
+    buf: &'a mut [u8; PATH_MAX],
+) -> Result<(&'a CStr, &'a CStr), Errno> {
+    let path = path.to_bytes_with_nul();
+    if path.len() > PATH_MAX {
+        return Err(crate::syscall::errno::ENAMETOOLONG);
+    }
+
+    let s = &path[..path.len() - 1];
+    let end = s.iter().rposition(|v| *v != b'/').map_or(0, |v| v + 1);
+
+    let (parent, name) = match s[..end].iter().rposition(|v| *v == b'/') {
+        None if end == 0 && !s.is_empty() => (&b"/"[..], &b".\0"[..]),
+        None => (&b"."[..], path),
+        Some(0) => (&b"/"[..], &path[1..]),
+        Some(v) => (&s[..v], &path[v + 1..]),
+    };
+
+    buf[..parent.len()].copy_from_slice(parent);
+    buf[parent.len()] = 0;
+
+    // Both are terminated by the only NUL byte they contain.
+    unsafe {
+        Ok((
+            CStr::from_bytes_with_nul_unchecked(&buf[..parent.len() + 1]),
+            CStr::from_bytes_with_nul_unchecked(name),
+        ))
+    }
+}
+
+impl Dir {
+    /// Create Directory Capability from File-Descriptor
+    ///
+    /// Create a new directory capability from an owned file-descriptor. If
+    /// `fd` does not refer to a directory, all operations will fail with
+    /// `ENOTDIR`.
+    pub fn from_fd(fd: OwnedFd) -> Self {
+        Self { fd }
+    }
+
+    /// Open Directory Capability
+    ///
+    /// Open the directory at `path`, which is resolved relative to the
+    /// current working directory of the calling task (or absolute). This is
+    /// the only operation that is not confined by a directory capability,
+    /// and thus is used to create the initial capabilities.
+    pub fn open_ambient(path: &CStr) -> Result<Self, Errno> {
+        let fd = api::openat(
+            api::AT_FDCWD,
+            path,
+            api::O_RDONLY | api::O_DIRECTORY | api::O_CLOEXEC,
+            0,
+        )?;
+
+        Ok(Self::from_fd(fd))
+    }
+
+    /// Return the file-descriptor of this capability and release ownership.
+    pub fn into_fd(self) -> OwnedFd {
+        self.fd
+    }
+
+    fn raw(&self) -> RawFd {
+        self.fd.as_raw()
+    }
+
+    // Open the parent directory of `path` beneath this directory, and return
+    // it together with the last path component of `path`.
+    fn parent<'a>(
+        &self,
+        path: &'a CStr,
+        buf: &'a mut [u8; PATH_MAX],
+    ) -> Result<(OwnedFd, &'a CStr), Errno> {
+        let (parent, name) = split(path, buf)?;
+        let how = api::OpenHow::new(
+            api::O_PATH | api::O_DIRECTORY | api::O_CLOEXEC,
+            0,
+            api::RESOLVE_BENEATH,
+        );
+        let fd = api::openat2(self.raw(), parent, &how)?;
+
+        Ok((fd, name))
+    }
+
+    /// Open File
+    ///
+    /// Open the file at `path` beneath this directory. `flags` takes the
+    /// `O_*` flags and `mode` is the file mode used with `O_CREAT` and
+    /// `O_TMPFILE`, see `syscall::api::openat2()`. `O_CLOEXEC` is always set.
+    pub fn open(&self, path: &CStr, flags: u32, mode: u32) -> Result<OwnedFd, Errno> {
+        let how = api::OpenHow::new(flags | api::O_CLOEXEC, mode, api::RESOLVE_BENEATH);
+        api::openat2(self.raw(), path, &how)
+    }
+
+    /// Open Sub-Directory Capability
+    ///
+    /// Open the directory at `path` beneath this directory, and return it as
+    /// a new directory capability. The new capability is confined to the
+    /// sub-directory, and thus grants narrower access than this capability.
+    pub fn open_dir(&self, path: &CStr) -> Result<Dir, Errno> {
+        self.open(path, api::O_RDONLY | api::O_DIRECTORY, 0).map(Dir::from_fd)
+    }
+
+    /// Get File Status
+    ///
+    /// Retrieve the status of the file at `path` beneath this directory, see
+    /// `syscall::api::statx()`. `flags` can contain `AT_SYMLINK_NOFOLLOW` to
+    /// query a symbolic link in the last path component, rather than its
+    /// target. Other `AT_*` flags are passed to the kernel unchanged.
+    pub fn statx(&self, path: &CStr, flags: u32, mask: u32) -> Result<api::Statx, Errno> {
+        let mut oflags = api::O_PATH;
+        if flags & api::AT_SYMLINK_NOFOLLOW != 0 {
+            oflags |= api::O_NOFOLLOW;
+        }
+
+        let fd = self.open(path, oflags, 0)?;
+        api::statx(fd.as_raw(), Default::default(), flags | api::AT_EMPTY_PATH, mask)
+    }
+
+    /// Read Symbolic Link
+    ///
+    /// Read the target of the symbolic link at `path` beneath this
+    /// directory, see `syscall::api::readlinkat()`. Note that the target is
+    /// returned verbatim, and might point outside of this directory.
+    pub fn readlink(&self, path: &CStr, buf: &mut [u8]) -> Result<usize, Errno> {
+        let mut b = [0; PATH_MAX];
+        let (parent, name) = self.parent(path, &mut b)?;
+
+        api::readlinkat(parent.as_raw(), name, buf)
+    }
+
+    /// Create Directory
+    ///
+    /// Create a new directory at `path` beneath this directory, see
+    /// `syscall::api::mkdirat()`.
+    pub fn mkdir(&self, path: &CStr, mode: u32) -> Result<(), Errno> {
+        let mut b = [0; PATH_MAX];
+        let (parent, name) = self.parent(path, &mut b)?;
+
+        api::mkdirat(parent.as_raw(), name, mode)
+    }
+
+    /// Remove Directory Entry
+    ///
+    /// Remove the directory entry at `path` beneath this directory, see
+    /// `syscall::api::unlinkat()`. `flags` can be 0 or `AT_REMOVEDIR`.
+    pub fn unlink(&self, path: &CStr, flags: u32) -> Result<(), Errno> {
+        let mut b = [0; PATH_MAX];
+        let (parent, name) = self.parent(path, &mut b)?;
+
+        api::unlinkat(parent.as_raw(), name, flags)
+    }
+
+    /// Read Directory Entries
+    ///
+    /// Return a reader for the entries of this directory, which uses `buf`
+    /// to read entries into. See `ReadDir` for details.
+    pub fn entries<'a>(&'a self, buf: &'a mut [u8]) -> ReadDir<'a> {
+        ReadDir::new(self.fd.as_fd(), buf)
+    }
+
+    /// Rename Directory Entry
+    ///
+    /// Move the directory entry at `old_path` beneath this directory to
+    /// `new_path` beneath `new_dir`, which can be this directory as well. See
+    /// `syscall::api::renameat2()` for details and the accepted `flags`.
+    pub fn rename(
+        &self,
+        old_path: &CStr,
+        new_dir: &Dir,
+        new_path: &CStr,
+        flags: u32,
+    ) -> Result<(), Errno> {
+        let mut b0 = [0; PATH_MAX];
+        let mut b1 = [0; PATH_MAX];
+        let (old_parent, old_name) = self.parent(old_path, &mut b0)?;
+        let (new_parent, new_name) = new_dir.parent(new_path, &mut b1)?;
+
+        api::renameat2(
+            old_parent.as_raw(),
+            old_name,
+            new_parent.as_raw(),
+            new_name,
+            flags,
+        )
+    }
+}
+
+impl AsFd for Dir {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Dir {
+    fn from(v: OwnedFd) -> Self {
+        Self::from_fd(v)
+    }
+}
+
+impl From<Dir> for OwnedFd {
+    fn from(v: Dir) -> Self {
+        v.into_fd()
+    }
+}
+
+/// Directory Entry
+///
+/// This is a single `struct linux_dirent64` record as returned by
+/// `getdents64(2)`, borrowed from the buffer it was read into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DirEntry<'a> {
+    ino: u64,
+    off: i64,
+    kind: u8,
+    name: &'a CStr,
+}
+
+impl<'a> DirEntry<'a> {
+    /// Return the inode number of this entry.
+    pub fn ino(&self) -> u64 {
+        self.ino
+    }
+
+    /// Return the opaque directory position following this entry. This is
+    /// only meaningful for `lseek(2)` on the same directory.
+    pub fn offset(&self) -> i64 {
+        self.off
+    }
+
+    /// Return the file type of this entry as one of the `DT_*` constants.
+    /// Note that file-systems can return `DT_UNKNOWN` for any entry.
+    pub fn file_type(&self) -> u8 {
+        self.kind
+    }
+
+    /// Return the name of this entry.
+    pub fn name(&self) -> &'a CStr {
+        self.name
+    }
+}
+
+/// Directory Entry Parser
+///
+/// This iterates over the `struct linux_dirent64` records in a buffer filled
+/// by `getdents64(2)`. The buffer does not need to be aligned, since all
+/// fields are read byte-wise. Iteration stops at the first malformed record.
+#[derive(Clone, Debug)]
+pub struct DirEntries<'a> {
+    buf: &'a [u8],
+}
+
+// Offsets of the fields of `struct linux_dirent64`.
+const DIRENT_INO: usize = 0;
+const DIRENT_OFF: usize = 8;
+const DIRENT_RECLEN: usize = 16;
+const DIRENT_TYPE: usize = 18;
+const DIRENT_NAME: usize = 19;
+
+impl<'a> DirEntries<'a> {
+    /// Create a parser for the records in `buf`, which must be the part of
+    /// the buffer filled in by `getdents64(2)`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for DirEntries<'a> {
+    type Item = DirEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = self.buf;
+        if buf.len() <= DIRENT_NAME {
+            return None;
+        }
+
+        let mut v = [0; 8];
+        let reclen = u16::from_ne_bytes([buf[DIRENT_RECLEN], buf[DIRENT_RECLEN + 1]]) as usize;
+        if reclen <= DIRENT_NAME || reclen > buf.len() {
+            self.buf = &[];
+            return None;
+        }
+
+        let rec = &buf[..reclen];
+        let name = &rec[DIRENT_NAME..];
+        let name = match name.iter().position(|v| *v == 0) {
+            Some(n) => CStr::from_bytes_with_nul(&name[..n + 1]).ok()?,
+            None => {
+                self.buf = &[];
+                return None;
+            }
+        };
+
+        v.copy_from_slice(&rec[DIRENT_INO..DIRENT_INO + 8]);
+        let ino = u64::from_ne_bytes(v);
+        v.copy_from_slice(&rec[DIRENT_OFF..DIRENT_OFF + 8]);
+        let off = i64::from_ne_bytes(v);
+
+        self.buf = &buf[reclen..];
+        Some(DirEntry {
+            ino,
+            off,
+            kind: rec[DIRENT_TYPE],
+            name,
+        })
+    }
+}
+
+/// Directory Reader
+///
+/// This reads the entries of a directory via `getdents64(2)` into a
+/// caller-provided buffer, and refills the buffer whenever all its entries
+/// were consumed. No memory is allocated. Since entries borrow from the
+/// buffer, this cannot implement `Iterator`. Use `next_entry()` instead.
+///
+/// Reading starts at the current position of the directory, which is shared
+/// by all file-descriptors of the same open file-description.
+#[derive(Debug)]
+pub struct ReadDir<'a> {
+    fd: BorrowedFd<'a>,
+    buf: &'a mut [u8],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> ReadDir<'a> {
+    /// Create a directory reader for `fd`, using `buf` to read entries into.
+    /// The buffer must be large enough to hold at least the largest possible
+    /// entry (280 bytes), otherwise reading can fail with `EINVAL`.
+    pub fn new(fd: BorrowedFd<'a>, buf: &'a mut [u8]) -> Self {
+        Self {
+            fd,
+            buf,
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Return the next directory entry, reading more entries into the buffer
+    /// if necessary. `None` is returned at the end of the directory.
+    pub fn next_entry(&mut self) -> Option<Result<DirEntry<'_>, Errno>> {
+        if self.pos >= self.len {
+            self.pos = 0;
+            self.len = match api::getdents64(self.fd.as_raw(), self.buf) {
+                Ok(v) => v,
+                Err(e) => {
+                    self.len = 0;
+                    return Some(Err(e));
+                }
+            };
+        }
+
+        let mut entries = DirEntries::new(&self.buf[self.pos..self.len]);
+        let entry = entries.next();
+        self.pos = self.len - entries.buf.len();
+        entry.map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::syscall::errno;
+
+    fn cstr(v: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(v).unwrap()
+    }
+
+    #[test]
+    fn split_check() {
+        let mut b = [0; PATH_MAX];
+
+        assert_eq!(split(cstr(b"foo\0"), &mut b), Ok((cstr(b".\0"), cstr(b"foo\0"))));
+        assert_eq!(split(cstr(b"a/b/c\0"), &mut b), Ok((cstr(b"a/b\0"), cstr(b"c\0"))));
+        assert_eq!(split(cstr(b"a/b//\0"), &mut b), Ok((cstr(b"a\0"), cstr(b"b//\0"))));
+        assert_eq!(split(cstr(b"/foo\0"), &mut b), Ok((cstr(b"/\0"), cstr(b"foo\0"))));
+        assert_eq!(split(cstr(b"//\0"), &mut b), Ok((cstr(b"/\0"), cstr(b".\0"))));
+        assert_eq!(split(cstr(b"\0"), &mut b), Ok((cstr(b".\0"), cstr(b"\0"))));
+
+        let mut long = [b'a'; PATH_MAX + 1];
+        long[PATH_MAX] = 0;
+        assert!(split(cstr(&long[1..]), &mut b).is_ok());
+        assert_eq!(split(cstr(&long), &mut b), Err(errno::ENAMETOOLONG));
+    }
+
+    #[test]
+    fn dir_check() {
+        //
+        // Verify the basic operations of a directory capability, and that
+        // none of them can escape the directory.
+        //
+
+        let pid = unsafe {
+            crate::syscall::raw::syscall0(crate::syscall::arch::native::nr::GETPID).unwrap()
+        };
+        let mut name = *b"r-linux-dir-check-0000000000\0";
+        for (i, v) in name[18..28].iter_mut().rev().enumerate() {
+            *v = b'0' + (pid / 10usize.pow(i as u32) % 10) as u8;
+        }
+        let name = cstr(&name);
+
+        // Create a scratch directory in `/tmp`, unique to this process.
+        let tmp = Dir::open_ambient(cstr(b"/tmp\0")).unwrap();
+        let _ = tmp.unlink(name, api::AT_REMOVEDIR);
+        tmp.mkdir(name, 0o700).unwrap();
+        let dir = tmp.open_dir(name).unwrap();
+
+        dir.mkdir(cstr(b"sub\0"), 0o700).unwrap();
+        assert_eq!(dir.mkdir(cstr(b"sub/\0"), 0o700), Err(errno::EEXIST));
+
+        let fd = dir.open(cstr(b"sub/file\0"), api::O_WRONLY | api::O_CREAT, 0o600).unwrap();
+        assert_eq!(api::write(fd.as_raw(), b"foo"), Ok(3));
+        drop(fd);
+
+        let sub = dir.open_dir(cstr(b"sub\0")).unwrap();
+        let mut b = [0u8; 3];
+        let fd = sub.open(cstr(b"file\0"), api::O_RDONLY, 0).unwrap();
+        assert_eq!(api::read(fd.as_raw(), &mut b), Ok(3));
+        assert_eq!(&b, b"foo");
+        drop(fd);
+
+        // Escaping via `..`, absolute paths, or symbolic links is rejected.
+        assert_eq!(sub.open(cstr(b"../sub/file\0"), api::O_RDONLY, 0), Err(errno::EXDEV));
+        assert_eq!(sub.open(cstr(b"/etc/passwd\0"), api::O_RDONLY, 0), Err(errno::EXDEV));
+        assert_eq!(sub.mkdir(cstr(b"../foo\0"), 0o700), Err(errno::EXDEV));
+        assert_eq!(sub.mkdir(cstr(b"/foo\0"), 0o700), Err(errno::EXDEV));
+        assert_eq!(sub.unlink(cstr(b"../sub\0"), api::AT_REMOVEDIR), Err(errno::EXDEV));
+
+        let (target, link) = (b"/tmp\0", b"link\0");
+        unsafe {
+            crate::syscall::raw::syscall3(
+                crate::syscall::arch::native::nr::SYMLINKAT,
+                target.as_ptr() as usize,
+                sub.raw() as usize,
+                link.as_ptr() as usize,
+            ).unwrap();
+        }
+        assert_eq!(sub.open_dir(cstr(b"link\0")).map(|_| ()), Err(errno::EXDEV));
+        assert_eq!(sub.mkdir(cstr(b"link/foo\0"), 0o700), Err(errno::EXDEV));
+
+        let mut b = [0u8; 16];
+        assert_eq!(sub.readlink(cstr(b"link\0"), &mut b), Ok(4));
+        assert_eq!(&b[..4], b"/tmp");
+        assert_eq!(dir.readlink(cstr(b"sub/file\0"), &mut b), Err(errno::EINVAL));
+
+        let st = dir.statx(cstr(b"sub/link\0"), api::AT_SYMLINK_NOFOLLOW, api::STATX_TYPE).unwrap();
+        assert_eq!(st.mode & api::S_IFMT, api::S_IFLNK);
+        assert_eq!(dir.statx(cstr(b"sub/link\0"), 0, api::STATX_TYPE), Err(errno::EXDEV));
+        let st = dir.statx(cstr(b"sub/file\0"), 0, api::STATX_TYPE | api::STATX_SIZE).unwrap();
+        assert_eq!(st.mode & api::S_IFMT, api::S_IFREG);
+        assert_eq!(st.size, 3);
+
+        dir.rename(cstr(b"sub/file\0"), &dir, cstr(b"moved\0"), api::RENAME_NOREPLACE).unwrap();
+        assert_eq!(sub.open(cstr(b"file\0"), api::O_RDONLY, 0), Err(errno::ENOENT));
+        assert_eq!(
+            dir.rename(cstr(b"moved\0"), &sub, cstr(b"../moved\0"), 0),
+            Err(errno::EXDEV),
+        );
+        dir.rename(cstr(b"moved\0"), &sub, cstr(b"file\0"), 0).unwrap();
+
+        sub.unlink(cstr(b"file\0"), 0).unwrap();
+        sub.unlink(cstr(b"link\0"), 0).unwrap();
+        drop(sub);
+        dir.unlink(cstr(b"sub\0"), api::AT_REMOVEDIR).unwrap();
+        drop(dir);
+        tmp.unlink(name, api::AT_REMOVEDIR).unwrap();
+    }
+
+    #[test]
+    fn entries_check() {
+        //
+        // Verify that `ReadDir` yields all entries of a directory, refilling
+        // its buffer as needed, even if the buffer is not aligned.
+        //
+
+        let dir = Dir::open_ambient(cstr(b"/proc/self/fdinfo\0")).unwrap();
+        let mut buf = [0u8; 65];
+        let mut entries = dir.entries(&mut buf[1..]);
+        let (mut n, mut dots) = (0, 0);
+
+        while let Some(e) = entries.next_entry() {
+            let e = e.unwrap();
+            assert_ne!(e.ino(), 0);
+            match e.name().to_bytes() {
+                b"." | b".." => {
+                    assert_eq!(e.file_type(), api::DT_DIR);
+                    dots += 1;
+                }
+                v => {
+                    assert!(v.iter().all(|v| v.is_ascii_digit()));
+                    assert_eq!(e.file_type(), api::DT_REG);
+                    n += 1;
+                }
+            }
+        }
+        assert_eq!(dots, 2);
+        assert!(n >= 3);
+        assert!(entries.next_entry().is_none());
+
+        let mut buf = [0u8; 16];
+        let dir = Dir::open_ambient(cstr(b"/proc/self/fdinfo\0")).unwrap();
+        let mut entries = dir.entries(&mut buf);
+        assert_eq!(entries.next_entry().map(|v| v.map(|_| ())), Some(Err(errno::EINVAL)));
+    }
+
+    #[test]
+    fn parse_check() {
+        //
+        // Verify parsing of hand-crafted records, including truncated and
+        // malformed ones.
+        //
+
+        let mut buf = [0u8; 56];
+        buf[0] = 7;
+        buf[8] = 1;
+        buf[16] = 24;
+        buf[18] = api::DT_REG;
+        buf[19..22].copy_from_slice(b"foo");
+        buf[24] = 8;
+        buf[40] = 40;
+        buf[42] = api::DT_DIR;
+        buf[43..48].copy_from_slice(b"bar\0x");
+
+        let mut entries = DirEntries::new(&buf[..24]);
+        let e = entries.next().unwrap();
+        assert_eq!((e.ino(), e.offset(), e.file_type()), (7, 1, api::DT_REG));
+        assert_eq!(e.name().to_bytes(), b"foo");
+        assert!(entries.next().is_none());
+
+        // The second record claims to exceed the buffer.
+        let mut entries = DirEntries::new(&buf);
+        assert_eq!(entries.next().unwrap().name().to_bytes(), b"foo");
+        assert!(entries.next().is_none());
+
+        buf[40] = 24;
+        buf[48] = 0xff;
+        let mut entries = DirEntries::new(&buf[..48]);
+        assert_eq!(entries.next().unwrap().ino(), 7);
+        assert_eq!(entries.next().unwrap().name().to_bytes(), b"bar");
+        assert!(entries.next().is_none());
+
+        // A name without terminating NUL byte is rejected.
+        buf[46] = b'x';
+        buf[47] = b'x';
+        assert_eq!(DirEntries::new(&buf[24..48]).count(), 0);
+    }
+}
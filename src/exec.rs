@@ -0,0 +1,295 @@
+//! Program Execution
+//!
+//! This module provides safe wrappers around `execve(2)` and `execveat(2)`.
+//! The arguments and environment of the new program are passed as
+//! NULL-terminated arrays of pointers to NUL-terminated strings. `ArgBuf`
+//! builds such an array in a buffer provided by the caller, so no memory is
+//! allocated. This makes it suitable to prepare a program execution before
+//! forking, and then execute it in the child.
+//!
+//! Paths are passed as `CStr`, so they are always valid for the kernel. All
+//! functions return the error of the kernel exactly as reported, since they
+//! only return on failure.
+
+use core::ffi::CStr;
+use crate::fd::BorrowedFd;
+use crate::syscall::{api, errno, errno::Errno};
+
+/// Argument Buffer
+///
+/// This builds a NULL-terminated array of pointers to NUL-terminated
+/// strings, as used for the arguments and environment of `execve(2)`, in a
+/// caller-provided buffer. The pointer array is placed at the start of the
+/// buffer, and strings are copied to its end. If the buffer is exhausted,
+/// `E2BIG` is returned, like the kernel does for oversized arguments.
+#[derive(Debug)]
+pub struct ArgBuf<'a> {
+    buf: &'a mut [u8],
+    head: usize,
+    tail: usize,
+    n: usize,
+}
+
+impl<'a> ArgBuf<'a> {
+    /// Create a new, empty argument array in `buf`. The buffer must hold at
+    /// least a single (NULL) pointer, otherwise all pushes fail and
+    /// `as_ptr()` returns NULL.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        let align = core::mem::align_of::<*const u8>();
+        let addr = buf.as_ptr() as usize;
+        let head = core::cmp::min(
+            (align - addr % align) % align,
+            buf.len(),
+        );
+        let tail = buf.len();
+        let mut v = Self { buf, head, tail, n: 0 };
+
+        if v.table_size(0) <= v.tail {
+            v.set(0, core::ptr::null());
+        }
+        v
+    }
+
+    // Return the end offset of a pointer array with `n` entries, including
+    // the terminating NULL.
+    fn table_size(&self, n: usize) -> usize {
+        self.head + (n + 1) * core::mem::size_of::<*const u8>()
+    }
+
+    fn set(&mut self, idx: usize, ptr: *const u8) {
+        unsafe {
+            let table = self.buf.as_mut_ptr().add(self.head) as *mut *const u8;
+            table.add(idx).write(ptr);
+        }
+    }
+
+    // Reserve a pointer slot and `len` bytes of string data, returning the
+    // offset of the latter.
+    fn reserve(&mut self, len: usize) -> Result<usize, Errno> {
+        let tail = self.tail.checked_sub(len).ok_or(errno::E2BIG)?;
+        if self.table_size(self.n + 1) > tail {
+            return Err(errno::E2BIG);
+        }
+        Ok(tail)
+    }
+
+    fn append(&mut self, ptr: *const u8) {
+        self.set(self.n, ptr);
+        self.n += 1;
+        self.set(self.n, core::ptr::null());
+    }
+
+    /// Append a copy of `arg` to the array. `arg` must not contain NUL
+    /// bytes, otherwise `EINVAL` is returned.
+    pub fn push(&mut self, arg: &[u8]) -> Result<(), Errno> {
+        self.push_parts(&[arg])
+    }
+
+    /// Append a copy of the environment variable `name=value` to the array.
+    /// `name` must not contain `=`, and neither must contain NUL bytes,
+    /// otherwise `EINVAL` is returned.
+    pub fn push_var(&mut self, name: &[u8], value: &[u8]) -> Result<(), Errno> {
+        if name.contains(&b'=') {
+            return Err(errno::EINVAL);
+        }
+        self.push_parts(&[name, b"=", value])
+    }
+
+    fn push_parts(&mut self, parts: &[&[u8]]) -> Result<(), Errno> {
+        if parts.iter().any(|v| v.contains(&0)) {
+            return Err(errno::EINVAL);
+        }
+
+        let len = parts.iter().map(|v| v.len()).sum::<usize>() + 1;
+        let tail = self.reserve(len)?;
+
+        let mut pos = tail;
+        for part in parts {
+            self.buf[pos..pos + part.len()].copy_from_slice(part);
+            pos += part.len();
+        }
+        self.buf[pos] = 0;
+
+        self.tail = tail;
+        let ptr = unsafe { self.buf.as_ptr().add(tail) };
+        self.append(ptr);
+        Ok(())
+    }
+
+    /// Append `arg` to the array without copying it. Only space for the
+    /// pointer is needed in the buffer.
+    pub fn push_cstr(&mut self, arg: &'a CStr) -> Result<(), Errno> {
+        self.reserve(0)?;
+        self.append(arg.as_ptr() as *const u8);
+        Ok(())
+    }
+
+    /// Return the number of strings in the array.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Check whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Return an iterator over the strings in the array.
+    pub fn iter(&self) -> impl Iterator<Item = &CStr> + '_ {
+        (0..self.n).map(move |i| unsafe {
+            let table = self.buf.as_ptr().add(self.head) as *const *const u8;
+            CStr::from_ptr(*table.add(i) as *const core::ffi::c_char)
+        })
+    }
+
+    /// Return a pointer to the NULL-terminated pointer array, or NULL if
+    /// the buffer is too small to hold even the terminating NULL.
+    pub fn as_ptr(&self) -> *const *const u8 {
+        if self.table_size(0) > self.buf.len() {
+            core::ptr::null()
+        } else {
+            unsafe { self.buf.as_ptr().add(self.head) as *const *const u8 }
+        }
+    }
+}
+
+/// Execute Program
+///
+/// Replace the program of the calling process with the program at `path`,
+/// passing `argv` as arguments and `envp` as environment. See
+/// `syscall::api::execve()` for details.
+///
+/// This only returns on failure, in which case the error is returned.
+pub fn execve(path: &CStr, argv: &ArgBuf<'_>, envp: &ArgBuf<'_>) -> Errno {
+    unsafe { api::execve(path, argv.as_ptr(), envp.as_ptr()) }
+}
+
+/// Execute Program Relative to Directory
+///
+/// Like `execve()`, but `path` is resolved relative to `dir`. `flags` takes
+/// `AT_SYMLINK_NOFOLLOW` and `AT_EMPTY_PATH`. See
+/// `syscall::api::execveat()` for details.
+pub fn execveat(
+    dir: BorrowedFd<'_>,
+    path: &CStr,
+    argv: &ArgBuf<'_>,
+    envp: &ArgBuf<'_>,
+    flags: u32,
+) -> Errno {
+    unsafe {
+        api::execveat(dir.as_raw(), path, argv.as_ptr(), envp.as_ptr(), flags)
+    }
+}
+
+/// Execute File
+///
+/// Execute the file referred to by `fd` (e.g., a sealed memfd), via
+/// `execveat(2)` with `AT_EMPTY_PATH`. Note that scripts cannot be executed
+/// this way if `fd` has `O_CLOEXEC` set, since the interpreter cannot open
+/// the script via `/dev/fd` after the execution.
+pub fn fexecve(fd: BorrowedFd<'_>, argv: &ArgBuf<'_>, envp: &ArgBuf<'_>) -> Errno {
+    execveat(fd, Default::default(), argv, envp, api::AT_EMPTY_PATH)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::process::Process;
+
+    #[test]
+    fn argbuf_check() {
+        //
+        // Build argument arrays and verify their content, as well as the
+        // handling of invalid arguments and exhausted buffers.
+        //
+
+        let mut b = [0u8; 64];
+        let mut a = ArgBuf::new(&mut b[1..]);
+        assert!(a.is_empty());
+        assert!(!a.as_ptr().is_null());
+        assert_eq!(a.as_ptr() as usize % core::mem::align_of::<usize>(), 0);
+
+        let foo = b"foo\0";
+        let foo = CStr::from_bytes_with_nul(foo).unwrap();
+        a.push_cstr(foo).unwrap();
+        a.push(b"bar").unwrap();
+        a.push_var(b"FOO", b"").unwrap();
+        assert_eq!(a.push(b"b\0r"), Err(errno::EINVAL));
+        assert_eq!(a.push_var(b"F=O", b"bar"), Err(errno::EINVAL));
+        assert_eq!(a.push(&[b'x'; 64]), Err(errno::E2BIG));
+        assert_eq!(a.len(), 3);
+
+        let v: std::vec::Vec<&[u8]> = a.iter().map(|v| v.to_bytes()).collect();
+        assert_eq!(v, [&b"foo"[..], b"bar", b"FOO="]);
+        unsafe { assert!((*a.as_ptr().add(3)).is_null()) };
+
+        while a.push(b"x").is_ok() {}
+        assert!(a.len() > 3);
+        unsafe { assert!((*a.as_ptr().add(a.len())).is_null()) };
+
+        let mut b = [0u8; 4];
+        let mut a = ArgBuf::new(&mut b);
+        assert_eq!(a.push(b""), Err(errno::E2BIG));
+        let mut a = ArgBuf::new(&mut []);
+        assert!(a.as_ptr().is_null());
+        assert_eq!(a.push_cstr(foo), Err(errno::E2BIG));
+    }
+
+    #[test]
+    fn exec_check() {
+        //
+        // Verify failed executions report the error of the kernel, and run
+        // a shell (if available) both via its path and via a memfd script.
+        //
+
+        let mut b0 = [0u8; 256];
+        let mut b1 = [0u8; 256];
+        let mut argv = ArgBuf::new(&mut b0);
+        let mut envp = ArgBuf::new(&mut b1);
+
+        argv.push(b"sh").unwrap();
+        argv.push(b"-c").unwrap();
+        argv.push(b"exit $FOO").unwrap();
+        envp.push_var(b"FOO", b"71").unwrap();
+
+        let path = b"/r-linux/nonexistent\0";
+        let path = CStr::from_bytes_with_nul(path).unwrap();
+        assert_eq!(execve(path, &argv, &envp), errno::ENOENT);
+        let path = b"/proc/self/fdinfo\0";
+        let path = CStr::from_bytes_with_nul(path).unwrap();
+        assert_eq!(execve(path, &argv, &envp), errno::EACCES);
+
+        let sh = b"/bin/sh\0";
+        let sh = CStr::from_bytes_with_nul(sh).unwrap();
+        if api::statx(api::AT_FDCWD, sh, 0, 0).is_err() {
+            return;
+        }
+
+        let p = match unsafe { Process::fork() } {
+            Ok(None) => unsafe { api::exit(execve(sh, &argv, &envp).get() as u32 + 128) },
+            Ok(Some(v)) => v,
+            // Emulators like qemu-user might lack `clone3(2)`.
+            Err(errno::ENOSYS) => return,
+            Err(e) => panic!("cannot fork: {}", e),
+        };
+        assert_eq!(p.wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(71))));
+
+        let name = b"r-linux-exec\0";
+        let name = CStr::from_bytes_with_nul(name).unwrap();
+        // Explicitly request an executable memfd, in case `vm.memfd_noexec`
+        // is set. Kernels prior to linux-6.3 reject `MFD_EXEC` as unknown.
+        let fd = match api::memfd_create(name, api::MFD_ALLOW_SEALING | api::MFD_EXEC) {
+            Err(errno::EINVAL) => api::memfd_create(name, api::MFD_ALLOW_SEALING),
+            v => v,
+        }.unwrap();
+        let script = b"#!/bin/sh\nexit $(($FOO + 1))\n";
+        assert_eq!(api::write(fd.as_raw(), script), Ok(script.len()));
+        api::add_seals(fd.as_raw(), api::Seals::IMMUTABLE).unwrap();
+
+        let p = match unsafe { Process::fork().unwrap() } {
+            None => unsafe { api::exit(fexecve(fd.as_fd(), &argv, &envp).get() as u32 + 128) },
+            Some(v) => v,
+        };
+        assert_eq!(p.wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(72))));
+    }
+}
@@ -0,0 +1,377 @@
+//! File-Descriptor Capabilities
+//!
+//! File-descriptors are the primary capabilities of the linux kernel. They
+//! are plain integers that index the file-descriptor table of a task, and
+//! thus carry no ownership information on their own. This module provides
+//! the `OwnedFd` type, which owns a file-descriptor and closes it when
+//! dropped, as well as the `BorrowedFd` type, which borrows a file-descriptor
+//! for a limited lifetime.
+//!
+//! With the `std` feature enabled, both types can be converted from and into
+//! their counterparts in `std::os::fd`.
+
+use crate::syscall::{api, arch, errno::Errno, raw};
+
+/// Raw File-Descriptor
+///
+/// The kernel uses `int` for file-descriptors in its user-space API.
+/// Negative values are never valid file-descriptors, but are used by some
+/// system calls to signal special conditions (e.g., `AT_FDCWD`).
+pub type RawFd = i32;
+
+/// Owned File-Descriptor
+///
+/// This type owns a file-descriptor and closes it when dropped. The
+/// file-descriptor is never negative.
+///
+/// Closing follows the rules documented for `syscall::api::CLOSE`: the
+/// file-descriptor is always released by the kernel, regardless of the
+/// result of `close(2)`. Hence, the close is never retried, and its result
+/// is ignored. Use `into_raw()` to take over ownership of the
+/// file-descriptor, if you need to close it manually.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct OwnedFd {
+    fd: RawFd,
+}
+
+/// Borrowed File-Descriptor
+///
+/// This type borrows a file-descriptor for the lifetime `'a`. The
+/// file-descriptor is guaranteed to stay open for this lifetime. The
+/// file-descriptor is never negative.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BorrowedFd<'a> {
+    fd: RawFd,
+    _fd: core::marker::PhantomData<&'a OwnedFd>,
+}
+
+/// Borrow File-Descriptor
+///
+/// This trait is implemented by all types that own or borrow a
+/// file-descriptor, and allows borrowing it for the lifetime of the object.
+pub trait AsFd {
+    /// Borrow the file-descriptor of this object.
+    fn as_fd(&self) -> BorrowedFd<'_>;
+}
+
+impl OwnedFd {
+    /// Create Owned File-Descriptor
+    ///
+    /// Create a new owned file-descriptor from a raw file-descriptor. The
+    /// new object takes over ownership, and will close the file-descriptor
+    /// when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file-descriptor that is not owned by anyone
+    /// else. It must not be negative.
+    pub unsafe fn from_raw(fd: RawFd) -> Self {
+        debug_assert!(fd >= 0);
+        Self { fd }
+    }
+
+    /// Return the raw file-descriptor of this object, without releasing
+    /// ownership.
+    pub fn as_raw(&self) -> RawFd {
+        self.fd
+    }
+
+    /// Return the raw file-descriptor of this object and release ownership.
+    /// The caller is responsible to close it.
+    pub fn into_raw(self) -> RawFd {
+        let fd = self.fd;
+        core::mem::forget(self);
+        fd
+    }
+
+    /// Borrow the file-descriptor of this object.
+    pub fn as_fd(&self) -> BorrowedFd<'_> {
+        unsafe { BorrowedFd::borrow_raw(self.fd) }
+    }
+
+    /// Duplicate File-Descriptor
+    ///
+    /// See `BorrowedFd::dup()` for details.
+    pub fn dup(&self) -> Result<OwnedFd, Errno> {
+        self.as_fd().dup()
+    }
+
+    /// Duplicate File-Descriptor to Target
+    ///
+    /// See `BorrowedFd::dup3()` for details.
+    ///
+    /// # Safety
+    ///
+    /// See `BorrowedFd::dup3()`.
+    pub unsafe fn dup3(&self, target: RawFd, flags: u32) -> Result<OwnedFd, Errno> {
+        self.as_fd().dup3(target, flags)
+    }
+}
+
+impl Drop for OwnedFd {
+    fn drop(&mut self) {
+        // The file-descriptor is released regardless of the result, so there
+        // is nothing to do on error. See `syscall::api::CLOSE`.
+        let _ = unsafe {
+            raw::syscall1(
+                arch::native::nr::CLOSE,
+                self.fd as usize,
+            )
+        };
+    }
+}
+
+impl AsFd for OwnedFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        OwnedFd::as_fd(self)
+    }
+}
+
+impl<'a> BorrowedFd<'a> {
+    /// Borrow Raw File-Descriptor
+    ///
+    /// Create a new borrowed file-descriptor from a raw file-descriptor.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open file-descriptor, and must stay open for the
+    /// lifetime `'a`. It must not be negative.
+    pub unsafe fn borrow_raw(fd: RawFd) -> Self {
+        debug_assert!(fd >= 0);
+        Self {
+            fd,
+            _fd: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the raw file-descriptor of this object.
+    pub fn as_raw(&self) -> RawFd {
+        self.fd
+    }
+
+    /// Duplicate File-Descriptor
+    ///
+    /// Create a new file-descriptor that refers to the same open
+    /// file-description as this file-descriptor, using `dup(2)`. The kernel
+    /// picks the lowest unused file-descriptor. Note that the new
+    /// file-descriptor does not have `O_CLOEXEC` set. Use `dup3()` if you
+    /// need it.
+    pub fn dup(&self) -> Result<OwnedFd, Errno> {
+        let r = unsafe {
+            raw::syscall1(
+                arch::native::nr::DUP,
+                self.fd as usize,
+            )
+        };
+
+        r.to_errno_result().map(|v| unsafe { OwnedFd::from_raw(v as RawFd) })
+    }
+
+    /// Duplicate File-Descriptor to Target
+    ///
+    /// Make `target` refer to the same open file-description as this
+    /// file-descriptor, using `dup3(2)`. If `target` is open, it is closed
+    /// first, following the same rules as `close(2)`. `flags` can be 0 or
+    /// `syscall::api::O_CLOEXEC`, which sets `O_CLOEXEC` on `target`.
+    ///
+    /// If `target` equals this file-descriptor, `EINVAL` is returned.
+    ///
+    /// # Safety
+    ///
+    /// `target` must not be negative. If it is open, it must not be owned
+    /// by anyone else, since it is closed by this operation. Ownership of
+    /// `target` is returned to the caller on success.
+    pub unsafe fn dup3(&self, target: RawFd, flags: u32) -> Result<OwnedFd, Errno> {
+        api::dup3(self.fd, target, flags)
+    }
+}
+
+impl<'a> AsFd for BorrowedFd<'a> {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        *self
+    }
+}
+
+impl<T: AsFd + ?Sized> AsFd for &T {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        T::as_fd(self)
+    }
+}
+
+impl<T: AsFd + ?Sized> AsFd for &mut T {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        T::as_fd(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<OwnedFd> for std::os::fd::OwnedFd {
+    fn from(v: OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(v.into_raw()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::os::fd::OwnedFd> for OwnedFd {
+    fn from(v: std::os::fd::OwnedFd) -> Self {
+        unsafe { OwnedFd::from_raw(std::os::fd::IntoRawFd::into_raw_fd(v)) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<BorrowedFd<'a>> for std::os::fd::BorrowedFd<'a> {
+    fn from(v: BorrowedFd<'a>) -> Self {
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(v.as_raw()) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<std::os::fd::BorrowedFd<'a>> for BorrowedFd<'a> {
+    fn from(v: std::os::fd::BorrowedFd<'a>) -> Self {
+        unsafe { BorrowedFd::borrow_raw(std::os::fd::AsRawFd::as_raw_fd(&v)) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::os::fd::AsFd for OwnedFd {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        OwnedFd::as_fd(self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::os::fd::AsFd for BorrowedFd<'a> {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::os::fd::AsRawFd for OwnedFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::os::fd::AsRawFd for BorrowedFd<'a> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::syscall::api;
+
+    fn pipe() -> (OwnedFd, OwnedFd) {
+        let mut p: [u32; 2] = [0, 0];
+
+        unsafe {
+            raw::syscall2(
+                arch::native::nr::PIPE2,
+                p.as_mut_ptr() as usize,
+                api::O_CLOEXEC as usize,
+            ).unwrap();
+
+            (OwnedFd::from_raw(p[0] as RawFd), OwnedFd::from_raw(p[1] as RawFd))
+        }
+    }
+
+    // Return the file-descriptor flags of `fd`, or the error code.
+    fn getfd(fd: RawFd) -> Result<usize, Errno> {
+        unsafe {
+            raw::syscall2(
+                arch::native::nr::FCNTL,
+                fd as usize,
+                1, // F_GETFD
+            ).to_errno_result()
+        }
+    }
+
+    #[test]
+    fn drop_check() {
+        //
+        // Verify `OwnedFd` closes its file-descriptor when dropped, but not
+        // after ownership was released via `into_raw()`.
+        //
+
+        let (r, w) = pipe();
+        let (r_raw, w_raw) = (r.as_raw(), w.as_raw());
+        assert_eq!(getfd(r_raw), Ok(1));
+
+        drop(r);
+        assert_eq!(getfd(r_raw), Err(crate::syscall::errno::EBADF));
+
+        let w_raw2 = w.into_raw();
+        assert_eq!(w_raw, w_raw2);
+        assert_eq!(getfd(w_raw), Ok(1));
+
+        drop(unsafe { OwnedFd::from_raw(w_raw) });
+        assert_eq!(getfd(w_raw), Err(crate::syscall::errno::EBADF));
+    }
+
+    #[test]
+    fn dup_check() {
+        //
+        // Verify `dup()` and `dup3()` create new file-descriptors referring
+        // to the same open file-description.
+        //
+
+        let (r, w) = pipe();
+        let mut b = [0u8; 3];
+
+        let w2 = w.dup().unwrap();
+        assert_ne!(w2.as_raw(), w.as_raw());
+        assert_eq!(getfd(w2.as_raw()), Ok(0));
+        assert_eq!(api::write(w2.as_raw(), b"foo"), Ok(3));
+        assert_eq!(api::read(r.as_raw(), &mut b), Ok(3));
+        assert_eq!(&b, b"foo");
+
+        let target = w2.into_raw();
+        let w3 = unsafe { w.as_fd().dup3(target, api::O_CLOEXEC).unwrap() };
+        assert_eq!(w3.as_raw(), target);
+        assert_eq!(getfd(target), Ok(1));
+        assert_eq!(api::write(w3.as_raw(), b"bar"), Ok(3));
+        assert_eq!(api::read(r.as_raw(), &mut b), Ok(3));
+        assert_eq!(&b, b"bar");
+
+        assert_eq!(
+            unsafe { w.dup3(w.as_raw(), 0) },
+            Err(crate::syscall::errno::EINVAL),
+        );
+
+        let borrowed: &dyn AsFd = &&w3;
+        assert_eq!(borrowed.as_fd().as_raw(), target);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_check() {
+        //
+        // Verify conversions from and into the types of `std::os::fd`.
+        //
+
+        let (r, w) = pipe();
+        let raw = r.as_raw();
+
+        let b: std::os::fd::BorrowedFd<'_> = r.as_fd().into();
+        assert_eq!(std::os::fd::AsRawFd::as_raw_fd(&b), raw);
+        let b: BorrowedFd<'_> = b.into();
+        assert_eq!(b.as_raw(), raw);
+
+        let s: std::os::fd::OwnedFd = r.into();
+        assert_eq!(std::os::fd::AsRawFd::as_raw_fd(&s), raw);
+        let r: OwnedFd = s.into();
+        assert_eq!(r.as_raw(), raw);
+
+        let f = std::fs::File::from(std::os::fd::OwnedFd::from(w));
+        drop(f);
+        assert_eq!(getfd(raw), Ok(1));
+        drop(r);
+        assert_eq!(getfd(raw), Err(crate::syscall::errno::EBADF));
+    }
+}
@@ -0,0 +1,447 @@
+//! Heap Allocator
+//!
+//! This module provides `Allocator`, an implementation of `GlobalAlloc` that
+//! is built solely on the system calls of this crate. It allows using the
+//! `alloc` crate (e.g., `Vec` or `Box`) in freestanding binaries without a
+//! libc. It is opt-in, and must be registered explicitly:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: r_linux::heap::Allocator = r_linux::heap::Allocator::new();
+//! ```
+//!
+//! Small allocations are served from size-class slabs. Each size-class is a
+//! power of two, and keeps a free-list of equally sized blocks. Slabs are
+//! carved from chunks of memory that are requested from the kernel via
+//! `mmap(2)`, or optionally by extending the program break via `brk(2)`.
+//! Chunks are never returned to the kernel, but blocks are reused by later
+//! allocations of the same size-class.
+//!
+//! Large allocations are served by direct anonymous mappings, and are
+//! unmapped when released. Reallocation of large allocations uses
+//! `mremap(2)`, so no data needs to be copied.
+//!
+//! All slab state is protected by a single futex-based lock, so the
+//! allocator can be used by multiple threads.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU32, Ordering};
+use crate::syscall::api;
+
+// Smallest size-class, as base-2 logarithm. Blocks must be large enough to
+// hold a free-list link.
+const CLASS_MIN_SHIFT: u32 = 4;
+// Largest size-class, as base-2 logarithm. Anything larger is mapped
+// directly.
+const CLASS_MAX_SHIFT: u32 = 11;
+const N_CLASSES: usize = (CLASS_MAX_SHIFT - CLASS_MIN_SHIFT + 1) as usize;
+// Size of the chunks requested from the kernel to carve slabs from.
+const CHUNK_SIZE: usize = 64 * 1024;
+// Minimum page size of all supported architectures. All mappings are at
+// least aligned to this.
+const PAGE_SIZE: usize = 4096;
+
+// Futex-based lock. The state is 0 if unlocked, 1 if locked, and 2 if
+// locked with possible waiters.
+struct Lock {
+    state: AtomicU32,
+}
+
+impl Lock {
+    const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(0),
+        }
+    }
+
+    fn lock(&self) {
+        if self.state.compare_exchange(0, 1, Ordering::Acquire, Ordering::Relaxed).is_ok() {
+            return;
+        }
+
+        while self.state.swap(2, Ordering::Acquire) != 0 {
+            // Wake-ups, interruptions, and value changes are all handled by
+            // retrying, so the result can be ignored.
+            let _ = api::futex_wait(&self.state, 2, true);
+        }
+    }
+
+    fn unlock(&self) {
+        if self.state.swap(0, Ordering::Release) == 2 {
+            let _ = api::futex_wake(&self.state, 1, true);
+        }
+    }
+}
+
+// Free block of a size-class, linked into its free-list.
+struct Block {
+    next: *mut Block,
+}
+
+struct State {
+    free: [*mut Block; N_CLASSES],
+    // Start of the unused part of the `brk(2)` arena, or 0 if uninitialized.
+    brk: usize,
+}
+
+/// Heap Allocator
+///
+/// This implements `GlobalAlloc` on top of `mmap(2)`, and optionally
+/// `brk(2)`. See the module documentation for details.
+pub struct Allocator {
+    lock: Lock,
+    state: UnsafeCell<State>,
+    use_brk: bool,
+}
+
+// All access to `state` is serialized via `lock`.
+unsafe impl Sync for Allocator {}
+
+fn round_up(v: usize, align: usize) -> Option<usize> {
+    Some(v.checked_add(align - 1)? & !(align - 1))
+}
+
+// Return the size-class of `layout`, or `None` if it must be mapped
+// directly. Blocks of a size-class are aligned to their size, so the size
+// must cover the alignment as well.
+fn class(layout: &Layout) -> Option<usize> {
+    let size = layout.size().max(layout.align()).max(1 << CLASS_MIN_SHIFT);
+    if size > 1 << CLASS_MAX_SHIFT {
+        None
+    } else {
+        Some((size.next_power_of_two().trailing_zeros() - CLASS_MIN_SHIFT) as usize)
+    }
+}
+
+impl Allocator {
+    const fn with(use_brk: bool) -> Self {
+        Self {
+            lock: Lock::new(),
+            state: UnsafeCell::new(State {
+                free: [core::ptr::null_mut(); N_CLASSES],
+                brk: 0,
+            }),
+            use_brk,
+        }
+    }
+
+    /// Create a new allocator that requests all memory via `mmap(2)`.
+    pub const fn new() -> Self {
+        Self::with(false)
+    }
+
+    /// Create a new allocator that requests slab chunks by extending the
+    /// program break via `brk(2)`, falling back to `mmap(2)` if that fails.
+    ///
+    /// # Safety
+    ///
+    /// The program break is a process-wide resource. The caller must
+    /// guarantee that nothing else in the process uses it (e.g., a libc
+    /// allocator), and that only a single such allocator is used.
+    pub const unsafe fn with_brk() -> Self {
+        Self::with(true)
+    }
+
+    // Request a new chunk of `CHUNK_SIZE` bytes from the kernel. Must be
+    // called with the lock held.
+    unsafe fn chunk(&self, state: &mut State) -> *mut u8 {
+        if self.use_brk {
+            if state.brk == 0 {
+                state.brk = round_up(api::brk(0), PAGE_SIZE).unwrap_or(0);
+            }
+
+            if let Some(end) = state.brk.checked_add(CHUNK_SIZE) {
+                if state.brk != 0 && api::brk(end) >= end {
+                    let v = state.brk;
+                    state.brk = end;
+                    return v as *mut u8;
+                }
+            }
+        }
+
+        api::mmap(
+            core::ptr::null_mut(),
+            CHUNK_SIZE,
+            api::PROT_READ | api::PROT_WRITE,
+            api::MAP_PRIVATE | api::MAP_ANONYMOUS,
+            -1,
+            0,
+        ).unwrap_or(core::ptr::null_mut())
+    }
+
+    unsafe fn alloc_small(&self, class: usize) -> *mut u8 {
+        let size = 1usize << (class as u32 + CLASS_MIN_SHIFT);
+
+        self.lock.lock();
+        let state = &mut *self.state.get();
+
+        if state.free[class].is_null() {
+            let chunk = self.chunk(state);
+            if chunk.is_null() {
+                self.lock.unlock();
+                return core::ptr::null_mut();
+            }
+
+            for i in (0..CHUNK_SIZE / size).rev() {
+                let b = chunk.add(i * size) as *mut Block;
+                (*b).next = state.free[class];
+                state.free[class] = b;
+            }
+        }
+
+        let b = state.free[class];
+        state.free[class] = (*b).next;
+
+        self.lock.unlock();
+        b as *mut u8
+    }
+
+    unsafe fn dealloc_small(&self, ptr: *mut u8, class: usize) {
+        let b = ptr as *mut Block;
+
+        self.lock.lock();
+        let state = &mut *self.state.get();
+        (*b).next = state.free[class];
+        state.free[class] = b;
+        self.lock.unlock();
+    }
+
+    unsafe fn alloc_large(layout: &Layout) -> *mut u8 {
+        let len = match round_up(layout.size(), PAGE_SIZE) {
+            Some(v) => v,
+            None => return core::ptr::null_mut(),
+        };
+        let extra = layout.align().saturating_sub(PAGE_SIZE);
+        let map_len = match len.checked_add(extra) {
+            Some(v) => v,
+            None => return core::ptr::null_mut(),
+        };
+
+        let ptr = match api::mmap(
+            core::ptr::null_mut(),
+            map_len,
+            api::PROT_READ | api::PROT_WRITE,
+            api::MAP_PRIVATE | api::MAP_ANONYMOUS,
+            -1,
+            0,
+        ) {
+            Ok(v) => v,
+            Err(_) => return core::ptr::null_mut(),
+        };
+
+        if extra == 0 {
+            return ptr;
+        }
+
+        // Trim the over-allocation to the requested alignment. This can
+        // fail on architectures with larger pages, in which case the excess
+        // is leaked.
+        let addr = ptr as usize;
+        let start = round_up(addr, layout.align()).unwrap();
+        let _ = api::munmap(ptr, start - addr);
+        let _ = api::munmap((start + len) as *mut u8, addr + map_len - start - len);
+        start as *mut u8
+    }
+
+    unsafe fn dealloc_large(ptr: *mut u8, layout: &Layout) {
+        let len = round_up(layout.size(), PAGE_SIZE).unwrap();
+        let _ = api::munmap(ptr, len);
+    }
+}
+
+impl Default for Allocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for Allocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        match class(&layout) {
+            Some(c) => self.alloc_small(c),
+            None => Self::alloc_large(&layout),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        match class(&layout) {
+            Some(c) => self.dealloc_small(ptr, c),
+            None => Self::dealloc_large(ptr, &layout),
+        }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        match class(&layout) {
+            Some(c) => {
+                let ptr = self.alloc_small(c);
+                if !ptr.is_null() {
+                    core::ptr::write_bytes(ptr, 0, layout.size());
+                }
+                ptr
+            }
+            // Anonymous mappings are always zeroed.
+            None => Self::alloc_large(&layout),
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+
+        match (class(&layout), class(&new_layout)) {
+            (Some(a), Some(b)) if a == b => return ptr,
+            (None, None) if layout.align() <= PAGE_SIZE => {
+                let old_len = round_up(layout.size(), PAGE_SIZE).unwrap();
+                let new_len = match round_up(new_size, PAGE_SIZE) {
+                    Some(v) => v,
+                    None => return core::ptr::null_mut(),
+                };
+
+                return api::mremap(
+                    ptr,
+                    old_len,
+                    new_len,
+                    api::MREMAP_MAYMOVE,
+                    core::ptr::null_mut(),
+                ).unwrap_or(core::ptr::null_mut());
+            }
+            _ => {}
+        }
+
+        let new = self.alloc(new_layout);
+        if !new.is_null() {
+            core::ptr::copy_nonoverlapping(ptr, new, layout.size().min(new_size));
+            self.dealloc(ptr, layout);
+        }
+        new
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    unsafe fn fill(a: &Allocator, size: usize, align: usize, v: u8) -> (*mut u8, Layout) {
+        let layout = Layout::from_size_align(size, align).unwrap();
+        let p = a.alloc(layout);
+        assert!(!p.is_null());
+        assert_eq!(p as usize % align, 0);
+        core::ptr::write_bytes(p, v, size);
+        (p, layout)
+    }
+
+    unsafe fn verify(p: *mut u8, size: usize, v: u8) {
+        assert!(core::slice::from_raw_parts(p, size).iter().all(|b| *b == v));
+    }
+
+    #[test]
+    fn class_check() {
+        let c = |s, a| class(&Layout::from_size_align(s, a).unwrap());
+
+        assert_eq!(c(1, 1), Some(0));
+        assert_eq!(c(16, 8), Some(0));
+        assert_eq!(c(17, 1), Some(1));
+        assert_eq!(c(1, 64), Some(2));
+        assert_eq!(c(2048, 1), Some(N_CLASSES - 1));
+        assert_eq!(c(2049, 1), None);
+        assert_eq!(c(1, 4096), None);
+    }
+
+    #[test]
+    fn alloc_check() {
+        //
+        // Verify small and large allocations of various alignments, as well
+        // as reallocation across size-classes.
+        //
+
+        let a = Allocator::new();
+
+        unsafe {
+            let mut v = std::vec::Vec::new();
+            for (i, (size, align)) in [
+                (1, 1), (8, 8), (24, 8), (100, 4), (2048, 2048), (3000, 16),
+                (70000, 8), (4096, 65536), (1, 1 << 20),
+            ].iter().enumerate() {
+                v.push((fill(&a, *size, *align, i as u8), *size, i as u8));
+            }
+            for ((p, _), size, i) in v.iter() {
+                verify(*p, *size, *i);
+            }
+            for ((p, layout), _, _) in v.into_iter() {
+                a.dealloc(p, layout);
+            }
+
+            let (p, layout) = fill(&a, 10, 8, 0xaa);
+            let p = a.realloc(p, layout, 12);
+            verify(p, 10, 0xaa);
+            let p = a.realloc(p, Layout::from_size_align(12, 8).unwrap(), 5000);
+            verify(p, 10, 0xaa);
+            core::ptr::write_bytes(p, 0xbb, 5000);
+            let p = a.realloc(p, Layout::from_size_align(5000, 8).unwrap(), 1 << 20);
+            verify(p, 5000, 0xbb);
+            let p = a.realloc(p, Layout::from_size_align(1 << 20, 8).unwrap(), 8);
+            verify(p, 8, 0xbb);
+            a.dealloc(p, Layout::from_size_align(8, 8).unwrap());
+
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let p = a.alloc_zeroed(layout);
+            verify(p, 64, 0);
+            a.dealloc(p, layout);
+        }
+    }
+
+    #[test]
+    fn thread_check() {
+        //
+        // Verify concurrent use of a single allocator from multiple threads.
+        //
+
+        static A: Allocator = Allocator::new();
+
+        let threads: std::vec::Vec<_> = (0..8u8).map(|t| {
+            std::thread::spawn(move || unsafe {
+                for i in 0..1000 {
+                    let size = 1 + (i * 7 + t as usize) % 600;
+                    let (p, layout) = fill(&A, size, 8, t);
+                    verify(p, size, t);
+                    A.dealloc(p, layout);
+                }
+            })
+        }).collect();
+
+        for t in threads {
+            t.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn brk_check() {
+        //
+        // Verify the `brk(2)` arena. This must not interfere with the libc
+        // allocator of the test-suite, so it is run in a forked child, which
+        // only uses the raw system call layer.
+        //
+
+        let pid = unsafe { api::fork() }.unwrap();
+        if pid.is_none() {
+            let ok = unsafe {
+                let a = Allocator::with_brk();
+                let start = api::brk(0);
+                let layout = Layout::from_size_align(32, 8).unwrap();
+                let p = a.alloc(layout);
+                let end = api::brk(0);
+
+                !p.is_null()
+                    && p as usize >= start
+                    && (p as usize) < end
+                    && end >= start + CHUNK_SIZE
+            };
+            unsafe { api::exit(if ok { 0 } else { 1 }) };
+        }
+
+        assert_eq!(
+            api::wait4(pid.unwrap(), 0, None),
+            Ok(Some((pid.unwrap(), api::ExitStatus::Exited(0)))),
+        );
+    }
+}
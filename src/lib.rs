@@ -8,4 +8,16 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(all(not(test), feature = "std"))]
+extern crate std;
+
+pub mod dir;
+pub mod exec;
+pub mod fd;
+pub mod heap;
+pub mod mmap;
+pub mod process;
+pub mod rt;
+pub mod spawn;
 pub mod syscall;
+pub mod thread;
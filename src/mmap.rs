@@ -0,0 +1,251 @@
+//! Memory Mappings
+//!
+//! This module provides the `Mapping` type, which owns a memory mapping in
+//! the address space of the calling task and unmaps it when dropped. It is
+//! built on the `mmap(2)` family of system calls in `syscall::api`.
+
+use crate::fd::BorrowedFd;
+use crate::syscall::{api, errno::Errno};
+
+/// Memory Mapping
+///
+/// This type owns a memory mapping of `len()` bytes at `as_ptr()`, and
+/// unmaps it when dropped. Like `close(2)`, `munmap(2)` is never retried and
+/// its result is ignored, since it cannot fail for a valid mapping.
+///
+/// The content of a mapping can change behind the back of the caller (e.g.,
+/// for shared mappings), or cannot be accessed at all (e.g., with
+/// `PROT_NONE`). Hence, access to the content is `unsafe`.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    ptr: core::ptr::NonNull<u8>,
+    len: usize,
+}
+
+// The mapping is plain memory owned by this object, so it can be sent to
+// and shared with other threads.
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl Mapping {
+    /// Map Anonymous Memory
+    ///
+    /// Create a new private anonymous mapping of `len` bytes with the
+    /// protection `prot` (see `PROT_*`). The memory is zeroed.
+    pub fn anonymous(len: usize, prot: u32) -> Result<Self, Errno> {
+        Self::new(None, 0, len, prot, api::MAP_PRIVATE | api::MAP_ANONYMOUS)
+    }
+
+    /// Map File
+    ///
+    /// Create a new mapping of `len` bytes of the file `fd`, starting at
+    /// `offset`, with the protection `prot` (see `PROT_*`). `flags` takes the
+    /// `MAP_*` flags, and must contain one of `MAP_SHARED`, `MAP_PRIVATE`, or
+    /// `MAP_SHARED_VALIDATE`.
+    ///
+    /// `MAP_FIXED` is rejected with `EINVAL`, since it can replace existing
+    /// mappings. Use `MAP_FIXED_NOREPLACE` instead, or `syscall::api::mmap()`
+    /// followed by `from_raw()`.
+    pub fn map(
+        fd: BorrowedFd<'_>,
+        offset: i64,
+        len: usize,
+        prot: u32,
+        flags: u32,
+    ) -> Result<Self, Errno> {
+        Self::new(Some(fd), offset, len, prot, flags)
+    }
+
+    fn new(
+        fd: Option<BorrowedFd<'_>>,
+        offset: i64,
+        len: usize,
+        prot: u32,
+        flags: u32,
+    ) -> Result<Self, Errno> {
+        if flags & api::MAP_FIXED != 0 {
+            return Err(crate::syscall::errno::EINVAL);
+        }
+
+        let fd = fd.map_or(-1, |v| v.as_raw());
+        let ptr = unsafe {
+            api::mmap(core::ptr::null_mut(), len, prot, flags, fd, offset)?
+        };
+
+        Ok(unsafe { Self::from_raw(ptr, len) })
+    }
+
+    /// Create Mapping from Raw Parts
+    ///
+    /// Create a new mapping object that takes over ownership of the mapping
+    /// of `len` bytes at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be the non-null start address of a mapping of `len` bytes
+    /// that is not owned by anyone else.
+    pub unsafe fn from_raw(ptr: *mut u8, len: usize) -> Self {
+        Self {
+            ptr: core::ptr::NonNull::new_unchecked(ptr),
+            len,
+        }
+    }
+
+    /// Return the start address and length of this mapping and release
+    /// ownership. The caller is responsible to unmap it.
+    pub fn into_raw(self) -> (*mut u8, usize) {
+        let v = (self.ptr.as_ptr(), self.len);
+        core::mem::forget(self);
+        v
+    }
+
+    /// Return the start address of this mapping.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Return the length of this mapping in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether this mapping is empty. This is never the case for
+    /// mappings created by this type.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the content of this mapping.
+    ///
+    /// # Safety
+    ///
+    /// The mapping must be readable, and its content must not be modified
+    /// for the lifetime of the returned slice (e.g., via a shared mapping of
+    /// the same file).
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.ptr.as_ptr(), self.len)
+    }
+
+    /// Return the content of this mapping as mutable slice.
+    ///
+    /// # Safety
+    ///
+    /// The mapping must be readable and writable, and its content must not
+    /// be accessed by anyone else for the lifetime of the returned slice.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len)
+    }
+
+    /// Change the protection of this mapping to `prot` (see `PROT_*`).
+    pub fn protect(&mut self, prot: u32) -> Result<(), Errno> {
+        unsafe { api::mprotect(self.ptr.as_ptr(), self.len, prot) }
+    }
+
+    /// Resize this mapping to `len` bytes. If `may_move` is true, the
+    /// mapping can be moved to a new address, otherwise it is resized in
+    /// place or `ENOMEM` is returned.
+    pub fn remap(&mut self, len: usize, may_move: bool) -> Result<(), Errno> {
+        let flags = if may_move { api::MREMAP_MAYMOVE } else { 0 };
+        let ptr = unsafe {
+            api::mremap(self.ptr.as_ptr(), self.len, len, flags, core::ptr::null_mut())?
+        };
+
+        self.ptr = unsafe { core::ptr::NonNull::new_unchecked(ptr) };
+        self.len = len;
+        Ok(())
+    }
+
+    /// Give advice about the use of this mapping (see `MADV_*`).
+    ///
+    /// # Safety
+    ///
+    /// See `syscall::api::madvise()`.
+    pub unsafe fn advise(&self, advice: u32) -> Result<(), Errno> {
+        api::madvise(self.ptr.as_ptr(), self.len, advice)
+    }
+
+    /// Lock the pages of this mapping into memory. `flags` can be 0 or
+    /// `MLOCK_ONFAULT`.
+    pub fn lock(&self, flags: u32) -> Result<(), Errno> {
+        api::mlock2(self.ptr.as_ptr(), self.len, flags)
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        let _ = unsafe { api::munmap(self.ptr.as_ptr(), self.len) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::ffi::CStr;
+    use crate::syscall::errno;
+
+    #[test]
+    fn anonymous_check() {
+        //
+        // Verify anonymous mappings, including resizing and unmap on drop.
+        //
+
+        let mut m = Mapping::anonymous(8192, api::PROT_READ | api::PROT_WRITE).unwrap();
+        assert_eq!(m.len(), 8192);
+        assert!(unsafe { m.as_slice() }.iter().all(|v| *v == 0));
+        unsafe { m.as_mut_slice()[8191] = 71 };
+
+        m.remap(4 * 8192, true).unwrap();
+        assert_eq!(m.len(), 4 * 8192);
+        assert_eq!(unsafe { m.as_slice() }[8191], 71);
+
+        unsafe { m.advise(api::MADV_DONTNEED).unwrap() };
+        assert_eq!(unsafe { m.as_slice() }[8191], 0);
+
+        m.protect(api::PROT_READ).unwrap();
+        match m.remap(8 * 8192, false) {
+            Ok(()) => assert_eq!(m.len(), 8 * 8192),
+            Err(e) => assert_eq!((e, m.len()), (errno::ENOMEM, 4 * 8192)),
+        }
+
+        // Locking can fail due to resource limits, so ignore the result.
+        let _ = m.lock(api::MLOCK_ONFAULT);
+
+        let (ptr, len) = m.into_raw();
+        let m = unsafe { Mapping::from_raw(ptr, len) };
+        assert_eq!((m.as_ptr(), m.len()), (ptr, len));
+        drop(m);
+
+        assert_eq!(Mapping::anonymous(0, api::PROT_READ), Err(errno::EINVAL));
+    }
+
+    #[test]
+    fn file_check() {
+        //
+        // Verify shared file mappings, and the offset handling of `mmap()`.
+        //
+
+        let name = b"r-linux-mmap\0";
+        let name = CStr::from_bytes_with_nul(name).unwrap();
+        let fd = api::memfd_create(name, api::MFD_CLOEXEC).unwrap();
+        assert_eq!(api::pwrite64(fd.as_raw(), b"foobar", 4096), Ok(6));
+
+        let prot = api::PROT_READ | api::PROT_WRITE;
+        let mut m = Mapping::map(fd.as_fd(), 4096, 4096, prot, api::MAP_SHARED).unwrap();
+        assert_eq!(unsafe { &m.as_slice()[..6] }, b"foobar");
+        unsafe { m.as_mut_slice()[..3].copy_from_slice(b"bar") };
+        drop(m);
+
+        let mut b = [0u8; 6];
+        assert_eq!(api::pread64(fd.as_raw(), &mut b, 4096), Ok(6));
+        assert_eq!(&b, b"barbar");
+
+        assert_eq!(
+            Mapping::map(fd.as_fd(), 1, 4096, prot, api::MAP_SHARED),
+            Err(errno::EINVAL),
+        );
+        assert_eq!(
+            Mapping::map(fd.as_fd(), 0, 4096, prot, api::MAP_SHARED | api::MAP_FIXED),
+            Err(errno::EINVAL),
+        );
+    }
+}
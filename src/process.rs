@@ -0,0 +1,250 @@
+//! Process Capabilities
+//!
+//! A PID can be reused by the kernel as soon as the process it referred to
+//! was reaped. Hence, any operation on a process via its PID races with
+//! the process exiting and a new process taking over its PID. A pidfd is a
+//! file-descriptor that refers to a process and keeps referring to it, even
+//! after its PID was reused. The `Process` type wraps such a pidfd and
+//! performs all operations via it.
+//!
+//! Pidfds require linux-5.3 or newer, waiting via pidfds linux-5.4, and
+//! `Process::info()` linux-6.13.
+
+use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
+use crate::syscall::{api, errno::Errno};
+
+/// Process Capability
+///
+/// This type owns a pidfd and performs all operations on the process it
+/// refers to via the pidfd. See the module documentation for details.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Process {
+    fd: OwnedFd,
+}
+
+impl Process {
+    /// Create Process Capability from File-Descriptor
+    ///
+    /// Create a new process capability from an owned pidfd. If `fd` is not
+    /// a pidfd, all operations will fail with `EBADF` or `EINVAL`.
+    pub fn from_fd(fd: OwnedFd) -> Self {
+        Self { fd }
+    }
+
+    /// Open Process
+    ///
+    /// Open a new process capability for the process `pid`. `flags` takes
+    /// `PIDFD_NONBLOCK` and `PIDFD_THREAD`. Note that this races with the
+    /// process exiting and its PID being reused, unless `pid` is a child of
+    /// the calling process that was not reaped, yet.
+    pub fn open(pid: api::Pid, flags: u32) -> Result<Self, Errno> {
+        api::pidfd_open(pid, flags).map(Self::from_fd)
+    }
+
+    /// Fork Process
+    ///
+    /// Fork the calling process like `syscall::api::fork()`, but return a
+    /// process capability for the child to the parent. The capability is
+    /// created atomically with the child via `CLONE_PIDFD`, so it cannot
+    /// race with the child exiting. The child gets `None`.
+    ///
+    /// # Safety
+    ///
+    /// See `syscall::api::fork()`. In multi-threaded processes, the child
+    /// must only call async-signal-safe functions.
+    pub unsafe fn fork() -> Result<Option<Self>, Errno> {
+        let mut fd: i32 = -1;
+        let args = api::CloneArgs {
+            flags: api::CLONE_PIDFD,
+            pidfd: &mut fd as *mut i32 as u64,
+            exit_signal: api::SIGCHLD as u64,
+            ..Default::default()
+        };
+
+        Ok(api::clone3(&args)?.map(|_| Self::from_fd(OwnedFd::from_raw(fd))))
+    }
+
+    /// Return the pidfd of this process and release ownership.
+    pub fn into_fd(self) -> OwnedFd {
+        self.fd
+    }
+
+    fn raw(&self) -> RawFd {
+        self.fd.as_raw()
+    }
+
+    /// Return the PID of this process in the PID namespace of the calling
+    /// process. See `info()` for requirements.
+    pub fn pid(&self) -> Result<api::Pid, Errno> {
+        self.info(api::PIDFD_INFO_PID).map(|v| v.pid as api::Pid)
+    }
+
+    /// Return information about this process. `mask` takes the
+    /// `PIDFD_INFO_*` flags of the requested fields. See
+    /// `syscall::api::pidfd_get_info()` for details.
+    pub fn info(&self, mask: u64) -> Result<api::PidfdInfo, Errno> {
+        api::pidfd_get_info(self.raw(), mask)
+    }
+
+    /// Send the signal `sig` to this process. `flags` takes the
+    /// `PIDFD_SIGNAL_*` flags.
+    pub fn send_signal(&self, sig: u32, flags: u32) -> Result<(), Errno> {
+        api::pidfd_send_signal(self.raw(), sig, flags)
+    }
+
+    /// Return a duplicate of the file-descriptor `target` of this process.
+    /// The caller needs ptrace access to the process.
+    pub fn get_fd(&self, target: RawFd) -> Result<OwnedFd, Errno> {
+        api::pidfd_getfd(self.raw(), target, 0)
+    }
+
+    /// Wait for Process
+    ///
+    /// Wait for this process to change state, and return the new state.
+    /// This process must be a child of the calling process. `options` takes
+    /// the `W*` flags (e.g., `WEXITED`), and `rusage` receives the resource
+    /// usage of the process. See `syscall::api::waitid()` for details.
+    ///
+    /// If the pidfd is non-blocking (see `PIDFD_NONBLOCK`), this fails with
+    /// `EAGAIN` rather than waiting.
+    pub fn wait(
+        &self,
+        options: u32,
+        rusage: Option<&mut api::Rusage>,
+    ) -> Result<Option<api::ExitStatus>, Errno> {
+        match api::waitid(api::P_PIDFD, self.raw() as u32, options, rusage)? {
+            None => Ok(None),
+            Some(v) => v.exit_status().map(Some).ok_or(crate::syscall::errno::EINVAL),
+        }
+    }
+
+    /// Check whether this process has exited, without reaping it. This
+    /// process must be a child of the calling process.
+    pub fn has_exited(&self) -> Result<bool, Errno> {
+        let options = api::WEXITED | api::WNOHANG | api::WNOWAIT;
+
+        match self.wait(options, None) {
+            Ok(v) => Ok(v.is_some()),
+            Err(crate::syscall::errno::EAGAIN) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl AsFd for Process {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl From<OwnedFd> for Process {
+    fn from(v: OwnedFd) -> Self {
+        Self::from_fd(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::syscall::errno;
+
+    // Block the calling task forever, without relying on any libc state.
+    fn block() -> ! {
+        let v = core::sync::atomic::AtomicU32::new(0);
+        loop {
+            let _ = api::futex_wait(&v, 0, true);
+        }
+    }
+
+    #[test]
+    fn wait_check() {
+        //
+        // Fork a child that exits right away, and verify its exit can be
+        // observed via its process capability.
+        //
+
+        let p = match unsafe { Process::fork() } {
+            Ok(None) => unsafe { api::exit(71) },
+            Ok(Some(v)) => v,
+            // Emulators like qemu-user might lack `clone3(2)`.
+            Err(errno::ENOSYS) => return,
+            Err(e) => panic!("cannot fork: {}", e),
+        };
+
+        assert_eq!(
+            p.wait(api::WEXITED | api::WNOWAIT, None),
+            Ok(Some(api::ExitStatus::Exited(71))),
+        );
+        assert!(p.has_exited().unwrap());
+
+        let mut ru = api::Rusage::default();
+        assert_eq!(
+            p.wait(api::WEXITED, Some(&mut ru)),
+            Ok(Some(api::ExitStatus::Exited(71))),
+        );
+        assert!(ru.maxrss > 0);
+        assert_eq!(p.wait(api::WEXITED, None), Err(errno::ECHILD));
+    }
+
+    #[test]
+    fn signal_check() {
+        //
+        // Fork a child that blocks, verify it is alive, and kill it via its
+        // process capability.
+        //
+
+        let p = match unsafe { Process::fork() } {
+            Ok(None) => block(),
+            Ok(Some(v)) => v,
+            // Emulators like qemu-user might lack `clone3(2)`.
+            Err(errno::ENOSYS) => return,
+            Err(e) => panic!("cannot fork: {}", e),
+        };
+
+        assert!(!p.has_exited().unwrap());
+        match p.pid() {
+            Ok(v) => assert!(v > 0),
+            Err(e) => assert_eq!(e, errno::ENOTTY),
+        }
+
+        p.send_signal(api::SIGKILL, 0).unwrap();
+        assert_eq!(
+            p.wait(api::WEXITED, None),
+            Ok(Some(api::ExitStatus::Killed(api::SIGKILL))),
+        );
+        assert_eq!(p.send_signal(api::SIGKILL, 0), Err(errno::ESRCH));
+    }
+
+    #[test]
+    fn getfd_check() {
+        //
+        // Open a process capability for the calling process and duplicate
+        // one of its file-descriptors via it.
+        //
+
+        let pid = unsafe {
+            crate::syscall::raw::syscall0(
+                crate::syscall::arch::native::nr::GETPID,
+            )
+        }.unwrap() as api::Pid;
+
+        let p = Process::open(pid, 0).unwrap();
+        let name = b"r-linux-process\0";
+        let name = core::ffi::CStr::from_bytes_with_nul(name).unwrap();
+        let fd = api::memfd_create(name, api::MFD_CLOEXEC).unwrap();
+
+        let dup = p.get_fd(fd.as_raw()).unwrap();
+        assert_ne!(dup.as_raw(), fd.as_raw());
+        assert_eq!(api::write(dup.as_raw(), b"foo"), Ok(3));
+        assert_eq!(api::pread64(fd.as_raw(), &mut [0; 3], 0), Ok(3));
+
+        assert_eq!(p.wait(api::WEXITED, None), Err(errno::ECHILD));
+        if let Ok(v) = p.info(api::PIDFD_INFO_PID) {
+            assert_eq!(v.pid as api::Pid, pid);
+            assert_eq!(v.mask & api::PIDFD_INFO_PID, api::PIDFD_INFO_PID);
+        }
+
+        assert_eq!(core::mem::size_of::<api::Siginfo>(), 128);
+        assert_eq!(core::mem::size_of::<api::PidfdInfo>(), api::PIDFD_INFO_SIZE_VER0);
+    }
+}
@@ -0,0 +1,587 @@
+//! Process Runtime
+//!
+//! When the kernel starts a new program, it passes the program arguments,
+//! the environment, and the auxiliary vector on the initial stack. This
+//! module provides `Env` to access this information, as well as (with the
+//! `freestanding` feature) a minimal runtime to start freestanding
+//! binaries.
+//!
+//! The initial stack is laid out as follows (one word per entry):
+//!
+//! ```text
+//! argc
+//! argv[0], ..., argv[argc - 1], NULL
+//! envp[0], ..., NULL
+//! auxv[0].key, auxv[0].value, ..., AT_NULL, 0
+//! ```
+//!
+//! The runtime is registered via the `entrypoint!()` macro, which provides
+//! the `_start` symbol for the target architecture. It aligns the stack,
+//! parses the initial stack into `Env`, runs the given main function, and
+//! exits with its return value:
+//!
+//! ```ignore
+//! fn main(env: &r_linux::rt::Env) -> i32 {
+//!     env.args().count() as i32
+//! }
+//!
+//! r_linux::entrypoint!(main);
+//! ```
+//!
+//! Such binaries must be linked without the startup files of the C library
+//! (e.g., via `-C link-arg=-nostartfiles`).
+
+use core::ffi::CStr;
+
+/// End of the auxiliary vector.
+pub const AT_NULL: usize = 0;
+/// Entry to be ignored.
+pub const AT_IGNORE: usize = 1;
+/// File-descriptor of the program.
+pub const AT_EXECFD: usize = 2;
+/// Address of the program headers of the program.
+pub const AT_PHDR: usize = 3;
+/// Size of a program header entry.
+pub const AT_PHENT: usize = 4;
+/// Number of program headers.
+pub const AT_PHNUM: usize = 5;
+/// System page size.
+pub const AT_PAGESZ: usize = 6;
+/// Base address of the program interpreter.
+pub const AT_BASE: usize = 7;
+/// Flags.
+pub const AT_FLAGS: usize = 8;
+/// Entry point of the program.
+pub const AT_ENTRY: usize = 9;
+/// Program is not ELF.
+pub const AT_NOTELF: usize = 10;
+/// Real user ID.
+pub const AT_UID: usize = 11;
+/// Effective user ID.
+pub const AT_EUID: usize = 12;
+/// Real group ID.
+pub const AT_GID: usize = 13;
+/// Effective group ID.
+pub const AT_EGID: usize = 14;
+/// Address of a string identifying the CPU.
+pub const AT_PLATFORM: usize = 15;
+/// Architecture-dependent CPU capabilities.
+pub const AT_HWCAP: usize = 16;
+/// Frequency of `times(2)`.
+pub const AT_CLKTCK: usize = 17;
+/// Program runs in secure mode (e.g., setuid), and must treat its
+/// environment as untrusted.
+pub const AT_SECURE: usize = 23;
+/// Address of a string identifying the real platform.
+pub const AT_BASE_PLATFORM: usize = 24;
+/// Address of 16 random bytes.
+pub const AT_RANDOM: usize = 25;
+/// Extended architecture-dependent CPU capabilities.
+pub const AT_HWCAP2: usize = 26;
+/// Address of the path used to execute the program.
+pub const AT_EXECFN: usize = 31;
+/// Address of the vsyscall entry point `__kernel_vsyscall` (x86 only).
+pub const AT_SYSINFO: usize = 32;
+/// Address of the vDSO.
+pub const AT_SYSINFO_EHDR: usize = 33;
+/// Minimal stack size for signal delivery.
+pub const AT_MINSIGSTKSZ: usize = 51;
+
+/// Process Environment
+///
+/// This provides access to the program arguments, the environment, and the
+/// auxiliary vector, as passed by the kernel on the initial stack. All data
+/// lives on the initial stack, and thus has `'static` lifetime.
+#[derive(Clone, Copy, Debug)]
+pub struct Env {
+    argc: usize,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    auxv: *const usize,
+}
+
+/// Program Arguments
+///
+/// This iterates over the program arguments of an `Env`.
+#[derive(Clone, Debug)]
+pub struct Args {
+    pos: *const *const u8,
+    end: *const *const u8,
+}
+
+/// Environment Variables
+///
+/// This iterates over the environment variables of an `Env`, each in the
+/// form `NAME=value`.
+#[derive(Clone, Debug)]
+pub struct Vars {
+    pos: *const *const u8,
+}
+
+/// Auxiliary Vector Entry
+///
+/// This is a decoded entry of the auxiliary vector. Entries without a
+/// dedicated variant are returned as `Other`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Aux {
+    /// See `AT_PHDR`.
+    Phdr(*const u8),
+    /// See `AT_PHENT`.
+    Phent(usize),
+    /// See `AT_PHNUM`.
+    Phnum(usize),
+    /// See `AT_PAGESZ`.
+    PageSize(usize),
+    /// See `AT_BASE`.
+    Base(*const u8),
+    /// See `AT_ENTRY`.
+    Entry(*const u8),
+    /// See `AT_UID`.
+    Uid(u32),
+    /// See `AT_EUID`.
+    Euid(u32),
+    /// See `AT_GID`.
+    Gid(u32),
+    /// See `AT_EGID`.
+    Egid(u32),
+    /// See `AT_PLATFORM`.
+    Platform(&'static CStr),
+    /// See `AT_HWCAP`.
+    HwCap(usize),
+    /// See `AT_CLKTCK`.
+    ClkTck(usize),
+    /// See `AT_SECURE`.
+    Secure(bool),
+    /// See `AT_RANDOM`.
+    Random(&'static [u8; 16]),
+    /// See `AT_HWCAP2`.
+    HwCap2(usize),
+    /// See `AT_EXECFN`.
+    ExecFn(&'static CStr),
+    /// See `AT_SYSINFO`.
+    Sysinfo(*const u8),
+    /// See `AT_SYSINFO_EHDR`.
+    SysinfoEhdr(*const u8),
+    /// See `AT_MINSIGSTKSZ`.
+    MinSigStkSz(usize),
+    /// Any other entry, with its key and value.
+    Other(usize, usize),
+}
+
+/// Auxiliary Vector
+///
+/// This iterates over the entries of the auxiliary vector of an `Env`.
+#[derive(Clone, Debug)]
+pub struct Auxv {
+    pos: *const usize,
+}
+
+// Like `CStr::from_ptr()`, but does not rely on `strlen()` to be provided,
+// since it is not available to freestanding binaries. The volatile read
+// prevents the compiler from turning the loop back into a `strlen()` call.
+unsafe fn cstr(ptr: *const u8) -> &'static CStr {
+    let mut n = 0;
+    while core::ptr::read_volatile(ptr.add(n)) != 0 {
+        n += 1;
+    }
+    CStr::from_bytes_with_nul_unchecked(core::slice::from_raw_parts(ptr, n + 1))
+}
+
+impl Env {
+    /// Parse Initial Stack
+    ///
+    /// Create a new environment from the initial stack `sp`, as passed by
+    /// the kernel to the entry point of a program.
+    ///
+    /// # Safety
+    ///
+    /// `sp` must point to a valid initial stack, as described in the module
+    /// documentation, which must never be modified or released.
+    pub unsafe fn from_stack(sp: *const usize) -> Self {
+        let argc = *sp;
+        let argv = sp.add(1) as *const *const u8;
+        let envp = argv.add(argc + 1);
+
+        let mut p = envp;
+        while !(*p).is_null() {
+            p = p.add(1);
+        }
+
+        Self {
+            argc,
+            argv,
+            envp,
+            auxv: p.add(1) as *const usize,
+        }
+    }
+
+    /// Return an iterator over the program arguments.
+    pub fn args(&self) -> Args {
+        Args {
+            pos: self.argv,
+            end: unsafe { self.argv.add(self.argc) },
+        }
+    }
+
+    /// Return an iterator over the environment variables.
+    pub fn vars(&self) -> Vars {
+        Vars { pos: self.envp }
+    }
+
+    /// Return the value of the environment variable `name`, or `None` if it
+    /// is not set. If it is set multiple times, the first value is returned.
+    pub fn var(&self, name: &[u8]) -> Option<&'static [u8]> {
+        // Compare manually, since freestanding binaries might lack `memcmp`.
+        self.vars().find_map(|v| {
+            let v = v.to_bytes();
+            match v.get(name.len()) {
+                Some(b'=') if v.iter().zip(name).all(|(a, b)| a == b) => {
+                    Some(&v[name.len() + 1..])
+                },
+                _ => None,
+            }
+        })
+    }
+
+    /// Return an iterator over the auxiliary vector.
+    pub fn auxv(&self) -> Auxv {
+        Auxv { pos: self.auxv }
+    }
+
+    /// Return the raw value of the auxiliary vector entry `key`, or `None`
+    /// if there is no such entry.
+    pub fn aux(&self, key: usize) -> Option<usize> {
+        let mut p = self.auxv;
+
+        unsafe {
+            while *p != AT_NULL {
+                if *p == key {
+                    return Some(*p.add(1));
+                }
+                p = p.add(2);
+            }
+        }
+
+        None
+    }
+
+    /// Return the system page size, as reported via `AT_PAGESZ`.
+    pub fn page_size(&self) -> Option<usize> {
+        self.aux(AT_PAGESZ)
+    }
+
+    /// Return the 16 random bytes provided by the kernel via `AT_RANDOM`.
+    pub fn random(&self) -> Option<&'static [u8; 16]> {
+        self.aux(AT_RANDOM).map(|v| unsafe { &*(v as *const [u8; 16]) })
+    }
+
+    /// Return the CPU capabilities reported via `AT_HWCAP`.
+    pub fn hwcap(&self) -> Option<usize> {
+        self.aux(AT_HWCAP)
+    }
+
+    /// Return the extended CPU capabilities reported via `AT_HWCAP2`.
+    pub fn hwcap2(&self) -> Option<usize> {
+        self.aux(AT_HWCAP2)
+    }
+
+    /// Return the address of the vsyscall entry point, as reported via
+    /// `AT_SYSINFO` on x86.
+    pub fn sysinfo(&self) -> Option<*const u8> {
+        self.aux(AT_SYSINFO).map(|v| v as *const u8)
+    }
+
+    /// Return the address of the vDSO, as reported via `AT_SYSINFO_EHDR`.
+    pub fn sysinfo_ehdr(&self) -> Option<*const u8> {
+        self.aux(AT_SYSINFO_EHDR).map(|v| v as *const u8)
+    }
+
+    /// Return whether the program runs in secure mode, as reported via
+    /// `AT_SECURE`. If so, the environment must be treated as untrusted.
+    pub fn secure(&self) -> bool {
+        matches!(self.aux(AT_SECURE), Some(v) if v != 0)
+    }
+}
+
+impl Iterator for Args {
+    type Item = &'static CStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        unsafe {
+            let v = cstr(*self.pos);
+            self.pos = self.pos.add(1);
+            Some(v)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.end as usize - self.pos as usize) / core::mem::size_of::<usize>();
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Args {}
+
+impl Iterator for Vars {
+    type Item = &'static CStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            if (*self.pos).is_null() {
+                return None;
+            }
+
+            let v = cstr(*self.pos);
+            self.pos = self.pos.add(1);
+            Some(v)
+        }
+    }
+}
+
+impl Iterator for Auxv {
+    type Item = Aux;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, v) = unsafe { (*self.pos, *self.pos.add(1)) };
+        if key == AT_NULL {
+            return None;
+        }
+        self.pos = unsafe { self.pos.add(2) };
+
+        let s = || unsafe { cstr(v as *const u8) };
+
+        Some(match key {
+            AT_PHDR => Aux::Phdr(v as *const u8),
+            AT_PHENT => Aux::Phent(v),
+            AT_PHNUM => Aux::Phnum(v),
+            AT_PAGESZ => Aux::PageSize(v),
+            AT_BASE => Aux::Base(v as *const u8),
+            AT_ENTRY => Aux::Entry(v as *const u8),
+            AT_UID => Aux::Uid(v as u32),
+            AT_EUID => Aux::Euid(v as u32),
+            AT_GID => Aux::Gid(v as u32),
+            AT_EGID => Aux::Egid(v as u32),
+            AT_PLATFORM => Aux::Platform(s()),
+            AT_HWCAP => Aux::HwCap(v),
+            AT_CLKTCK => Aux::ClkTck(v),
+            AT_SECURE => Aux::Secure(v != 0),
+            AT_RANDOM => Aux::Random(unsafe { &*(v as *const [u8; 16]) }),
+            AT_HWCAP2 => Aux::HwCap2(v),
+            AT_EXECFN => Aux::ExecFn(s()),
+            AT_SYSINFO => Aux::Sysinfo(v as *const u8),
+            AT_SYSINFO_EHDR => Aux::SysinfoEhdr(v as *const u8),
+            AT_MINSIGSTKSZ => Aux::MinSigStkSz(v),
+            _ => Aux::Other(key, v),
+        })
+    }
+}
+
+/// Start Program
+///
+/// Parse the initial stack `sp`, run `main`, and exit with its return value.
+/// This is called by the entry point provided via `entrypoint!()`.
+///
+/// Before running `main`, the vDSO is initialized from `AT_SYSINFO_EHDR` (see
+/// `syscall::vdso::init()`), since `/proc/self/auxv` might not be available
+/// to freestanding programs. On x86 with the `vsyscall` feature, system calls
+/// are switched to the entry point passed via `AT_SYSINFO`.
+///
+/// # Safety
+///
+/// `sp` must be the initial stack as passed by the kernel, and the caller
+/// must be the only thread of the process.
+#[cfg(feature = "freestanding")]
+pub unsafe fn start(sp: *const usize, main: fn(&Env) -> i32) -> ! {
+    let env = Env::from_stack(sp);
+
+    crate::syscall::vdso::init(env.sysinfo_ehdr().unwrap_or(core::ptr::null()));
+    #[cfg(all(target_arch = "x86", feature = "vsyscall"))]
+    if let Some(v) = env.sysinfo() {
+        crate::syscall::arch::x86::syscall::set_vsyscall(v as usize);
+    }
+
+    let code = main(&env);
+    crate::syscall::api::exit(code as u32)
+}
+
+/// Provide Program Entry Point
+///
+/// Define the `_start` symbol, which serves as entry point of freestanding
+/// binaries, and run the given main function of type `fn(&Env) -> i32`.
+/// See `rt` for details.
+///
+/// The entry point clears the frame pointer (and the return address, where
+/// kept in a register) to terminate stack unwinding, aligns the stack to 16
+/// bytes, and calls `rt::start()` with the initial stack.
+#[cfg(feature = "freestanding")]
+#[macro_export]
+macro_rules! entrypoint {
+    ($main:path) => {
+        #[export_name = "r_linux_rt_enter"]
+        unsafe extern "C" fn __r_linux_rt_enter(sp: *const usize) -> ! {
+            $crate::rt::start(sp, $main)
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        ::core::arch::global_asm!(
+            ".globl _start",
+            ".type _start, %function",
+            "_start:",
+            "xor ebp, ebp",
+            "mov rdi, rsp",
+            "and rsp, -16",
+            "call r_linux_rt_enter",
+            "ud2",
+        );
+
+        #[cfg(target_arch = "x86")]
+        ::core::arch::global_asm!(
+            ".globl _start",
+            ".type _start, %function",
+            "_start:",
+            "xor ebp, ebp",
+            "mov eax, esp",
+            "and esp, -16",
+            "sub esp, 12",
+            "push eax",
+            "call r_linux_rt_enter",
+            "ud2",
+        );
+
+        #[cfg(target_arch = "arm")]
+        ::core::arch::global_asm!(
+            ".globl _start",
+            ".type _start, %function",
+            "_start:",
+            // Clear both the ARM (r11) and the Thumb (r7) frame pointer.
+            "mov fp, #0",
+            "mov r7, #0",
+            "mov lr, #0",
+            "mov r0, sp",
+            "mov r1, sp",
+            "bic r1, r1, #15",
+            "mov sp, r1",
+            "bl r_linux_rt_enter",
+            "udf #0",
+        );
+
+        #[cfg(target_arch = "aarch64")]
+        ::core::arch::global_asm!(
+            ".globl _start",
+            ".type _start, %function",
+            "_start:",
+            "mov x29, #0",
+            "mov x30, #0",
+            "mov x0, sp",
+            "and x1, x0, #-16",
+            "mov sp, x1",
+            "bl r_linux_rt_enter",
+            "udf #0",
+        );
+
+        #[cfg(target_arch = "riscv64")]
+        ::core::arch::global_asm!(
+            ".globl _start",
+            ".type _start, %function",
+            "_start:",
+            ".option push",
+            ".option norelax",
+            "lla gp, __global_pointer$",
+            ".option pop",
+            "li s0, 0",
+            "li ra, 0",
+            "mv a0, sp",
+            "andi sp, sp, -16",
+            "call r_linux_rt_enter",
+            "unimp",
+        );
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn env_check() {
+        //
+        // Verify parsing of a hand-crafted initial stack.
+        //
+
+        static RANDOM: [u8; 16] = [7; 16];
+        let a0 = b"foo\0";
+        let a1 = b"bar\0";
+        let e0 = b"FOO=foo\0";
+        let e1 = b"BAR=\0";
+        let e2 = b"FOO=bar\0";
+        let execfn = b"/bin/foo\0";
+
+        let stack: [usize; 22] = [
+            2,
+            a0.as_ptr() as usize,
+            a1.as_ptr() as usize,
+            0,
+            e0.as_ptr() as usize,
+            e1.as_ptr() as usize,
+            e2.as_ptr() as usize,
+            0,
+            AT_PAGESZ, 4096,
+            AT_RANDOM, RANDOM.as_ptr() as usize,
+            AT_SECURE, 1,
+            AT_EXECFN, execfn.as_ptr() as usize,
+            AT_SYSINFO, 0x1000,
+            71, 72,
+            AT_NULL, 0,
+        ];
+
+        let env = unsafe { Env::from_stack(stack.as_ptr()) };
+
+        let mut args = env.args();
+        assert_eq!(args.len(), 2);
+        assert_eq!(args.next().unwrap().to_bytes(), b"foo");
+        assert_eq!(args.len(), 1);
+        assert_eq!(args.next().unwrap().to_bytes(), b"bar");
+        assert!(args.next().is_none());
+
+        assert_eq!(env.vars().count(), 3);
+        assert_eq!(env.var(b"FOO"), Some(&b"foo"[..]));
+        assert_eq!(env.var(b"BAR"), Some(&b""[..]));
+        assert_eq!(env.var(b"FO"), None);
+        assert_eq!(env.var(b"BAZ"), None);
+
+        assert_eq!(env.page_size(), Some(4096));
+        assert_eq!(env.random(), Some(&RANDOM));
+        assert!(env.secure());
+        assert_eq!(env.hwcap(), None);
+        assert_eq!(env.sysinfo(), Some(0x1000 as *const u8));
+        assert_eq!(env.sysinfo_ehdr(), None);
+        assert_eq!(env.aux(71), Some(72));
+
+        let mut auxv = env.auxv();
+        assert_eq!(auxv.next(), Some(Aux::PageSize(4096)));
+        assert_eq!(auxv.next(), Some(Aux::Random(&RANDOM)));
+        assert_eq!(auxv.next(), Some(Aux::Secure(true)));
+        match auxv.next() {
+            Some(Aux::ExecFn(v)) => assert_eq!(v.to_bytes(), b"/bin/foo"),
+            v => panic!("unexpected entry: {:?}", v),
+        }
+        assert_eq!(auxv.next(), Some(Aux::Sysinfo(0x1000 as *const u8)));
+        assert_eq!(auxv.next(), Some(Aux::Other(71, 72)));
+        assert_eq!(auxv.next(), None);
+    }
+
+    #[test]
+    fn empty_check() {
+        let stack: [usize; 5] = [0, 0, 0, AT_NULL, 0];
+        let env = unsafe { Env::from_stack(stack.as_ptr()) };
+
+        assert_eq!(env.args().count(), 0);
+        assert_eq!(env.vars().count(), 0);
+        assert_eq!(env.auxv().count(), 0);
+        assert!(!env.secure());
+    }
+}
@@ -0,0 +1,492 @@
+//! Process Spawning
+//!
+//! This module provides `Command`, which spawns a new process running a
+//! program. Before the program is executed, the child applies a plan of
+//! `Action`s (e.g., to set up its file-descriptors, working directory,
+//! session, resource limits, or credentials). The plan is declared upfront
+//! and only borrowed by the child, so nothing is allocated after the fork.
+//!
+//! The child is created via `clone3(2)` with `CLONE_VM` and `CLONE_VFORK`,
+//! so it runs on a separate stack in the address space of the parent, which
+//! is suspended until the child executed the program or exited. Failures in
+//! the child are reported to the parent through a pipe with `O_CLOEXEC` set,
+//! which is closed without any data if the execution succeeds. Hence, the
+//! parent gets the exact error of the failed step. The new process is
+//! returned as a pidfd-backed `Process`.
+//!
+//! All signals are blocked while the child runs in the address space of the
+//! parent, and `CLONE_CLEAR_SIGHAND` resets the signal handlers of the
+//! child. This requires linux-5.5 or newer.
+
+use core::ffi::CStr;
+use crate::exec::ArgBuf;
+use crate::fd::{BorrowedFd, OwnedFd, RawFd};
+use crate::mmap::Mapping;
+use crate::process::Process;
+use crate::syscall::{api, errno, errno::Errno};
+use crate::thread::{self, GUARD_SIZE};
+
+/// Size of the stack of the child. The child only performs system calls
+/// before it executes the program, so this is plenty.
+const STACK_SIZE: usize = 64 * 1024;
+
+/// Spawn Action
+///
+/// This describes a single step performed by the child, before it executes
+/// the program. Actions are applied in order, and the first failing action
+/// aborts the spawn with its error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action<'a> {
+    /// Duplicate the first file-descriptor to the second one, replacing it
+    /// if open. The new file-descriptor does not have `FD_CLOEXEC` set. If
+    /// both are equal, `FD_CLOEXEC` is cleared instead.
+    Dup2(RawFd, RawFd),
+    /// Close the file-descriptor. This fails only if it is not open.
+    Close(RawFd),
+    /// Close all file-descriptors in the range (inclusive).
+    CloseRange(u32, u32),
+    /// Change the working directory to the path.
+    Chdir(&'a CStr),
+    /// Change the working directory to the directory of the
+    /// file-descriptor.
+    Fchdir(RawFd),
+    /// Create a new session (see `syscall::api::setsid()`).
+    Setsid,
+    /// Move the child into the process group, or into a new process group
+    /// if 0 (see `syscall::api::setpgid()`).
+    Setpgid(api::Pid),
+    /// Clear the signal mask of the program. By default, the program
+    /// inherits the signal mask of the calling thread. Regardless of its
+    /// position, this is applied last, since all signals are blocked while
+    /// the plan is applied.
+    ResetSignalMask,
+    /// Set the resource limit (see `RLIMIT_*`).
+    Setrlimit(u32, api::Rlimit),
+    /// Set the supplementary group IDs.
+    Setgroups(&'a [u32]),
+    /// Set the real, effective, and saved group ID.
+    Setgid(u32),
+    /// Set the real, effective, and saved user ID.
+    Setuid(u32),
+    /// Unshare parts of the execution context (see `syscall::api::unshare()`).
+    /// If `CLONE_NEWUSER` is requested, the child cannot share the address
+    /// space with the parent, and is forked without `CLONE_VM` instead.
+    Unshare(u64),
+}
+
+/// Command
+///
+/// This describes a program execution, together with the plan of actions to
+/// apply in the child before executing it. It borrows all its data, so it
+/// can be spawned any number of times.
+#[derive(Clone, Copy, Debug)]
+pub struct Command<'a> {
+    dir: Option<BorrowedFd<'a>>,
+    path: &'a CStr,
+    flags: u32,
+    argv: &'a ArgBuf<'a>,
+    envp: &'a ArgBuf<'a>,
+    actions: &'a [Action<'a>],
+}
+
+// State shared with the child. `pipe` is the write end of the error pipe,
+// and `mask` the signal mask of the calling thread.
+struct Child<'a, 'b> {
+    cmd: &'b Command<'a>,
+    pipe: RawFd,
+    mask: u64,
+}
+
+unsafe extern "C" fn entry(data: *mut u8) {
+    let child = &*(data as *const Child<'_, '_>);
+    let mut pipe = child.pipe;
+    let e = child.run(&mut pipe);
+    let _ = api::write(pipe, &e.get().to_ne_bytes());
+    api::exit(127);
+}
+
+// Close the range from `first` to `last`, except for `keep`.
+//
+// The caller must guarantee that the range is not owned by anyone else
+// (see `api::close_range()`).
+unsafe fn close_range(first: u32, last: u32, keep: RawFd) -> Result<(), Errno> {
+    let keep = keep as u32;
+
+    if keep < first || keep > last {
+        return api::close_range(first, last, 0);
+    }
+    if keep > first {
+        api::close_range(first, keep - 1, 0)?;
+    }
+    if keep < last {
+        api::close_range(keep + 1, last, 0)?;
+    }
+    Ok(())
+}
+
+impl Child<'_, '_> {
+    // Apply the plan and execute the program. The error pipe is kept open
+    // (and moved out of the way, if needed), and its final number is
+    // returned in `pipe`.
+    fn run(&self, pipe: &mut RawFd) -> Errno {
+        if let Err(e) = self.apply(pipe) {
+            return e;
+        }
+
+        let cmd = self.cmd;
+        let dirfd = cmd.dir.map_or(api::AT_FDCWD, |v| v.as_raw());
+        unsafe {
+            api::execveat(dirfd, cmd.path, cmd.argv.as_ptr(), cmd.envp.as_ptr(), cmd.flags)
+        }
+    }
+
+    fn apply(&self, pipe: &mut RawFd) -> Result<(), Errno> {
+        let mut mask = self.mask;
+
+        for action in self.cmd.actions {
+            match *action {
+                Action::Dup2(from, to) if from == to => {
+                    api::fcntl(from, api::F_SETFD, 0)?;
+                },
+                Action::Dup2(from, to) => {
+                    if to == *pipe {
+                        *pipe = api::fcntl(*pipe, api::F_DUPFD_CLOEXEC, 0)? as RawFd;
+                    }
+                    // `to` is handed over to the program.
+                    unsafe { api::dup3(from, to, 0) }?.into_raw();
+                },
+                // The child runs on its own copy of the file-descriptor
+                // table, and none of its entries are owned by the child.
+                Action::Close(fd) => {
+                    if fd != *pipe {
+                        if let Err(errno::EBADF) = unsafe { api::close(fd) } {
+                            return Err(errno::EBADF);
+                        }
+                    }
+                },
+                Action::CloseRange(first, last) => unsafe { close_range(first, last, *pipe) }?,
+                Action::Chdir(path) => api::chdir(path)?,
+                Action::Fchdir(fd) => api::fchdir(fd)?,
+                Action::Setsid => {
+                    api::setsid()?;
+                },
+                Action::Setpgid(pgid) => api::setpgid(0, pgid)?,
+                Action::ResetSignalMask => mask = 0,
+                Action::Setrlimit(resource, limit) => {
+                    api::prlimit64(0, resource, Some(&limit))?;
+                },
+                Action::Setgroups(groups) => api::setgroups(groups)?,
+                Action::Setgid(gid) => api::setresgid(gid, gid, gid)?,
+                Action::Setuid(uid) => api::setresuid(uid, uid, uid)?,
+                Action::Unshare(flags) => api::unshare(flags)?,
+            }
+        }
+
+        api::rt_sigprocmask(api::SIG_SETMASK, Some(mask))?;
+        Ok(())
+    }
+}
+
+impl<'a> Command<'a> {
+    /// Create Command
+    ///
+    /// Create a new command executing the program at `path` with the
+    /// arguments `argv` and the environment `envp`.
+    /// Relative paths are resolved against the working directory of the
+    /// child (i.e., after `Action::Chdir`). No `PATH` lookup is performed.
+    pub fn new(path: &'a CStr, argv: &'a ArgBuf<'a>, envp: &'a ArgBuf<'a>) -> Self {
+        Self {
+            dir: None,
+            path,
+            flags: 0,
+            argv,
+            envp,
+            actions: &[],
+        }
+    }
+
+    /// Create Command Relative to Directory
+    ///
+    /// Like `new()`, but `path` is resolved relative to `dir`. `flags` takes
+    /// `AT_SYMLINK_NOFOLLOW` and `AT_EMPTY_PATH`. See
+    /// `syscall::api::execveat()` for details.
+    pub fn new_at(
+        dir: BorrowedFd<'a>,
+        path: &'a CStr,
+        argv: &'a ArgBuf<'a>,
+        envp: &'a ArgBuf<'a>,
+        flags: u32,
+    ) -> Self {
+        Self {
+            dir: Some(dir),
+            flags,
+            ..Self::new(path, argv, envp)
+        }
+    }
+
+    /// Create Command from File-Descriptor
+    ///
+    /// Like `new()`, but execute the file referred to by `fd`. See
+    /// `exec::fexecve()` for details.
+    pub fn from_fd(fd: BorrowedFd<'a>, argv: &'a ArgBuf<'a>, envp: &'a ArgBuf<'a>) -> Self {
+        Self::new_at(fd, Default::default(), argv, envp, api::AT_EMPTY_PATH)
+    }
+
+    /// Set the plan of actions applied by the child before executing the
+    /// program. This replaces any previous plan.
+    pub fn actions(mut self, actions: &'a [Action<'a>]) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// Spawn Process
+    ///
+    /// Spawn a new child process, apply the plan of actions in it, and
+    /// execute the program. This returns once the program was executed, or
+    /// the child failed. In the latter case, the child is reaped and the
+    /// error of the failed action or execution is returned.
+    ///
+    /// The child is a copy of the calling thread, with the file-descriptor
+    /// table, working directory, and signal mask of the caller. Any
+    /// file-descriptor without `FD_CLOEXEC` is inherited by the program.
+    pub fn spawn(&self) -> Result<Process, Errno> {
+        let (r, w) = api::pipe2(api::O_CLOEXEC)?;
+
+        let len = STACK_SIZE + GUARD_SIZE;
+        let stack = Mapping::anonymous(len, api::PROT_READ | api::PROT_WRITE)?;
+        unsafe { api::mprotect(stack.as_ptr(), GUARD_SIZE, api::PROT_NONE)? };
+
+        let mut flags = api::CLONE_PIDFD | api::CLONE_CLEAR_SIGHAND;
+        let newuser = self.actions.iter().any(|v| {
+            matches!(v, Action::Unshare(f) if f & api::CLONE_NEWUSER != 0)
+        });
+        if !newuser {
+            flags |= api::CLONE_VM | api::CLONE_VFORK;
+        }
+
+        let mut pidfd: i32 = -1;
+        let args = api::CloneArgs {
+            flags,
+            pidfd: &mut pidfd as *mut i32 as u64,
+            exit_signal: api::SIGCHLD as u64,
+            stack: stack.as_ptr() as u64,
+            stack_size: len as u64,
+            ..Default::default()
+        };
+
+        // Block all signals, so no signal handler of the parent runs on the
+        // stack of the child. The child restores the mask before executing
+        // the program.
+        let mask = api::rt_sigprocmask(api::SIG_SETMASK, Some(!0))?;
+        let child = Child { cmd: self, pipe: w.as_raw(), mask };
+        let res = unsafe {
+            thread::trampoline(&args, entry, &child as *const Child<'_, '_> as *mut u8)
+        };
+        // Restoring the previous mask cannot fail.
+        let _ = api::rt_sigprocmask(api::SIG_SETMASK, Some(mask));
+        res?;
+
+        let process = Process::from_fd(unsafe { OwnedFd::from_raw(pidfd) });
+        drop(w);
+
+        // The write end is closed once the child executed the program or
+        // exited. Errors are reported as a single error number.
+        let mut buf = [0u8; 2];
+        let mut n = 0;
+        while n < buf.len() {
+            match api::read(r.as_raw(), &mut buf[n..]) {
+                Ok(0) => break,
+                Ok(v) => n += v,
+                Err(errno::EINTR) => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        if n == 0 {
+            return Ok(process);
+        }
+
+        let e = Errno::new(u16::from_ne_bytes(buf)).unwrap_or(errno::EIO);
+        let _ = process.wait(api::WEXITED, None);
+        Err(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cstr(v: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(v).unwrap()
+    }
+
+    fn sh() -> Option<&'static CStr> {
+        let sh = cstr(b"/bin/sh\0");
+        api::statx(api::AT_FDCWD, sh, 0, 0).ok().map(|_| sh)
+    }
+
+    fn run(script: &str, actions: &[Action<'_>]) -> Result<api::ExitStatus, Errno> {
+        let mut b0 = [0u8; 512];
+        let mut b1 = [0u8; 64];
+        let mut argv = ArgBuf::new(&mut b0);
+        let envp = ArgBuf::new(&mut b1);
+
+        argv.push(b"sh").unwrap();
+        argv.push(b"-c").unwrap();
+        argv.push(script.as_bytes()).unwrap();
+
+        let p = Command::new(sh().unwrap(), &argv, &envp).actions(actions).spawn()?;
+        Ok(p.wait(api::WEXITED, None)?.unwrap())
+    }
+
+    #[test]
+    fn spawn_check() {
+        //
+        // Spawn programs and verify their exit status, as well as the exact
+        // error of failed executions and actions.
+        //
+
+        let mut b0 = [0u8; 64];
+        let mut b1 = [0u8; 64];
+        let mut argv = ArgBuf::new(&mut b0);
+        let mut envp = ArgBuf::new(&mut b1);
+        argv.push(b"nonexistent").unwrap();
+        envp.push_var(b"FOO", b"71").unwrap();
+
+        let cmd = Command::new(cstr(b"/r-linux/nonexistent\0"), &argv, &envp);
+        match cmd.spawn().map(|_| ()) {
+            // Emulators like qemu-user might lack `clone3(2)`.
+            Err(errno::ENOSYS) => return,
+            v => assert_eq!(v, Err(errno::ENOENT)),
+        }
+        let cmd = Command::new(cstr(b"/proc/self/fdinfo\0"), &argv, &envp);
+        assert_eq!(cmd.spawn().map(|_| ()), Err(errno::EACCES));
+
+        if sh().is_none() {
+            return;
+        }
+
+        assert_eq!(run("exit 71", &[]), Ok(api::ExitStatus::Exited(71)));
+        assert_eq!(
+            run("exit 0", &[Action::Chdir(cstr(b"/r-linux/nonexistent\0"))]),
+            Err(errno::ENOENT),
+        );
+        assert_eq!(run("exit 0", &[Action::Setpgid(-1)]), Err(errno::EINVAL));
+        assert_eq!(run("exit 0", &[Action::Close(-1)]), Err(errno::EBADF));
+
+        let mut b0 = [0u8; 64];
+        let mut argv = ArgBuf::new(&mut b0);
+        argv.push(b"sh").unwrap();
+        argv.push(b"-c").unwrap();
+        argv.push(b"exit $FOO").unwrap();
+
+        let p = Command::new(sh().unwrap(), &argv, &envp).spawn().unwrap();
+        assert_eq!(p.wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(71))));
+    }
+
+    #[test]
+    fn action_check() {
+        //
+        // Apply different actions in the child and verify their effect on
+        // the executed program.
+        //
+
+        if sh().is_none() {
+            return;
+        }
+
+        // Redirect the output of the program into a pipe.
+        let (r, w) = api::pipe2(api::O_CLOEXEC).unwrap();
+        let p = {
+            let mut b0 = [0u8; 64];
+            let mut b1 = [0u8; 8];
+            let mut argv = ArgBuf::new(&mut b0);
+            argv.push(b"sh").unwrap();
+            argv.push(b"-c").unwrap();
+            argv.push(b"echo foo").unwrap();
+            let envp = ArgBuf::new(&mut b1);
+            let actions = [Action::Dup2(w.as_raw(), 1)];
+            match Command::new(sh().unwrap(), &argv, &envp).actions(&actions).spawn() {
+                // Emulators like qemu-user might lack `clone3(2)`.
+                Err(errno::ENOSYS) => return,
+                v => v.unwrap(),
+            }
+        };
+        drop(w);
+
+        let mut buf = [0u8; 16];
+        let mut n = 0;
+        loop {
+            match api::read(r.as_raw(), &mut buf[n..]).unwrap() {
+                0 => break,
+                v => n += v,
+            }
+        }
+        assert_eq!(&buf[..n], b"foo\n");
+        assert_eq!(p.wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(0))));
+
+        // Change the working directory and session.
+        let actions = [Action::Chdir(cstr(b"/proc\0")), Action::Setsid];
+        assert_eq!(
+            run("[ -e self ] && [ \"$(cut -d' ' -f6 self/stat)\" = $$ ]", &actions),
+            Ok(api::ExitStatus::Exited(0)),
+        );
+
+        // Inherit a file-descriptor, unless closed or marked `FD_CLOEXEC`.
+        let fd = api::memfd_create(cstr(b"r-linux-spawn\0"), 0).unwrap();
+        let script = std::format!("[ -e /proc/self/fd/{} ]", fd.as_raw());
+        let raw = fd.as_raw() as u32;
+        assert_eq!(run(&script, &[]), Ok(api::ExitStatus::Exited(0)));
+        assert_eq!(
+            run(&script, &[Action::CloseRange(raw, raw)]),
+            Ok(api::ExitStatus::Exited(1)),
+        );
+        assert_eq!(
+            run(&script, &[Action::CloseRange(3, u32::MAX)]),
+            Ok(api::ExitStatus::Exited(1)),
+        );
+        assert_eq!(
+            run(&script, &[Action::Close(fd.as_raw())]),
+            Ok(api::ExitStatus::Exited(1)),
+        );
+        api::fcntl(fd.as_raw(), api::F_SETFD, api::FD_CLOEXEC).unwrap();
+        assert_eq!(run(&script, &[]), Ok(api::ExitStatus::Exited(1)));
+        assert_eq!(
+            run(&script, &[Action::Dup2(fd.as_raw(), fd.as_raw())]),
+            Ok(api::ExitStatus::Exited(0)),
+        );
+
+        // Lower the file-descriptor limit of the program.
+        let limit = api::prlimit64(0, api::RLIMIT_NOFILE, None).unwrap();
+        let limit = api::Rlimit { cur: 71, ..limit };
+        assert_eq!(
+            run("[ \"$(ulimit -n)\" = 71 ]", &[Action::Setrlimit(api::RLIMIT_NOFILE, limit)]),
+            Ok(api::ExitStatus::Exited(0)),
+        );
+
+        // Reset the signal mask, which is inherited otherwise. The shell
+        // clears its signal mask, so `grep` is executed directly.
+        let grep = cstr(b"/bin/grep\0");
+        if api::statx(api::AT_FDCWD, grep, 0, 0).is_err() {
+            return;
+        }
+
+        let mut b0 = [0u8; 128];
+        let mut b1 = [0u8; 8];
+        let mut argv = ArgBuf::new(&mut b0);
+        argv.push(b"grep").unwrap();
+        argv.push(b"-q").unwrap();
+        argv.push(b"^SigBlk:.*[2367abef]..$").unwrap();
+        argv.push(b"/proc/self/status").unwrap();
+        let envp = ArgBuf::new(&mut b1);
+        let cmd = Command::new(grep, &argv, &envp);
+
+        let mask = api::rt_sigprocmask(api::SIG_BLOCK, Some(1 << (api::SIGUSR1 - 1))).unwrap();
+        let p0 = cmd.spawn();
+        let p1 = cmd.actions(&[Action::ResetSignalMask]).spawn();
+        api::rt_sigprocmask(api::SIG_SETMASK, Some(mask)).unwrap();
+
+        assert_eq!(p0.unwrap().wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(0))));
+        assert_eq!(p1.unwrap().wait(api::WEXITED, None), Ok(Some(api::ExitStatus::Exited(1))));
+    }
+}
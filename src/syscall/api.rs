@@ -12,19 +12,10 @@
 //! as specific in the type-system as possible, we must also make sure to be
 //! future-proof and allow passing invalid values along just as well.
 
-/// Error Number
-///
-/// The linux kernel commonly returns error information as an integer code
-/// between 1 and 4096. These have associated symbolic names and are used each
-/// for a wide range of possible errors, some more specific, some more generic.
-///
-/// We encode the error numbers as a u16, to better encapsulate their range.
-/// This can be easily converted to the `i32` used by most C standard
-/// libraries.
-///
-/// A value of 0 is not a valid error number, same as any value greater than
-/// 4096. It depends on the context how these invalid values are treated.
-pub type Errno = u16;
+pub use super::errno::Errno;
+use super::vdso::{KernelLong, Timeval};
+use core::ffi::CStr;
+use crate::fd::{OwnedFd, RawFd};
 
 /// Task Identifier
 ///
@@ -71,6 +62,25 @@ pub unsafe fn exit(code: u32) -> ! {
     core::unreachable!("`r_linux::syscall::api::exit()` unexpectedly returned");
 }
 
+/// Exit Thread Group
+///
+/// Stop the current execution and tear down all tasks of the thread group
+/// of the calling task (i.e., the entire process). Like `exit()`, this
+/// system call never returns, and the kernel uses the lower byte of `code`
+/// as exit-code.
+///
+/// Unlike `exit()`, this is safe to call, since no task is left behind that
+/// could still refer to the stack of the calling task.
+pub fn exit_group(code: u32) -> ! {
+    unsafe {
+        super::raw::syscall1(
+            super::arch::native::nr::EXIT_GROUP,
+            code as usize,
+        );
+    }
+    core::unreachable!("`r_linux::syscall::api::exit_group()` unexpectedly returned");
+}
+
 /// Fork Task
 ///
 /// Create a new thread of execution by forking the calling task. The calling
@@ -87,10 +97,36 @@ pub unsafe fn exit(code: u32) -> ! {
 /// creation of new tasks.
 ///
 /// On error, an error-code is returned and no new process is created.
+///
+/// Architectures using the generic syscall table (e.g., aarch64 and riscv64)
+/// lack the legacy `fork(2)` system call. On these, `clone(2)` is invoked with
+/// just `SIGCHLD` as exit-signal, which is exactly how the kernel implements
+/// `fork(2)` internally.
 pub unsafe fn fork() -> Result<Option<Pid>, Errno> {
-    super::raw::syscall0(
+    #[cfg(not(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+    )))]
+    let r = super::raw::syscall0(
         super::arch::native::nr::FORK,
-    ).to_result().map(|v| {
+    );
+
+    // The argument order of `clone(2)` differs between architectures, but
+    // all arguments but the flags are 0, so this does not matter here.
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+    ))]
+    let r = super::raw::syscall5(
+        super::arch::native::nr::CLONE,
+        17, // SIGCHLD
+        0,
+        0,
+        0,
+        0,
+    );
+
+    r.to_errno_result().map(|v| {
         let p = Pid::try_from(v).unwrap();
         match p {
             0 => None,
@@ -129,22 +165,2439 @@ pub unsafe fn fork() -> Result<Option<Pid>, Errno> {
 pub unsafe fn restart_syscall() -> Result<usize, Errno> {
     super::raw::syscall0(
         super::arch::native::nr::RESTART_SYSCALL,
-    ).to_result()
+    ).to_errno_result()
+}
+
+/// Read-Only I/O Vector
+///
+/// This describes a single buffer of a scatter-gather I/O operation, which is
+/// only read by the kernel (e.g., `writev(2)`). It has the same layout as
+/// `struct iovec` of the kernel, so a slice of it can be passed to the kernel
+/// directly.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct IoVec<'a> {
+    base: *const u8,
+    len: usize,
+    _buf: core::marker::PhantomData<&'a [u8]>,
+}
+
+impl<'a> IoVec<'a> {
+    /// Create a new I/O vector referring to `buf`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            base: buf.as_ptr(),
+            len: buf.len(),
+            _buf: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the buffer this I/O vector refers to.
+    pub fn as_slice(&self) -> &'a [u8] {
+        unsafe { core::slice::from_raw_parts(self.base, self.len) }
+    }
+}
+
+/// Mutable I/O Vector
+///
+/// This describes a single buffer of a scatter-gather I/O operation, which is
+/// written to by the kernel (e.g., `readv(2)`). It has the same layout as
+/// `struct iovec` of the kernel, so a slice of it can be passed to the kernel
+/// directly.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IoVecMut<'a> {
+    base: *mut u8,
+    len: usize,
+    _buf: core::marker::PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> IoVecMut<'a> {
+    /// Create a new I/O vector referring to `buf`.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            base: buf.as_mut_ptr(),
+            len: buf.len(),
+            _buf: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the buffer this I/O vector refers to.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.base, self.len) }
+    }
+
+    /// Return the buffer this I/O vector refers to as mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.base, self.len) }
+    }
+}
+
+/// High-priority request, poll if possible.
+pub const RWF_HIPRI: u32 = 0x00000001;
+/// Per-IO `O_DSYNC`.
+pub const RWF_DSYNC: u32 = 0x00000002;
+/// Per-IO `O_SYNC`.
+pub const RWF_SYNC: u32 = 0x00000004;
+/// Per-IO, return `EAGAIN` if the operation would block.
+pub const RWF_NOWAIT: u32 = 0x00000008;
+/// Per-IO `O_APPEND`.
+pub const RWF_APPEND: u32 = 0x00000010;
+
+// Split a 64-bit file offset into the low and high word, as expected by system
+// calls that take the offset in two registers on 32-bit architectures. On
+// 64-bit architectures, the kernel takes the full offset from the low word,
+// and ignores the high word. x32 passes 64-bit offsets in a single register,
+// instead.
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+fn offset_lo_hi(offset: i64) -> (usize, usize) {
+    (offset as usize, ((offset as u64) >> 32) as usize)
 }
 
 /// Read from File-Descriptor
 ///
-/// XXX
-pub use crate::syscall::arch::native::nr::READ;
+/// Read data from the file-descriptor `fd` into `buf`, starting at the
+/// current file position of the open file-description. The file position is
+/// advanced by the number of bytes read, if the file supports seeking.
+///
+/// On success, the number of bytes read is returned, which can be less than
+/// the size of `buf`. A return value of 0 signals end-of-file (or that `buf`
+/// is empty). On error, the error code is returned and the content of `buf`
+/// is unspecified.
+pub fn read(fd: RawFd, buf: &mut [u8]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::READ,
+            fd as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        ).to_errno_result()
+    }
+}
 
 /// Write to File-Descriptor
 ///
-/// XXX
-pub use crate::syscall::arch::native::nr::WRITE;
+/// Write the data in `buf` to the file-descriptor `fd`, starting at the
+/// current file position of the open file-description (or at the end of the
+/// file, if it was opened with `O_APPEND`). The file position is advanced by
+/// the number of bytes written, if the file supports seeking.
+///
+/// On success, the number of bytes written is returned, which can be less
+/// than the size of `buf`.
+pub fn write(fd: RawFd, buf: &[u8]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall3_readonly(
+            super::arch::native::nr::WRITE,
+            fd as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+        ).to_errno_result()
+    }
+}
+
+/// Read from File-Descriptor at Offset
+///
+/// This works like `read()`, but reads from the file-offset `offset`, rather
+/// than the current file position. The file position is not changed. The
+/// file must support seeking, otherwise `ESPIPE` is returned.
+///
+/// On 32-bit architectures, the offset is split across two registers, as
+/// required by the architecture. On x32, it is passed in a single 64-bit
+/// register, like on 64-bit architectures.
+pub fn pread64(fd: RawFd, buf: &mut [u8], offset: i64) -> Result<usize, Errno> {
+    let fd = fd as usize;
+    let ptr = buf.as_mut_ptr() as usize;
+    let len = buf.len();
+    let nr = super::arch::native::nr::PREAD64;
+
+    #[cfg(target_arch = "x86")]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe { super::raw::syscall5(nr, fd, ptr, len, lo, hi) }
+    };
+
+    // arm passes 64-bit arguments in even/odd register pairs, so a padding
+    // register is needed before the offset.
+    #[cfg(target_arch = "arm")]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe { super::raw::syscall6(nr, fd, ptr, len, 0, lo, hi) }
+    };
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let r = super::raw::Retval::from_usize(unsafe {
+        super::arch::native::syscall::syscall4_u64(
+            nr,
+            fd as u64,
+            ptr as u64,
+            len as u64,
+            offset as u64,
+        )
+    });
+
+    #[cfg(target_pointer_width = "64")]
+    let r = unsafe { super::raw::syscall4(nr, fd, ptr, len, offset as usize) };
+
+    r.to_errno_result()
+}
+
+/// Write to File-Descriptor at Offset
+///
+/// This works like `write()`, but writes at the file-offset `offset`, rather
+/// than the current file position. The file position is not changed. The
+/// file must support seeking, otherwise `ESPIPE` is returned. Note that
+/// linux ignores `offset` and appends the data if the file was opened with
+/// `O_APPEND`.
+///
+/// See `pread64()` for details on how `offset` is passed to the kernel.
+pub fn pwrite64(fd: RawFd, buf: &[u8], offset: i64) -> Result<usize, Errno> {
+    let fd = fd as usize;
+    let ptr = buf.as_ptr() as usize;
+    let len = buf.len();
+    let nr = super::arch::native::nr::PWRITE64;
+
+    #[cfg(target_arch = "x86")]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe { super::raw::syscall5_readonly(nr, fd, ptr, len, lo, hi) }
+    };
+
+    // see pread64() for the padding register
+    #[cfg(target_arch = "arm")]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe { super::raw::syscall6_readonly(nr, fd, ptr, len, 0, lo, hi) }
+    };
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let r = super::raw::Retval::from_usize(unsafe {
+        super::arch::native::syscall::syscall4_u64(
+            nr,
+            fd as u64,
+            ptr as u64,
+            len as u64,
+            offset as u64,
+        )
+    });
+
+    #[cfg(target_pointer_width = "64")]
+    let r = unsafe {
+        super::raw::syscall4_readonly(nr, fd, ptr, len, offset as usize)
+    };
+
+    r.to_errno_result()
+}
+
+/// Read from File-Descriptor into Multiple Buffers
+///
+/// This works like `read()`, but reads into all buffers in `bufs` in order,
+/// filling each buffer before moving on to the next one. The data is read
+/// atomically with respect to other readers of the same file-description.
+///
+/// On success, the total number of bytes read is returned. The kernel
+/// limits the number of buffers to 1024 (`UIO_MAXIOV`) and returns `EINVAL`
+/// if more are passed.
+pub fn readv(fd: RawFd, bufs: &mut [IoVecMut<'_>]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::READV,
+            fd as usize,
+            bufs.as_mut_ptr() as usize,
+            bufs.len(),
+        ).to_errno_result()
+    }
+}
+
+/// Write to File-Descriptor from Multiple Buffers
+///
+/// This works like `write()`, but writes the data of all buffers in `bufs`
+/// in order. The data is written atomically with respect to other writers of
+/// the same file-description.
+///
+/// On success, the total number of bytes written is returned. See `readv()`
+/// for the limit on the number of buffers.
+pub fn writev(fd: RawFd, bufs: &[IoVec<'_>]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall3_readonly(
+            super::arch::native::nr::WRITEV,
+            fd as usize,
+            bufs.as_ptr() as usize,
+            bufs.len(),
+        ).to_errno_result()
+    }
+}
+
+/// Read from File-Descriptor at Offset into Multiple Buffers
+///
+/// This combines `readv()` and `pread64()`, and additionally takes a set of
+/// `RWF_*` flags in `flags` to modify the behavior of this single operation.
+/// If `offset` is -1, the current file position is used and updated, like
+/// `readv()` does.
+///
+/// The offset is passed split across two registers, as defined by the kernel
+/// for this system call on all architectures but x32. On x32, the kernel
+/// takes it in a single 64-bit register, followed by `flags`.
+pub fn preadv2(
+    fd: RawFd,
+    bufs: &mut [IoVecMut<'_>],
+    offset: i64,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let fd = fd as usize;
+    let ptr = bufs.as_mut_ptr() as usize;
+    let len = bufs.len();
+    let nr = super::arch::native::nr::PREADV2;
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let r = super::raw::Retval::from_usize(unsafe {
+        super::arch::native::syscall::syscall5_u64(
+            nr,
+            fd as u64,
+            ptr as u64,
+            len as u64,
+            offset as u64,
+            flags as u64,
+        )
+    });
+
+    #[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe { super::raw::syscall6(nr, fd, ptr, len, lo, hi, flags as usize) }
+    };
+
+    r.to_errno_result()
+}
+
+/// Write to File-Descriptor at Offset from Multiple Buffers
+///
+/// This combines `writev()` and `pwrite64()`, and additionally takes a set
+/// of `RWF_*` flags in `flags` to modify the behavior of this single
+/// operation. If `offset` is -1, the current file position is used and
+/// updated, like `writev()` does.
+///
+/// See `preadv2()` for details on how `offset` is passed to the kernel.
+pub fn pwritev2(
+    fd: RawFd,
+    bufs: &[IoVec<'_>],
+    offset: i64,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let fd = fd as usize;
+    let ptr = bufs.as_ptr() as usize;
+    let len = bufs.len();
+    let nr = super::arch::native::nr::PWRITEV2;
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let r = super::raw::Retval::from_usize(unsafe {
+        super::arch::native::syscall::syscall5_u64(
+            nr,
+            fd as u64,
+            ptr as u64,
+            len as u64,
+            offset as u64,
+            flags as u64,
+        )
+    });
+
+    #[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+    let r = {
+        let (lo, hi) = offset_lo_hi(offset);
+        unsafe {
+            super::raw::syscall6_readonly(nr, fd, ptr, len, lo, hi, flags as usize)
+        }
+    };
+
+    r.to_errno_result()
+}
+
+/// Use the current working directory as base for relative paths, rather than
+/// a directory file-descriptor.
+pub const AT_FDCWD: i32 = -100;
+
+/// Open for reading only.
+pub const O_RDONLY: u32 = 0o0;
+/// Open for writing only.
+pub const O_WRONLY: u32 = 0o1;
+/// Open for reading and writing.
+pub const O_RDWR: u32 = 0o2;
+/// Create the file if it does not exist.
+pub const O_CREAT: u32 = 0o100;
+/// Fail with `EEXIST` if the file exists, when combined with `O_CREAT`.
+pub const O_EXCL: u32 = 0o200;
+/// Do not make a terminal the controlling terminal of the process.
+pub const O_NOCTTY: u32 = 0o400;
+/// Truncate the file to length 0.
+pub const O_TRUNC: u32 = 0o1000;
+/// Always write at the end of the file.
+pub const O_APPEND: u32 = 0o2000;
+/// Never block on operations of the open file-description.
+pub const O_NONBLOCK: u32 = 0o4000;
+/// Complete data integrity on each write.
+pub const O_DSYNC: u32 = 0o10000;
+/// Bypass the page-cache, if possible.
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+pub const O_DIRECT: u32 = 0o40000;
+/// Bypass the page-cache, if possible.
+#[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+pub const O_DIRECT: u32 = 0o200000;
+/// Allow 64-bit file sizes on 32-bit architectures. Always set by
+/// `openat()`, and by `openat2()` unless `O_PATH` is set.
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+pub const O_LARGEFILE: u32 = 0o100000;
+/// Allow 64-bit file sizes on 32-bit architectures. Always set by
+/// `openat()`, and by `openat2()` unless `O_PATH` is set.
+#[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+pub const O_LARGEFILE: u32 = 0o400000;
+/// Fail with `ENOTDIR` if the file is not a directory.
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+pub const O_DIRECTORY: u32 = 0o200000;
+/// Fail with `ENOTDIR` if the file is not a directory.
+#[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+pub const O_DIRECTORY: u32 = 0o40000;
+/// Fail with `ELOOP` if the last path component is a symbolic link.
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+pub const O_NOFOLLOW: u32 = 0o400000;
+/// Fail with `ELOOP` if the last path component is a symbolic link.
+#[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+pub const O_NOFOLLOW: u32 = 0o100000;
+/// Do not update the access time of the file.
+pub const O_NOATIME: u32 = 0o1000000;
+/// Set close-on-exec on the new file-descriptor. Accepted by `dup3(2)`,
+/// `pipe2(2)`, `openat(2)`, and others.
+pub const O_CLOEXEC: u32 = 0o2000000;
+/// Complete file integrity on each write.
+pub const O_SYNC: u32 = 0o4010000;
+/// Only obtain a location in the file-system tree, without opening the file.
+pub const O_PATH: u32 = 0o10000000;
+/// Create an unnamed temporary file in the specified directory.
+pub const O_TMPFILE: u32 = 0o20000000 | O_DIRECTORY;
+
+/// Fail with `EXDEV` if path resolution crosses a mount point.
+pub const RESOLVE_NO_XDEV: u64 = 0x01;
+/// Fail with `ELOOP` if path resolution encounters a magic link (e.g.,
+/// `/proc/self/fd/*`).
+pub const RESOLVE_NO_MAGICLINKS: u64 = 0x02;
+/// Fail with `ELOOP` if path resolution encounters a symbolic link. This
+/// implies `RESOLVE_NO_MAGICLINKS`.
+pub const RESOLVE_NO_SYMLINKS: u64 = 0x04;
+/// Fail with `EXDEV` if path resolution would leave the directory `dirfd`,
+/// including via absolute paths, `..`, or symbolic links.
+pub const RESOLVE_BENEATH: u64 = 0x08;
+/// Resolve the path as if `dirfd` was the root directory of the calling
+/// task. Absolute paths, `..`, and symbolic links are all clamped to it.
+pub const RESOLVE_IN_ROOT: u64 = 0x10;
+/// Only resolve the path from the dentry cache, and fail with `EAGAIN` if
+/// that is not possible.
+pub const RESOLVE_CACHED: u64 = 0x20;
+
+/// Size of the first revision of `OpenHow`.
+pub const OPEN_HOW_SIZE_VER0: usize = 24;
+
+/// Open Parameters
+///
+/// This is the extensible argument structure of `openat2()`. `flags` takes
+/// the `O_*` flags, `mode` the file mode used with `O_CREAT` and `O_TMPFILE`
+/// (and must be 0 otherwise), and `resolve` the `RESOLVE_*` flags.
+///
+/// The kernel identifies the revision of this structure by its size. Newer
+/// kernels accept older, smaller revisions. Older kernels accept newer,
+/// larger revisions only if all unknown trailing fields are 0, and fail with
+/// `E2BIG` otherwise.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OpenHow {
+    pub flags: u64,
+    pub mode: u64,
+    pub resolve: u64,
+}
+
+impl OpenHow {
+    /// Create open parameters with the given `O_*` flags, file mode, and
+    /// `RESOLVE_*` flags.
+    pub fn new(flags: u32, mode: u32, resolve: u64) -> Self {
+        Self {
+            flags: flags as u64,
+            mode: mode as u64,
+            resolve,
+        }
+    }
+}
+
+// Take over ownership of a new file-descriptor returned by the kernel.
+fn owned_fd(v: usize) -> OwnedFd {
+    unsafe { OwnedFd::from_raw(v as RawFd) }
+}
+
+/// Open File Relative to Directory
+///
+/// Open the file at `path` and return a new file-descriptor for it. If
+/// `path` is relative, it is resolved relative to the directory `dirfd`, or
+/// to the current working directory if `dirfd` is `AT_FDCWD`. `flags` takes
+/// the `O_*` flags, and `mode` is the file mode used with `O_CREAT` and
+/// `O_TMPFILE`.
+///
+/// `O_LARGEFILE` is always set, as done by the kernel on 64-bit
+/// architectures. Note that `O_CLOEXEC` is not set implicitly.
+pub fn openat(dirfd: RawFd, path: &CStr, flags: u32, mode: u32) -> Result<OwnedFd, Errno> {
+    unsafe {
+        super::raw::syscall4_readonly(
+            super::arch::native::nr::OPENAT,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            (flags | O_LARGEFILE) as usize,
+            mode as usize,
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+/// Open File Relative to Directory with Extended Parameters
+///
+/// This works like `openat()`, but takes its parameters as `OpenHow`. This
+/// allows restricting path resolution via the `RESOLVE_*` flags. For
+/// instance, `RESOLVE_BENEATH` or `RESOLVE_IN_ROOT` confine path resolution
+/// to `dirfd`, so untrusted paths can be opened without sanitizing them in
+/// user-space first.
+///
+/// Unlike `openat()`, the kernel rejects the following with `EINVAL`:
+/// unknown flags, flags other than `O_CLOEXEC`, `O_DIRECTORY`, and
+/// `O_NOFOLLOW` combined with `O_PATH`, and a non-zero `mode` without
+/// `O_CREAT` or `O_TMPFILE`. `ENOSYS` is returned if the kernel does not
+/// support this system call (older than linux-5.6).
+///
+/// `OpenHow` is always passed as its first revision. Use `openat2_sized()` to
+/// pass newer revisions.
+pub fn openat2(dirfd: RawFd, path: &CStr, how: &OpenHow) -> Result<OwnedFd, Errno> {
+    // `O_PATH` rejects all other flags but a select few, so only set
+    // `O_LARGEFILE` if it is not set.
+    let mut how = *how;
+    if how.flags & O_PATH as u64 == 0 {
+        how.flags |= O_LARGEFILE as u64;
+    }
+
+    unsafe {
+        super::raw::syscall4_readonly(
+            super::arch::native::nr::OPENAT2,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            &how as *const OpenHow as usize,
+            core::mem::size_of::<OpenHow>(),
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+/// Open File with Sized Extended Parameters
+///
+/// This works like `openat2()`, but takes the raw bytes of the parameters,
+/// whose size identifies the revision of the structure. This allows passing
+/// revisions newer than `OpenHow`, which start with the fields of `OpenHow`
+/// followed by further fields. Sizes smaller than `OPEN_HOW_SIZE_VER0` are
+/// rejected by the kernel with `EINVAL`.
+///
+/// If all bytes beyond the first revision are 0, only the first revision is
+/// passed, since all kernels treat both equally. Hence, `E2BIG` is only
+/// returned if the kernel does not know about a non-zero field.
+///
+/// Unlike `openat2()`, `O_LARGEFILE` is not set implicitly on 32-bit
+/// architectures.
+pub fn openat2_sized(dirfd: RawFd, path: &CStr, how: &[u8]) -> Result<OwnedFd, Errno> {
+    let size = match how.get(OPEN_HOW_SIZE_VER0..) {
+        Some(v) if v.iter().all(|v| *v == 0) => OPEN_HOW_SIZE_VER0,
+        _ => how.len(),
+    };
+
+    unsafe {
+        super::raw::syscall4_readonly(
+            super::arch::native::nr::OPENAT2,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            how.as_ptr() as usize,
+            size,
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+/// Do not follow a symbolic link in the last path component.
+pub const AT_SYMLINK_NOFOLLOW: u32 = 0x100;
+/// Remove a directory rather than a file, with `unlinkat()`.
+pub const AT_REMOVEDIR: u32 = 0x200;
+/// Follow a symbolic link in the last path component.
+pub const AT_SYMLINK_FOLLOW: u32 = 0x400;
+/// Do not trigger an automount of the last path component.
+pub const AT_NO_AUTOMOUNT: u32 = 0x800;
+/// Operate on `dirfd` itself, if the path is empty.
+pub const AT_EMPTY_PATH: u32 = 0x1000;
+
+/// Fail with `EEXIST` if the target of `renameat2()` exists.
+pub const RENAME_NOREPLACE: u32 = 0x1;
+/// Atomically exchange source and target of `renameat2()`.
+pub const RENAME_EXCHANGE: u32 = 0x2;
+/// Leave a whiteout object at the source of `renameat2()`.
+pub const RENAME_WHITEOUT: u32 = 0x4;
+
+/// Synchronize with the server as `stat(2)` does (default).
+pub const AT_STATX_SYNC_AS_STAT: u32 = 0x0000;
+/// Force synchronization of the attributes with the server.
+pub const AT_STATX_FORCE_SYNC: u32 = 0x2000;
+/// Never synchronize, but use cached attributes.
+pub const AT_STATX_DONT_SYNC: u32 = 0x4000;
+/// Mask of the `AT_STATX_*` synchronization types.
+pub const AT_STATX_SYNC_TYPE: u32 = 0x6000;
+
+/// File type bits of `Statx::mode`.
+pub const S_IFMT: u16 = 0o170000;
+/// Socket.
+pub const S_IFSOCK: u16 = 0o140000;
+/// Symbolic link.
+pub const S_IFLNK: u16 = 0o120000;
+/// Regular file.
+pub const S_IFREG: u16 = 0o100000;
+/// Block device.
+pub const S_IFBLK: u16 = 0o060000;
+/// Directory.
+pub const S_IFDIR: u16 = 0o040000;
+/// Character device.
+pub const S_IFCHR: u16 = 0o020000;
+/// FIFO.
+pub const S_IFIFO: u16 = 0o010000;
+
+/// Want/got the file type bits of `Statx::mode`.
+pub const STATX_TYPE: u32 = 0x00000001;
+/// Want/got the permission bits of `Statx::mode`.
+pub const STATX_MODE: u32 = 0x00000002;
+/// Want/got `Statx::nlink`.
+pub const STATX_NLINK: u32 = 0x00000004;
+/// Want/got `Statx::uid`.
+pub const STATX_UID: u32 = 0x00000008;
+/// Want/got `Statx::gid`.
+pub const STATX_GID: u32 = 0x00000010;
+/// Want/got `Statx::atime`.
+pub const STATX_ATIME: u32 = 0x00000020;
+/// Want/got `Statx::mtime`.
+pub const STATX_MTIME: u32 = 0x00000040;
+/// Want/got `Statx::ctime`.
+pub const STATX_CTIME: u32 = 0x00000080;
+/// Want/got `Statx::ino`.
+pub const STATX_INO: u32 = 0x00000100;
+/// Want/got `Statx::size`.
+pub const STATX_SIZE: u32 = 0x00000200;
+/// Want/got `Statx::blocks`.
+pub const STATX_BLOCKS: u32 = 0x00000400;
+/// All the fields that `stat(2)` returns.
+pub const STATX_BASIC_STATS: u32 = 0x000007ff;
+/// Want/got `Statx::btime`.
+pub const STATX_BTIME: u32 = 0x00000800;
+/// Want/got `Statx::mnt_id`.
+pub const STATX_MNT_ID: u32 = 0x00001000;
+/// Want/got `Statx::dio_mem_align` and `Statx::dio_offset_align`.
+pub const STATX_DIOALIGN: u32 = 0x00002000;
+/// Want/got a unique, never reused `Statx::mnt_id`.
+pub const STATX_MNT_ID_UNIQUE: u32 = 0x00004000;
+/// Want/got `Statx::subvol`.
+pub const STATX_SUBVOL: u32 = 0x00008000;
+/// Want/got the `Statx::atomic_write_*` fields.
+pub const STATX_WRITE_ATOMIC: u32 = 0x00010000;
+/// Want/got `Statx::dio_read_offset_align`.
+pub const STATX_DIO_READ_ALIGN: u32 = 0x00020000;
+
+/// File is compressed by the file-system.
+pub const STATX_ATTR_COMPRESSED: u64 = 0x00000004;
+/// File cannot be modified.
+pub const STATX_ATTR_IMMUTABLE: u64 = 0x00000010;
+/// File can only be opened in append mode for writing.
+pub const STATX_ATTR_APPEND: u64 = 0x00000020;
+/// File is not a candidate for backup.
+pub const STATX_ATTR_NODUMP: u64 = 0x00000040;
+/// File requires a key to be decrypted.
+pub const STATX_ATTR_ENCRYPTED: u64 = 0x00000800;
+/// Directory is an automount trigger.
+pub const STATX_ATTR_AUTOMOUNT: u64 = 0x00001000;
+/// Root of a mount.
+pub const STATX_ATTR_MOUNT_ROOT: u64 = 0x00002000;
+/// File has fs-verity enabled.
+pub const STATX_ATTR_VERITY: u64 = 0x00100000;
+/// File is in the DAX (cpu direct access) state.
+pub const STATX_ATTR_DAX: u64 = 0x00200000;
+/// File supports atomic write operations.
+pub const STATX_ATTR_WRITE_ATOMIC: u64 = 0x00400000;
+
+/// File Status Timestamp
+///
+/// Timestamp as returned by `statx()`, in seconds and nanoseconds since the
+/// epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StatxTimestamp {
+    pub sec: i64,
+    pub nsec: u32,
+    _reserved: i32,
+}
+
+/// File Status
+///
+/// This is `struct statx` as returned by `statx()`. The fields are named
+/// after their kernel counterparts, without the `stx_` prefix.
+///
+/// `mask` contains the `STATX_*` flags of all fields that were filled in by
+/// the kernel. Use `has()` to check for them. This can include fields the
+/// caller did not ask for, and can lack fields the caller asked for, if the
+/// file-system or kernel does not support them. The content of any field not
+/// reported in `mask` is unspecified.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Statx {
+    pub mask: u32,
+    pub blksize: u32,
+    pub attributes: u64,
+    pub nlink: u32,
+    pub uid: u32,
+    pub gid: u32,
+    pub mode: u16,
+    _spare0: [u16; 1],
+    pub ino: u64,
+    pub size: u64,
+    pub blocks: u64,
+    pub attributes_mask: u64,
+    pub atime: StatxTimestamp,
+    pub btime: StatxTimestamp,
+    pub ctime: StatxTimestamp,
+    pub mtime: StatxTimestamp,
+    pub rdev_major: u32,
+    pub rdev_minor: u32,
+    pub dev_major: u32,
+    pub dev_minor: u32,
+    pub mnt_id: u64,
+    pub dio_mem_align: u32,
+    pub dio_offset_align: u32,
+    pub subvol: u64,
+    pub atomic_write_unit_min: u32,
+    pub atomic_write_unit_max: u32,
+    pub atomic_write_segments_max: u32,
+    pub dio_read_offset_align: u32,
+    _spare3: [u64; 9],
+}
+
+impl Statx {
+    /// Check whether all fields selected by the `STATX_*` flags in `mask`
+    /// were filled in by the kernel.
+    pub fn has(&self, mask: u32) -> bool {
+        self.mask & mask == mask
+    }
+
+    /// Check whether all `STATX_ATTR_*` attributes in `attr` are supported
+    /// by the file-system and set on the file.
+    pub fn has_attributes(&self, attr: u64) -> bool {
+        self.attributes_mask & attr == attr && self.attributes & attr == attr
+    }
+}
+
+/// Get File Status
+///
+/// Retrieve the status of the file at `path`. `path` is resolved like with
+/// `openat()`, and `flags` takes `AT_*` flags to modify path resolution
+/// (e.g., `AT_EMPTY_PATH` to query `dirfd` itself, or `AT_SYMLINK_NOFOLLOW`)
+/// as well as one of the `AT_STATX_*` synchronization types.
+///
+/// `mask` takes the `STATX_*` flags of the fields the caller is interested
+/// in. The kernel is free to fill in more or less fields, and reports the
+/// fields actually filled in via `Statx::mask`.
+pub fn statx(dirfd: RawFd, path: &CStr, flags: u32, mask: u32) -> Result<Statx, Errno> {
+    let mut buf = Statx::default();
+
+    unsafe {
+        super::raw::syscall5(
+            super::arch::native::nr::STATX,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            flags as usize,
+            mask as usize,
+            &mut buf as *mut Statx as usize,
+        ).to_errno_result().map(|_| buf)
+    }
+}
+
+/// Unknown file type.
+pub const DT_UNKNOWN: u8 = 0;
+/// FIFO.
+pub const DT_FIFO: u8 = 1;
+/// Character device.
+pub const DT_CHR: u8 = 2;
+/// Directory.
+pub const DT_DIR: u8 = 4;
+/// Block device.
+pub const DT_BLK: u8 = 6;
+/// Regular file.
+pub const DT_REG: u8 = 8;
+/// Symbolic link.
+pub const DT_LNK: u8 = 10;
+/// Socket.
+pub const DT_SOCK: u8 = 12;
+/// Whiteout.
+pub const DT_WHT: u8 = 14;
+
+/// Read Directory Entries
+///
+/// Read directory entries from the directory `fd` into `buf`, starting at
+/// the current file position, which is advanced accordingly. Entries are
+/// stored as `struct linux_dirent64` records, which are variable-sized and
+/// padded to 8 bytes. See `dir::DirEntries` to parse them.
+///
+/// On success, the number of bytes filled in is returned, which is 0 at the
+/// end of the directory. `EINVAL` is returned if `buf` is too small for the
+/// next entry.
+pub fn getdents64(fd: RawFd, buf: &mut [u8]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::GETDENTS64,
+            fd as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        ).to_errno_result()
+    }
+}
+
+/// Read Symbolic Link
+///
+/// Read the target of the symbolic link at `path` into `buf`. The last path
+/// component is never followed. `path` is resolved like with `openat()`.
+///
+/// On success, the number of bytes written to `buf` is returned. The target
+/// is not NUL-terminated, and is silently truncated if `buf` is too small.
+pub fn readlinkat(dirfd: RawFd, path: &CStr, buf: &mut [u8]) -> Result<usize, Errno> {
+    unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::READLINKAT,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        ).to_errno_result()
+    }
+}
+
+/// Create Directory
+///
+/// Create a new directory at `path` with the file mode `mode` (masked by the
+/// umask of the calling task). `path` is resolved like with `openat()`.
+pub fn mkdirat(dirfd: RawFd, path: &CStr, mode: u32) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall3_readonly(
+            super::arch::native::nr::MKDIRAT,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            mode as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Remove Directory Entry
+///
+/// Remove the directory entry at `path`. If `flags` contains `AT_REMOVEDIR`,
+/// the entry must be an empty directory, otherwise it must not be a
+/// directory. `path` is resolved like with `openat()`.
+pub fn unlinkat(dirfd: RawFd, path: &CStr, flags: u32) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall3_readonly(
+            super::arch::native::nr::UNLINKAT,
+            dirfd as usize,
+            path.as_ptr() as usize,
+            flags as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Rename Directory Entry
+///
+/// Move the directory entry at `old_path` (relative to `old_dirfd`) to
+/// `new_path` (relative to `new_dirfd`), replacing any existing entry at the
+/// target. `flags` takes the `RENAME_*` flags to modify this behavior. Both
+/// paths are resolved like with `openat()`.
+pub fn renameat2(
+    old_dirfd: RawFd,
+    old_path: &CStr,
+    new_dirfd: RawFd,
+    new_path: &CStr,
+    flags: u32,
+) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall5_readonly(
+            super::arch::native::nr::RENAMEAT2,
+            old_dirfd as usize,
+            old_path.as_ptr() as usize,
+            new_dirfd as usize,
+            new_path.as_ptr() as usize,
+            flags as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Set close-on-exec on the new memfd.
+pub const MFD_CLOEXEC: u32 = 0x0001;
+/// Allow sealing of the new memfd via `add_seals()`.
+pub const MFD_ALLOW_SEALING: u32 = 0x0002;
+/// Back the new memfd by huge pages. The page size can be selected via the
+/// `MFD_HUGE_*` constants.
+pub const MFD_HUGETLB: u32 = 0x0004;
+/// Create the memfd non-executable, and seal it via `F_SEAL_EXEC`. This
+/// implies `MFD_ALLOW_SEALING`.
+pub const MFD_NOEXEC_SEAL: u32 = 0x0008;
+/// Create the memfd executable.
+pub const MFD_EXEC: u32 = 0x0010;
+
+/// Shift of the huge page size encoding in the flags of `memfd_create()`.
+/// The size is encoded as its base-2 logarithm.
+pub const MFD_HUGE_SHIFT: u32 = 26;
+/// Mask of the huge page size encoding, after shifting by `MFD_HUGE_SHIFT`.
+pub const MFD_HUGE_MASK: u32 = 0x3f;
+/// Use 64KiB huge pages.
+pub const MFD_HUGE_64KB: u32 = 16 << MFD_HUGE_SHIFT;
+/// Use 512KiB huge pages.
+pub const MFD_HUGE_512KB: u32 = 19 << MFD_HUGE_SHIFT;
+/// Use 1MiB huge pages.
+pub const MFD_HUGE_1MB: u32 = 20 << MFD_HUGE_SHIFT;
+/// Use 2MiB huge pages.
+pub const MFD_HUGE_2MB: u32 = 21 << MFD_HUGE_SHIFT;
+/// Use 8MiB huge pages.
+pub const MFD_HUGE_8MB: u32 = 23 << MFD_HUGE_SHIFT;
+/// Use 16MiB huge pages.
+pub const MFD_HUGE_16MB: u32 = 24 << MFD_HUGE_SHIFT;
+/// Use 32MiB huge pages.
+pub const MFD_HUGE_32MB: u32 = 25 << MFD_HUGE_SHIFT;
+/// Use 256MiB huge pages.
+pub const MFD_HUGE_256MB: u32 = 28 << MFD_HUGE_SHIFT;
+/// Use 512MiB huge pages.
+pub const MFD_HUGE_512MB: u32 = 29 << MFD_HUGE_SHIFT;
+/// Use 1GiB huge pages.
+pub const MFD_HUGE_1GB: u32 = 30 << MFD_HUGE_SHIFT;
+/// Use 2GiB huge pages.
+pub const MFD_HUGE_2GB: u32 = 31 << MFD_HUGE_SHIFT;
+/// Use 16GiB huge pages.
+pub const MFD_HUGE_16GB: u32 = 34 << MFD_HUGE_SHIFT;
+
+/// Create Anonymous Memory File
+///
+/// Create a new anonymous file that lives in memory, and return a
+/// file-descriptor for it. `name` is used for debugging only (it shows up as
+/// `/memfd:<name>` in `/proc`). The kernel limits it to 249 bytes, excluding
+/// the NUL byte, and returns `EINVAL` otherwise. `flags` takes the `MFD_*`
+/// flags.
+///
+/// If neither `MFD_EXEC` nor `MFD_NOEXEC_SEAL` is passed, the behavior
+/// depends on the `vm.memfd_noexec` sysctl of the kernel.
+pub fn memfd_create(name: &CStr, flags: u32) -> Result<OwnedFd, Errno> {
+    unsafe {
+        super::raw::syscall2_readonly(
+            super::arch::native::nr::MEMFD_CREATE,
+            name.as_ptr() as usize,
+            flags as usize,
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+// `fcntl(2)` commands for file sealing.
+const F_ADD_SEALS: usize = 1033;
+const F_GET_SEALS: usize = 1034;
+
+/// Prevent further seals from being added.
+pub const F_SEAL_SEAL: u32 = 0x0001;
+/// Prevent the file from shrinking.
+pub const F_SEAL_SHRINK: u32 = 0x0002;
+/// Prevent the file from growing.
+pub const F_SEAL_GROW: u32 = 0x0004;
+/// Prevent writes to the file.
+pub const F_SEAL_WRITE: u32 = 0x0008;
+/// Prevent future writes, while allowing existing writable mappings.
+pub const F_SEAL_FUTURE_WRITE: u32 = 0x0010;
+/// Prevent changes to the executable bits of the file mode.
+pub const F_SEAL_EXEC: u32 = 0x0020;
+
+/// File Seal Set
+///
+/// This is a set of `F_SEAL_*` flags, as used by `add_seals()` and
+/// `get_seals()`. Seals restrict the operations allowed on a file, and can
+/// never be removed once added. Unknown seals are preserved, so the set can
+/// carry seals added by newer kernels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Seals(u32);
+
+impl Seals {
+    /// See `F_SEAL_SEAL`.
+    pub const SEAL: Seals = Seals(F_SEAL_SEAL);
+    /// See `F_SEAL_SHRINK`.
+    pub const SHRINK: Seals = Seals(F_SEAL_SHRINK);
+    /// See `F_SEAL_GROW`.
+    pub const GROW: Seals = Seals(F_SEAL_GROW);
+    /// See `F_SEAL_WRITE`.
+    pub const WRITE: Seals = Seals(F_SEAL_WRITE);
+    /// See `F_SEAL_FUTURE_WRITE`.
+    pub const FUTURE_WRITE: Seals = Seals(F_SEAL_FUTURE_WRITE);
+    /// See `F_SEAL_EXEC`.
+    pub const EXEC: Seals = Seals(F_SEAL_EXEC);
+
+    /// Seals that make a file fully immutable, including its set of seals.
+    pub const IMMUTABLE: Seals = Seals(
+        F_SEAL_SEAL | F_SEAL_SHRINK | F_SEAL_GROW | F_SEAL_WRITE,
+    );
+
+    /// Create an empty seal set.
+    pub const fn empty() -> Self {
+        Seals(0)
+    }
+
+    /// Create a seal set from its `F_SEAL_*` flags.
+    pub const fn from_bits(bits: u32) -> Self {
+        Seals(bits)
+    }
+
+    /// Return the `F_SEAL_*` flags of this seal set.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Check whether this set contains all seals of `other`.
+    pub const fn contains(&self, other: Seals) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check whether this set is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl core::ops::BitOr for Seals {
+    type Output = Seals;
+
+    fn bitor(self, rhs: Seals) -> Seals {
+        Seals(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for Seals {
+    fn bitor_assign(&mut self, rhs: Seals) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Add File Seals
+///
+/// Add the seals in `seals` to the file `fd`, using `fcntl(F_ADD_SEALS)`.
+/// Seals already set are ignored. The file must support sealing (e.g., a
+/// memfd created with `MFD_ALLOW_SEALING`), otherwise `EINVAL` is returned.
+/// `EPERM` is returned if `F_SEAL_SEAL` is set, and `EBUSY` if
+/// `F_SEAL_WRITE` is requested while writable shared mappings exist.
+pub fn add_seals(fd: RawFd, seals: Seals) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall3_nomem(
+            super::arch::native::nr::FCNTL,
+            fd as usize,
+            F_ADD_SEALS,
+            seals.bits() as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Get File Seals
+///
+/// Return the seals set on the file `fd`, using `fcntl(F_GET_SEALS)`.
+/// `EINVAL` is returned if the file does not support sealing.
+pub fn get_seals(fd: RawFd) -> Result<Seals, Errno> {
+    unsafe {
+        super::raw::syscall2_nomem(
+            super::arch::native::nr::FCNTL,
+            fd as usize,
+            F_GET_SEALS,
+        ).to_errno_result().map(|v| Seals::from_bits(v as u32))
+    }
+}
+
+/// Pages cannot be accessed.
+pub const PROT_NONE: u32 = 0x0;
+/// Pages can be read.
+pub const PROT_READ: u32 = 0x1;
+/// Pages can be written.
+pub const PROT_WRITE: u32 = 0x2;
+/// Pages can be executed.
+pub const PROT_EXEC: u32 = 0x4;
+/// Extend the change of `mprotect()` down to the start of a grows-down
+/// mapping.
+pub const PROT_GROWSDOWN: u32 = 0x01000000;
+/// Extend the change of `mprotect()` up to the end of a grows-up mapping.
+pub const PROT_GROWSUP: u32 = 0x02000000;
+
+/// Share the mapping with all other mappings of the same object.
+pub const MAP_SHARED: u32 = 0x01;
+/// Create a private copy-on-write mapping.
+pub const MAP_PRIVATE: u32 = 0x02;
+/// Like `MAP_SHARED`, but fail with `EOPNOTSUPP` on unknown flags.
+pub const MAP_SHARED_VALIDATE: u32 = 0x03;
+/// Place the mapping exactly at the given address, replacing any existing
+/// mapping.
+pub const MAP_FIXED: u32 = 0x10;
+/// Map anonymous memory rather than a file. The memory is zeroed.
+pub const MAP_ANONYMOUS: u32 = 0x20;
+/// Place the mapping in the first 2GiB of the address space.
+#[cfg(target_arch = "x86_64")]
+pub const MAP_32BIT: u32 = 0x40;
+/// Create a mapping that grows down, as used for stacks.
+pub const MAP_GROWSDOWN: u32 = 0x0100;
+/// Lock the pages of the mapping, like `mlock2()`.
+pub const MAP_LOCKED: u32 = 0x2000;
+/// Do not reserve swap space for the mapping.
+pub const MAP_NORESERVE: u32 = 0x4000;
+/// Pre-fault the pages of the mapping.
+pub const MAP_POPULATE: u32 = 0x8000;
+/// Do not block on I/O, when combined with `MAP_POPULATE`.
+pub const MAP_NONBLOCK: u32 = 0x10000;
+/// Allocate the mapping at an address suitable for a stack.
+pub const MAP_STACK: u32 = 0x20000;
+/// Back the mapping by huge pages. The page size can be selected via the
+/// `MAP_HUGE_*` constants, which use the same encoding as `MFD_HUGE_*`.
+pub const MAP_HUGETLB: u32 = 0x40000;
+/// Guarantee synchronous page faults for DAX files.
+pub const MAP_SYNC: u32 = 0x80000;
+/// Like `MAP_FIXED`, but fail with `EEXIST` rather than replacing existing
+/// mappings.
+pub const MAP_FIXED_NOREPLACE: u32 = 0x100000;
+/// Shift of the huge page size encoding in the flags of `mmap()`.
+pub const MAP_HUGE_SHIFT: u32 = MFD_HUGE_SHIFT;
+/// Mask of the huge page size encoding, after shifting by `MAP_HUGE_SHIFT`.
+pub const MAP_HUGE_MASK: u32 = MFD_HUGE_MASK;
+/// Use 2MiB huge pages.
+pub const MAP_HUGE_2MB: u32 = MFD_HUGE_2MB;
+/// Use 1GiB huge pages.
+pub const MAP_HUGE_1GB: u32 = MFD_HUGE_1GB;
+
+/// No special treatment (default).
+pub const MADV_NORMAL: u32 = 0;
+/// Expect random page references.
+pub const MADV_RANDOM: u32 = 1;
+/// Expect sequential page references.
+pub const MADV_SEQUENTIAL: u32 = 2;
+/// Expect access in the near future.
+pub const MADV_WILLNEED: u32 = 3;
+/// Release the pages. Private mappings are zero-filled on next access.
+pub const MADV_DONTNEED: u32 = 4;
+/// Release the pages lazily, if under memory pressure.
+pub const MADV_FREE: u32 = 8;
+/// Release the pages and their backing store.
+pub const MADV_REMOVE: u32 = 9;
+/// Do not inherit the mapping across `fork(2)`.
+pub const MADV_DONTFORK: u32 = 10;
+/// Undo `MADV_DONTFORK`.
+pub const MADV_DOFORK: u32 = 11;
+/// Enable same-page merging.
+pub const MADV_MERGEABLE: u32 = 12;
+/// Undo `MADV_MERGEABLE`.
+pub const MADV_UNMERGEABLE: u32 = 13;
+/// Enable transparent huge pages.
+pub const MADV_HUGEPAGE: u32 = 14;
+/// Disable transparent huge pages.
+pub const MADV_NOHUGEPAGE: u32 = 15;
+/// Exclude the pages from core dumps.
+pub const MADV_DONTDUMP: u32 = 16;
+/// Undo `MADV_DONTDUMP`.
+pub const MADV_DODUMP: u32 = 17;
+/// Zero-fill the pages in the child after `fork(2)`.
+pub const MADV_WIPEONFORK: u32 = 18;
+/// Undo `MADV_WIPEONFORK`.
+pub const MADV_KEEPONFORK: u32 = 19;
+/// Deactivate the pages.
+pub const MADV_COLD: u32 = 20;
+/// Reclaim the pages.
+pub const MADV_PAGEOUT: u32 = 21;
+/// Pre-fault the pages readable.
+pub const MADV_POPULATE_READ: u32 = 22;
+/// Pre-fault the pages writable.
+pub const MADV_POPULATE_WRITE: u32 = 23;
+
+/// Allow `mremap()` to move the mapping to a new address.
+pub const MREMAP_MAYMOVE: u32 = 1;
+/// Move the mapping to exactly the given new address. Requires
+/// `MREMAP_MAYMOVE`.
+pub const MREMAP_FIXED: u32 = 2;
+/// Keep the old mapping after moving, but without any pages. Requires
+/// `MREMAP_MAYMOVE`.
+pub const MREMAP_DONTUNMAP: u32 = 4;
+
+/// Lock pages of `mlock2()` only once they are faulted in.
+pub const MLOCK_ONFAULT: u32 = 1;
+
+/// Map Memory
+///
+/// Create a new mapping of `len` bytes in the address space of the calling
+/// task, and return its address. `prot` takes the `PROT_*` flags, and
+/// `flags` takes the `MAP_*` flags. The mapping is backed by the file `fd`,
+/// starting at `offset`, unless `MAP_ANONYMOUS` is passed (in which case `fd`
+/// should be -1 and `offset` 0). Unless `MAP_FIXED` is passed, `addr` is
+/// only a hint for the placement of the mapping.
+///
+/// On x86 and arm, this uses `mmap2(2)`, which takes the offset in units of
+/// 4096 bytes. `offset` must be a multiple of 4096 and is rejected with
+/// `EINVAL` otherwise. On all other architectures, `offset` must be a
+/// multiple of the page size. On x32, it is passed in a single 64-bit
+/// register, like on 64-bit architectures.
+///
+/// # Safety
+///
+/// With `MAP_FIXED`, any existing mapping at the target range is replaced,
+/// so the caller must guarantee that no other code uses that range.
+pub unsafe fn mmap(
+    addr: *mut u8,
+    len: usize,
+    prot: u32,
+    flags: u32,
+    fd: RawFd,
+    offset: i64,
+) -> Result<*mut u8, Errno> {
+    #[cfg(any(target_arch = "x86", target_arch = "arm"))]
+    let r = {
+        if offset & 4095 != 0 {
+            return Err(super::errno::EINVAL);
+        }
+        let v = usize::try_from(offset >> 12).map_err(|_| super::errno::EOVERFLOW)?;
+        super::raw::syscall6(
+            super::arch::native::nr::MMAP2,
+            addr as usize,
+            len,
+            prot as usize,
+            flags as usize,
+            fd as usize,
+            v,
+        )
+    };
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let r = super::raw::Retval::from_usize(super::arch::native::syscall::syscall6_u64(
+        super::arch::native::nr::MMAP,
+        addr as usize as u64,
+        len as u64,
+        prot as u64,
+        flags as u64,
+        fd as u64,
+        offset as u64,
+    ));
+
+    #[cfg(target_pointer_width = "64")]
+    let r = super::raw::syscall6(
+        super::arch::native::nr::MMAP,
+        addr as usize,
+        len,
+        prot as usize,
+        flags as usize,
+        fd as usize,
+        offset as usize,
+    );
+
+    r.to_errno_result().map(|v| v as *mut u8)
+}
+
+/// Unmap Memory
+///
+/// Remove all mappings in the range of `len` bytes at `addr`. It is not an
+/// error if the range does not contain any mappings.
+///
+/// # Safety
+///
+/// The caller must guarantee that no other code uses the range.
+pub unsafe fn munmap(addr: *mut u8, len: usize) -> Result<(), Errno> {
+    super::raw::syscall2(
+        super::arch::native::nr::MUNMAP,
+        addr as usize,
+        len,
+    ).to_errno_result().map(|_| ())
+}
+
+/// Change Memory Protection
+///
+/// Change the protection of all pages in the range of `len` bytes at `addr`
+/// to `prot`, which takes the `PROT_*` flags.
+///
+/// # Safety
+///
+/// The caller must guarantee that no other code relies on the previous
+/// protection of the range.
+pub unsafe fn mprotect(addr: *mut u8, len: usize, prot: u32) -> Result<(), Errno> {
+    super::raw::syscall3(
+        super::arch::native::nr::MPROTECT,
+        addr as usize,
+        len,
+        prot as usize,
+    ).to_errno_result().map(|_| ())
+}
+
+/// Remap Memory
+///
+/// Resize the mapping at `old_addr` from `old_len` to `new_len` bytes, and
+/// return its (possibly new) address. `flags` takes the `MREMAP_*` flags.
+/// Unless `MREMAP_MAYMOVE` is passed, the mapping is resized in place, or
+/// `ENOMEM` is returned. `new_addr` is only used with `MREMAP_FIXED`.
+///
+/// # Safety
+///
+/// The caller must guarantee that no other code uses the old range, or the
+/// new range if `MREMAP_FIXED` is passed.
+pub unsafe fn mremap(
+    old_addr: *mut u8,
+    old_len: usize,
+    new_len: usize,
+    flags: u32,
+    new_addr: *mut u8,
+) -> Result<*mut u8, Errno> {
+    super::raw::syscall5(
+        super::arch::native::nr::MREMAP,
+        old_addr as usize,
+        old_len,
+        new_len,
+        flags as usize,
+        new_addr as usize,
+    ).to_errno_result().map(|v| v as *mut u8)
+}
+
+/// Advise Memory Usage
+///
+/// Give advice about the use of the range of `len` bytes at `addr`.
+/// `advice` takes one of the `MADV_*` constants.
+///
+/// # Safety
+///
+/// Some advice changes the content of the range (e.g., `MADV_DONTNEED`
+/// discards the content of private mappings), so the caller must guarantee
+/// that no other code relies on it.
+pub unsafe fn madvise(addr: *mut u8, len: usize, advice: u32) -> Result<(), Errno> {
+    super::raw::syscall3(
+        super::arch::native::nr::MADVISE,
+        addr as usize,
+        len,
+        advice as usize,
+    ).to_errno_result().map(|_| ())
+}
+
+/// Lock Memory
+///
+/// Lock all pages in the range of `len` bytes at `addr` into memory. `flags`
+/// can be 0 or `MLOCK_ONFAULT`.
+pub fn mlock2(addr: *const u8, len: usize, flags: u32) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::MLOCK2,
+            addr as usize,
+            len,
+            flags as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Change Program Break
+///
+/// Set the end of the data segment of the calling process to `addr`, and
+/// return the new end. If `addr` is invalid or the change fails, the current
+/// end is returned unchanged. Hence, `brk(0)` returns the current end.
+///
+/// # Safety
+///
+/// Shrinking the data segment unmaps memory, so the caller must guarantee
+/// that no other code uses the released range. Moreover, the program break
+/// is a process-wide resource, so the caller must coordinate with any other
+/// user of it (e.g., a libc allocator).
+pub unsafe fn brk(addr: usize) -> usize {
+    super::raw::syscall1(
+        super::arch::native::nr::BRK,
+        addr,
+    ).unwrap()
+}
+
+/// Wait on a futex.
+pub const FUTEX_WAIT: u32 = 0;
+/// Wake waiters of a futex.
+pub const FUTEX_WAKE: u32 = 1;
+/// The futex is private to the calling process.
+pub const FUTEX_PRIVATE_FLAG: u32 = 128;
+
+/// Wait on Futex
+///
+/// Atomically check that `uaddr` still contains `val`, and sleep until woken
+/// up via `futex_wake()`. If the value differs, `EAGAIN` is returned
+/// immediately. `EINTR` is returned if the sleep was interrupted by a
+/// signal. Spurious wake-ups are possible, so the caller must re-check the
+/// condition it waits for.
+///
+/// If `private` is true, the futex is only matched against waiters and
+/// wakers of the calling process, which is faster but does not work across
+/// shared mappings.
+pub fn futex_wait(
+    uaddr: &core::sync::atomic::AtomicU32,
+    val: u32,
+    private: bool,
+) -> Result<(), Errno> {
+    let op = FUTEX_WAIT | if private { FUTEX_PRIVATE_FLAG } else { 0 };
+
+    unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::FUTEX,
+            uaddr as *const core::sync::atomic::AtomicU32 as usize,
+            op as usize,
+            val as usize,
+            0,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Wake Futex
+///
+/// Wake up to `n` waiters sleeping on `uaddr` via `futex_wait()`, and return
+/// the number of woken waiters. See `futex_wait()` for `private`.
+pub fn futex_wake(
+    uaddr: &core::sync::atomic::AtomicU32,
+    n: u32,
+    private: bool,
+) -> Result<usize, Errno> {
+    let op = FUTEX_WAKE | if private { FUTEX_PRIVATE_FLAG } else { 0 };
+
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::FUTEX,
+            uaddr as *const core::sync::atomic::AtomicU32 as usize,
+            op as usize,
+            n as usize,
+        ).to_errno_result()
+    }
+}
+
+/// Set the time namespace of the new task (since linux-5.6).
+pub const CLONE_NEWTIME: u64 = 0x80;
+/// Share the address space with the new task.
+pub const CLONE_VM: u64 = 0x100;
+/// Share the file-system information (root, working directory, umask).
+pub const CLONE_FS: u64 = 0x200;
+/// Share the file-descriptor table.
+pub const CLONE_FILES: u64 = 0x400;
+/// Share the signal handlers.
+pub const CLONE_SIGHAND: u64 = 0x800;
+/// Return a pidfd of the new task via `pidfd` (since linux-5.2).
+pub const CLONE_PIDFD: u64 = 0x1000;
+/// Continue tracing the new task, if the calling task is traced.
+pub const CLONE_PTRACE: u64 = 0x2000;
+/// Suspend the calling task until the new task exits or executes.
+pub const CLONE_VFORK: u64 = 0x4000;
+/// Make the new task a sibling rather than a child of the calling task.
+pub const CLONE_PARENT: u64 = 0x8000;
+/// Place the new task in the thread group of the calling task.
+pub const CLONE_THREAD: u64 = 0x10000;
+/// Create a new mount namespace.
+pub const CLONE_NEWNS: u64 = 0x20000;
+/// Share the System V semaphore adjustment values.
+pub const CLONE_SYSVSEM: u64 = 0x40000;
+/// Set the TLS pointer of the new task to `tls`.
+pub const CLONE_SETTLS: u64 = 0x80000;
+/// Store the task ID of the new task at `parent_tid`.
+pub const CLONE_PARENT_SETTID: u64 = 0x100000;
+/// Clear `child_tid` and wake a futex on it when the new task exits.
+pub const CLONE_CHILD_CLEARTID: u64 = 0x200000;
+/// Do not notify the tracer of the new task.
+pub const CLONE_UNTRACED: u64 = 0x800000;
+/// Store the task ID of the new task at `child_tid` in the new task.
+pub const CLONE_CHILD_SETTID: u64 = 0x1000000;
+/// Create a new cgroup namespace.
+pub const CLONE_NEWCGROUP: u64 = 0x2000000;
+/// Create a new UTS namespace.
+pub const CLONE_NEWUTS: u64 = 0x4000000;
+/// Create a new IPC namespace.
+pub const CLONE_NEWIPC: u64 = 0x8000000;
+/// Create a new user namespace.
+pub const CLONE_NEWUSER: u64 = 0x10000000;
+/// Create a new PID namespace.
+pub const CLONE_NEWPID: u64 = 0x20000000;
+/// Create a new network namespace.
+pub const CLONE_NEWNET: u64 = 0x40000000;
+/// Share the I/O context.
+pub const CLONE_IO: u64 = 0x80000000;
+/// Reset all signal handlers to their default (since linux-5.5).
+pub const CLONE_CLEAR_SIGHAND: u64 = 0x100000000;
+/// Place the new task in the cgroup `cgroup` (since linux-5.7).
+pub const CLONE_INTO_CGROUP: u64 = 0x200000000;
+
+/// Size of the first revision of `CloneArgs`.
+pub const CLONE_ARGS_SIZE_VER0: usize = 64;
+/// Size of the second revision of `CloneArgs`, adding `set_tid`.
+pub const CLONE_ARGS_SIZE_VER1: usize = 80;
+/// Size of the third revision of `CloneArgs`, adding `cgroup`.
+pub const CLONE_ARGS_SIZE_VER2: usize = 88;
+
+/// Clone Parameters
+///
+/// This is the extensible argument structure of `clone3()`. `flags` takes
+/// the `CLONE_*` flags, and all pointers are passed as 64-bit integers,
+/// regardless of the architecture:
+///
+///  * `pidfd`: Address of an `i32` to store the pidfd at, with `CLONE_PIDFD`.
+///  * `child_tid`: Address of an `i32` for `CLONE_CHILD_SETTID` and
+///    `CLONE_CHILD_CLEARTID`.
+///  * `parent_tid`: Address of an `i32` for `CLONE_PARENT_SETTID`.
+///  * `exit_signal`: Signal sent to the parent when the new task exits. Must
+///    be 0 with `CLONE_THREAD`.
+///  * `stack`, `stack_size`: Lowest address and size of the stack of the new
+///    task. If 0, the new task uses the stack pointer of the calling task.
+///  * `tls`: TLS pointer of the new task, with `CLONE_SETTLS`.
+///  * `set_tid`, `set_tid_size`: Address and length of an array of task IDs
+///    to request for the new task in each nested PID namespace.
+///  * `cgroup`: File-descriptor of the cgroup for `CLONE_INTO_CGROUP`.
+///
+/// Like `OpenHow`, the kernel identifies the revision of this structure by
+/// its size.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CloneArgs {
+    pub flags: u64,
+    pub pidfd: u64,
+    pub child_tid: u64,
+    pub parent_tid: u64,
+    pub exit_signal: u64,
+    pub stack: u64,
+    pub stack_size: u64,
+    pub tls: u64,
+    pub set_tid: u64,
+    pub set_tid_size: u64,
+    pub cgroup: u64,
+}
+
+/// Clone Task
+///
+/// Create a new task as described by `args`. Like `fork()`, the new task
+/// returns from this system call with `None`, while the calling task gets the
+/// task ID of the new task.
+///
+/// # Safety
+///
+/// The new task continues execution on the stack given in `args`, or on a
+/// copy of the stack of the calling task if none is given. Unless it has its
+/// own copy of the address space and stack (i.e., neither `CLONE_VM` nor a
+/// separate stack is used), the new task must not return from this function,
+/// since it would corrupt the state of the calling task or execute on a stack
+/// without any frames. Use `thread::trampoline()` to run code on a separate
+/// stack instead.
+///
+/// Furthermore, all addresses in `args` must be valid for the respective
+/// flags.
+pub unsafe fn clone3(args: &CloneArgs) -> Result<Option<Pid>, Errno> {
+    super::raw::syscall2(
+        super::arch::native::nr::CLONE3,
+        args as *const CloneArgs as usize,
+        core::mem::size_of::<CloneArgs>(),
+    ).to_errno_result().map(|v| {
+        let p = Pid::try_from(v).unwrap();
+        match p {
+            0 => None,
+            _ => Some(p),
+        }
+    })
+}
+
+/// Hangup.
+pub const SIGHUP: u32 = 1;
+/// Interrupt from keyboard.
+pub const SIGINT: u32 = 2;
+/// Quit from keyboard.
+pub const SIGQUIT: u32 = 3;
+/// Illegal instruction.
+pub const SIGILL: u32 = 4;
+/// Trace or breakpoint trap.
+pub const SIGTRAP: u32 = 5;
+/// Abort.
+pub const SIGABRT: u32 = 6;
+/// Bus error.
+pub const SIGBUS: u32 = 7;
+/// Floating-point exception.
+pub const SIGFPE: u32 = 8;
+/// Kill (cannot be caught or ignored).
+pub const SIGKILL: u32 = 9;
+/// User-defined signal 1.
+pub const SIGUSR1: u32 = 10;
+/// Invalid memory reference.
+pub const SIGSEGV: u32 = 11;
+/// User-defined signal 2.
+pub const SIGUSR2: u32 = 12;
+/// Broken pipe.
+pub const SIGPIPE: u32 = 13;
+/// Timer signal of `alarm(2)`.
+pub const SIGALRM: u32 = 14;
+/// Termination.
+pub const SIGTERM: u32 = 15;
+/// Stack fault on coprocessor.
+pub const SIGSTKFLT: u32 = 16;
+/// Child stopped, continued, or terminated.
+pub const SIGCHLD: u32 = 17;
+/// Continue if stopped.
+pub const SIGCONT: u32 = 18;
+/// Stop (cannot be caught or ignored).
+pub const SIGSTOP: u32 = 19;
+/// Stop from terminal.
+pub const SIGTSTP: u32 = 20;
+/// Terminal input for background process.
+pub const SIGTTIN: u32 = 21;
+/// Terminal output for background process.
+pub const SIGTTOU: u32 = 22;
+/// Urgent condition on socket.
+pub const SIGURG: u32 = 23;
+/// CPU time limit exceeded.
+pub const SIGXCPU: u32 = 24;
+/// File size limit exceeded.
+pub const SIGXFSZ: u32 = 25;
+/// Virtual alarm clock.
+pub const SIGVTALRM: u32 = 26;
+/// Profiling timer expired.
+pub const SIGPROF: u32 = 27;
+/// Window resize.
+pub const SIGWINCH: u32 = 28;
+/// I/O now possible.
+pub const SIGIO: u32 = 29;
+/// Power failure.
+pub const SIGPWR: u32 = 30;
+/// Bad system call.
+pub const SIGSYS: u32 = 31;
+
+/// Return a non-blocking pidfd (since linux-5.10).
+pub const PIDFD_NONBLOCK: u32 = O_NONBLOCK;
+/// Return a pidfd referring to a thread rather than a process (since
+/// linux-6.9).
+pub const PIDFD_THREAD: u32 = O_EXCL;
+
+/// Send the signal to the thread referred to by the pidfd (since linux-6.9).
+pub const PIDFD_SIGNAL_THREAD: u32 = 1;
+/// Send the signal to the thread group of the pidfd (since linux-6.9).
+pub const PIDFD_SIGNAL_THREAD_GROUP: u32 = 2;
+/// Send the signal to the process group of the pidfd (since linux-6.9).
+pub const PIDFD_SIGNAL_PROCESS_GROUP: u32 = 4;
+
+/// Request `pid`, `tgid`, and `ppid` of `PidfdInfo`.
+pub const PIDFD_INFO_PID: u64 = 0x1;
+/// Request the user and group IDs of `PidfdInfo`.
+pub const PIDFD_INFO_CREDS: u64 = 0x2;
+/// Request `cgroupid` of `PidfdInfo`.
+pub const PIDFD_INFO_CGROUPID: u64 = 0x4;
+/// Request `exit_code` of `PidfdInfo` (since linux-6.15).
+pub const PIDFD_INFO_EXIT: u64 = 0x8;
+
+/// Size of the first revision of `PidfdInfo`.
+pub const PIDFD_INFO_SIZE_VER0: usize = 64;
+
+// `_IOWR(0xff, 11, struct pidfd_info)`, using the size of the first
+// revision. The kernel accepts any size and only copies the common prefix.
+const PIDFD_GET_INFO: usize = 0xc040ff0b;
+
+/// Pidfd Information
+///
+/// This is the extensible argument structure of `pidfd_get_info()`. The
+/// caller sets `mask` to the `PIDFD_INFO_*` flags of the requested fields,
+/// and the kernel sets it to the fields actually returned (which can include
+/// fields that were not requested). `PIDFD_INFO_PID` and `PIDFD_INFO_CREDS`
+/// are always returned.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PidfdInfo {
+    pub mask: u64,
+    pub cgroupid: u64,
+    pub pid: u32,
+    pub tgid: u32,
+    pub ppid: u32,
+    pub ruid: u32,
+    pub rgid: u32,
+    pub euid: u32,
+    pub egid: u32,
+    pub suid: u32,
+    pub sgid: u32,
+    pub fsuid: u32,
+    pub fsgid: u32,
+    pub exit_code: i32,
+}
+
+/// Open Pidfd
+///
+/// Return a new file-descriptor referring to the process `pid`. Unlike the
+/// PID, the file-descriptor keeps referring to the same process, even after
+/// it exited and its PID was reused. `flags` takes `PIDFD_NONBLOCK` and
+/// `PIDFD_THREAD`. The file-descriptor always has `O_CLOEXEC` set.
+pub fn pidfd_open(pid: Pid, flags: u32) -> Result<OwnedFd, Errno> {
+    unsafe {
+        super::raw::syscall2(
+            super::arch::native::nr::PIDFD_OPEN,
+            pid as usize,
+            flags as usize,
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+/// Send Signal via Pidfd
+///
+/// Send the signal `sig` to the process referred to by `pidfd`, as if sent
+/// via `kill(2)`. `flags` takes the `PIDFD_SIGNAL_*` flags. Passing a
+/// `siginfo_t` is not supported.
+pub fn pidfd_send_signal(pidfd: RawFd, sig: u32, flags: u32) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::PIDFD_SEND_SIGNAL,
+            pidfd as usize,
+            sig as usize,
+            0,
+            flags as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Duplicate File-Descriptor of Other Process
+///
+/// Return a duplicate of the file-descriptor `targetfd` of the process
+/// referred to by `pidfd`. The caller needs ptrace access to the process.
+/// `flags` must be 0. The new file-descriptor always has `O_CLOEXEC` set.
+pub fn pidfd_getfd(pidfd: RawFd, targetfd: RawFd, flags: u32) -> Result<OwnedFd, Errno> {
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::PIDFD_GETFD,
+            pidfd as usize,
+            targetfd as usize,
+            flags as usize,
+        ).to_errno_result().map(owned_fd)
+    }
+}
+
+/// Query Pidfd Information
+///
+/// Return information about the process referred to by `pidfd`, via the
+/// `PIDFD_GET_INFO` ioctl. `mask` takes the `PIDFD_INFO_*` flags of the
+/// requested fields. Fails with `ENOTTY` before linux-6.13, and with `ESRCH`
+/// if the process was already reaped (unless `PIDFD_INFO_EXIT` is requested
+/// and available).
+pub fn pidfd_get_info(pidfd: RawFd, mask: u64) -> Result<PidfdInfo, Errno> {
+    let mut info = PidfdInfo { mask, ..Default::default() };
+
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::IOCTL,
+            pidfd as usize,
+            PIDFD_GET_INFO,
+            &mut info as *mut PidfdInfo as usize,
+        ).to_errno_result()?;
+    }
+
+    Ok(info)
+}
+
+/// Wait for any child.
+pub const P_ALL: u32 = 0;
+/// Wait for the child with the given PID.
+pub const P_PID: u32 = 1;
+/// Wait for any child in the given process group.
+pub const P_PGID: u32 = 2;
+/// Wait for the child referred to by the given pidfd (since linux-5.4).
+pub const P_PIDFD: u32 = 3;
+
+/// Return immediately if no child changed state.
+pub const WNOHANG: u32 = 0x1;
+/// Wait for children that were stopped by a signal.
+pub const WSTOPPED: u32 = 0x2;
+/// Alias of `WSTOPPED`, as used with `wait4()`.
+pub const WUNTRACED: u32 = WSTOPPED;
+/// Wait for children that exited.
+pub const WEXITED: u32 = 0x4;
+/// Wait for stopped children that were continued via `SIGCONT`.
+pub const WCONTINUED: u32 = 0x8;
+/// Leave the child in a waitable state.
+pub const WNOWAIT: u32 = 0x1000000;
+/// Only wait for children of the calling thread, not of other threads of
+/// its thread group.
+pub const __WNOTHREAD: u32 = 0x20000000;
+/// Wait for all children, regardless of their exit signal.
+pub const __WALL: u32 = 0x40000000;
+/// Only wait for children with an exit signal other than `SIGCHLD`.
+pub const __WCLONE: u32 = 0x80000000;
+
+/// Child exited.
+pub const CLD_EXITED: i32 = 1;
+/// Child was killed.
+pub const CLD_KILLED: i32 = 2;
+/// Child was killed and dumped core.
+pub const CLD_DUMPED: i32 = 3;
+/// Traced child has trapped.
+pub const CLD_TRAPPED: i32 = 4;
+/// Child was stopped.
+pub const CLD_STOPPED: i32 = 5;
+/// Stopped child was continued.
+pub const CLD_CONTINUED: i32 = 6;
+
+/// Signal Information
+///
+/// This is the `siginfo_t` structure, limited to the fields used for
+/// `SIGCHLD`, as returned by `waitid()`. `code` is one of `CLD_*`, and
+/// `status` is the exit-code for `CLD_EXITED`, and the signal number
+/// otherwise.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Siginfo {
+    pub signo: i32,
+    pub errno: i32,
+    pub code: i32,
+    #[cfg(target_pointer_width = "64")]
+    _pad: i32,
+    pub pid: Pid,
+    pub uid: u32,
+    pub status: i32,
+    #[cfg(target_pointer_width = "64")]
+    _spare: [u32; 25],
+    #[cfg(target_pointer_width = "32")]
+    _spare: [u32; 26],
+}
+
+impl Default for Siginfo {
+    fn default() -> Self {
+        // SAFETY: All fields are plain integers.
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+impl Siginfo {
+    /// Decode the state change of a child reported via `SIGCHLD` (or
+    /// `waitid()`). Returns `None` if `code` is not one of `CLD_*`.
+    pub fn exit_status(&self) -> Option<ExitStatus> {
+        let sig = self.status as u32;
+
+        match self.code {
+            CLD_EXITED => Some(ExitStatus::Exited(self.status as u8)),
+            CLD_KILLED => Some(ExitStatus::Killed(sig)),
+            CLD_DUMPED => Some(ExitStatus::Dumped(sig)),
+            CLD_TRAPPED => Some(ExitStatus::Trapped(sig)),
+            CLD_STOPPED => Some(ExitStatus::Stopped(sig)),
+            CLD_CONTINUED => Some(ExitStatus::Continued),
+            _ => None,
+        }
+    }
+}
+
+/// Child State Change
+///
+/// This is the decoded state change of a child, as returned by `waitid()`
+/// (via `Siginfo`) and `wait4()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExitStatus {
+    /// The child exited with the given exit-code.
+    Exited(u8),
+    /// The child was killed by the given signal.
+    Killed(u32),
+    /// The child was killed by the given signal and dumped core.
+    Dumped(u32),
+    /// The traced child trapped with the given signal.
+    Trapped(u32),
+    /// The child was stopped by the given signal.
+    Stopped(u32),
+    /// The stopped child was continued.
+    Continued,
+}
+
+impl ExitStatus {
+    /// Decode the status returned by `wait4()`. Returns `None` for values
+    /// the kernel does not produce.
+    ///
+    /// Unlike `waitid()`, `wait4()` does not tell stopped and trapped
+    /// children apart, so `Trapped` is never returned. For traced children,
+    /// the upper bits of the stop signal carry the ptrace event, which is
+    /// discarded.
+    pub fn from_wait_status(status: i32) -> Option<Self> {
+        let sig = (status & 0x7f) as u32;
+        let high = ((status >> 8) & 0xff) as u32;
+
+        if status == 0xffff {
+            Some(ExitStatus::Continued)
+        } else if status & 0xff == 0x7f {
+            Some(ExitStatus::Stopped(high))
+        } else if sig == 0 {
+            Some(ExitStatus::Exited(high as u8))
+        } else if status & !0xff != 0 {
+            None
+        } else if status & 0x80 != 0 {
+            Some(ExitStatus::Dumped(sig))
+        } else {
+            Some(ExitStatus::Killed(sig))
+        }
+    }
+
+    /// Check whether the child exited with an exit-code of 0.
+    pub fn success(&self) -> bool {
+        *self == ExitStatus::Exited(0)
+    }
+
+    /// Return the exit-code, if the child exited.
+    pub fn code(&self) -> Option<u8> {
+        match *self {
+            ExitStatus::Exited(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Return the signal that killed the child, if any.
+    pub fn signal(&self) -> Option<u32> {
+        match *self {
+            ExitStatus::Killed(v) | ExitStatus::Dumped(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Resource Usage
+///
+/// This is the `rusage` structure, as returned by `wait4()` and `waitid()`
+/// for the reaped child (and its reaped descendants). See `getrusage(2)`
+/// for a description of the fields; several of them are unused by linux and
+/// always 0. Its time values and integers are `vdso::Timeval` and
+/// `vdso::KernelLong`, which are 64-bit wide on x32.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rusage {
+    pub utime: Timeval,
+    pub stime: Timeval,
+    pub maxrss: KernelLong,
+    pub ixrss: KernelLong,
+    pub idrss: KernelLong,
+    pub isrss: KernelLong,
+    pub minflt: KernelLong,
+    pub majflt: KernelLong,
+    pub nswap: KernelLong,
+    pub inblock: KernelLong,
+    pub oublock: KernelLong,
+    pub msgsnd: KernelLong,
+    pub msgrcv: KernelLong,
+    pub nsignals: KernelLong,
+    pub nvcsw: KernelLong,
+    pub nivcsw: KernelLong,
+}
+
+/// Wait for Child
+///
+/// Wait for a child of the calling process to change state. `idtype` is one
+/// of `P_*`, and selects the children to wait for via `id`:
+///
+///  * `P_ALL`: Any child. `id` is ignored.
+///  * `P_PID`: The child with the PID `id`.
+///  * `P_PGID`: Any child in the process group `id`, or in the process group
+///    of the calling process if `id` is 0.
+///  * `P_PIDFD`: The child referred to by the pidfd `id`.
+///
+/// `options` takes the `W*` flags, and must contain at least one of the
+/// state changes to wait for (`WEXITED`, `WSTOPPED`, or `WCONTINUED`). Use
+/// `Siginfo::exit_status()` to decode the returned state change.
+///
+/// If `rusage` is given, the resource usage of the child is stored in it.
+/// With `WNOHANG`, `None` is returned if no selected child changed state.
+pub fn waitid(
+    idtype: u32,
+    id: u32,
+    options: u32,
+    rusage: Option<&mut Rusage>,
+) -> Result<Option<Siginfo>, Errno> {
+    let mut info = Siginfo::default();
+
+    // On x32, `waitid(2)` is a compat system call, which uses the 32-bit
+    // layout of `rusage` (unlike `wait4(2)`). It is converted afterwards.
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let mut compat = [0i32; 18];
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    let ru = if rusage.is_some() { compat.as_mut_ptr() as usize } else { 0 };
+    #[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+    let ru = rusage.map_or(0, |v| v as *mut Rusage as usize);
+
+    unsafe {
+        super::raw::syscall5(
+            super::arch::native::nr::WAITID,
+            idtype as usize,
+            id as usize,
+            &mut info as *mut Siginfo as usize,
+            options as usize,
+            ru,
+        ).to_errno_result()?;
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    if let Some(v) = rusage {
+        // `Rusage` consists of 18 64-bit integers on x32.
+        let v = unsafe { &mut *(v as *mut Rusage as *mut [i64; 18]) };
+        for (d, s) in v.iter_mut().zip(compat) {
+            *d = s as i64;
+        }
+    }
+
+    Ok(if info.pid == 0 { None } else { Some(info) })
+}
+
+/// Wait for Child (Legacy)
+///
+/// Wait for a child of the calling process to change state. `pid` selects
+/// the children to wait for:
+///
+///  * `< -1`: Any child in the process group `-pid`.
+///  * `-1`: Any child.
+///  * `0`: Any child in the process group of the calling process.
+///  * `> 0`: The child with the PID `pid`.
+///
+/// `options` takes `WNOHANG`, `WUNTRACED`, `WCONTINUED`, and the `__W*`
+/// flags. Children that exited are always reported.
+///
+/// On success, the PID of the child is returned together with its state
+/// change. If `rusage` is given, the resource usage of the child is stored
+/// in it. With `WNOHANG`, `None` is returned if no selected child changed
+/// state.
+///
+/// Unlike `waitid()`, the state change is encoded in an integer, which
+/// loses information (see `ExitStatus::from_wait_status()`). If the kernel
+/// returns a value that cannot be decoded, `EINVAL` is returned.
+pub fn wait4(
+    pid: Pid,
+    options: u32,
+    rusage: Option<&mut Rusage>,
+) -> Result<Option<(Pid, ExitStatus)>, Errno> {
+    let mut status: i32 = 0;
+
+    let r = unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::WAIT4,
+            pid as usize,
+            &mut status as *mut i32 as usize,
+            options as usize,
+            rusage.map_or(0, |v| v as *mut Rusage as usize),
+        ).to_errno_result()?
+    };
+
+    if r == 0 {
+        return Ok(None);
+    }
+
+    match ExitStatus::from_wait_status(status) {
+        Some(v) => Ok(Some((r as Pid, v))),
+        None => Err(super::errno::EINVAL),
+    }
+}
+
+/// Execute Program
+///
+/// Replace the program of the calling process with the program at `path`.
+/// `argv` and `envp` are NULL-terminated arrays of pointers to
+/// NUL-terminated strings, which are passed to the new program as its
+/// arguments and environment.
+///
+/// On success, this never returns. Otherwise, the error is returned exactly
+/// as reported by the kernel.
+///
+/// # Safety
+///
+/// `argv` and `envp` must be valid NULL-terminated arrays of pointers to
+/// NUL-terminated strings (see `exec::ArgBuf` for a safe way to build them).
+pub unsafe fn execve(
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+) -> Errno {
+    let r = super::raw::syscall3(
+        super::arch::native::nr::EXECVE,
+        path.as_ptr() as usize,
+        argv as usize,
+        envp as usize,
+    ).to_errno_result();
+
+    match r {
+        Ok(_) => core::unreachable!("`r_linux::syscall::api::execve()` unexpectedly returned"),
+        Err(e) => e,
+    }
+}
+
+/// Execute Program Relative to Directory
+///
+/// Like `execve()`, but `path` is resolved relative to `dirfd` (or the
+/// current working directory if `AT_FDCWD`). `flags` takes
+/// `AT_SYMLINK_NOFOLLOW` and `AT_EMPTY_PATH`. With `AT_EMPTY_PATH` and an
+/// empty `path`, the file referred to by `dirfd` is executed (e.g., a sealed
+/// memfd).
+///
+/// # Safety
+///
+/// See `execve()`.
+pub unsafe fn execveat(
+    dirfd: RawFd,
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    flags: u32,
+) -> Errno {
+    let r = super::raw::syscall5(
+        super::arch::native::nr::EXECVEAT,
+        dirfd as usize,
+        path.as_ptr() as usize,
+        argv as usize,
+        envp as usize,
+        flags as usize,
+    ).to_errno_result();
+
+    match r {
+        Ok(_) => core::unreachable!("`r_linux::syscall::api::execveat()` unexpectedly returned"),
+        Err(e) => e,
+    }
+}
+
+/// Close File-Descriptor
+///
+/// Close the file-descriptor `fd`. See `CLOSE` for the semantics of the
+/// return value. In particular, the file-descriptor is released even if an
+/// error is returned, so this must never be retried.
+///
+/// # Safety
+///
+/// `fd` must not be owned by anyone else (e.g., an `OwnedFd`), since it is
+/// released by this operation and its number can be reused right away.
+pub unsafe fn close(fd: RawFd) -> Result<(), Errno> {
+    super::raw::syscall1_nomem(
+        super::arch::native::nr::CLOSE,
+        fd as usize,
+    ).to_errno_result().map(|_| ())
+}
+
+/// Unshare the file-descriptor table before closing.
+pub const CLOSE_RANGE_UNSHARE: u32 = 0x2;
+/// Set `FD_CLOEXEC` on all file-descriptors of the range rather than
+/// closing them right away.
+pub const CLOSE_RANGE_CLOEXEC: u32 = 0x4;
+
+/// Close File-Descriptor Range
+///
+/// Close all file-descriptors from `first` to `last` (inclusive). `flags`
+/// takes the `CLOSE_RANGE_*` flags. Requires linux-5.9 or newer.
+///
+/// # Safety
+///
+/// No file-descriptor in the range must be owned by anyone else, unless
+/// `CLOSE_RANGE_CLOEXEC` is passed, or `CLOSE_RANGE_UNSHARE` is passed and
+/// the owners only ever use them in other tasks (see `close()`).
+pub unsafe fn close_range(first: u32, last: u32, flags: u32) -> Result<(), Errno> {
+    super::raw::syscall3_nomem(
+        super::arch::native::nr::CLOSE_RANGE,
+        first as usize,
+        last as usize,
+        flags as usize,
+    ).to_errno_result().map(|_| ())
+}
+
+/// Duplicate File-Descriptor
+///
+/// Make `newfd` refer to the same open file-description as `oldfd`, and
+/// return ownership of it. If `newfd` is open, it is closed first. `flags`
+/// can be 0 or `O_CLOEXEC`. Unlike `dup2(2)`, `EINVAL` is returned if both
+/// are equal.
+///
+/// # Safety
+///
+/// `newfd` must not be negative. If it is open, it must not be owned by
+/// anyone else, since it is closed by this operation.
+pub unsafe fn dup3(oldfd: RawFd, newfd: RawFd, flags: u32) -> Result<OwnedFd, Errno> {
+    super::raw::syscall3_nomem(
+        super::arch::native::nr::DUP3,
+        oldfd as usize,
+        newfd as usize,
+        flags as usize,
+    ).to_errno_result().map(owned_fd)
+}
+
+/// Duplicate to the lowest available file-descriptor not below `arg`.
+pub const F_DUPFD: u32 = 0;
+/// Get the file-descriptor flags (see `FD_CLOEXEC`).
+pub const F_GETFD: u32 = 1;
+/// Set the file-descriptor flags (see `FD_CLOEXEC`).
+pub const F_SETFD: u32 = 2;
+/// Get the file status flags (see `O_*`).
+pub const F_GETFL: u32 = 3;
+/// Set the file status flags (see `O_*`).
+pub const F_SETFL: u32 = 4;
+/// Like `F_DUPFD`, but set `FD_CLOEXEC` on the new file-descriptor.
+pub const F_DUPFD_CLOEXEC: u32 = 1030;
+
+/// The file-descriptor is closed on `exec`.
+pub const FD_CLOEXEC: u32 = 1;
+
+/// Control File-Descriptor
+///
+/// Perform the operation `cmd` on `fd` with the integer argument `arg`.
+/// This supports the `F_*` commands defined in this module. Commands taking
+/// a pointer argument (e.g., file locks) need dedicated wrappers.
+pub fn fcntl(fd: RawFd, cmd: u32, arg: u32) -> Result<usize, Errno> {
+    match cmd {
+        F_DUPFD | F_GETFD | F_SETFD | F_GETFL | F_SETFL | F_DUPFD_CLOEXEC => {},
+        _ => return Err(super::errno::EINVAL),
+    }
+
+    unsafe {
+        super::raw::syscall3_nomem(
+            super::arch::native::nr::FCNTL,
+            fd as usize,
+            cmd as usize,
+            arg as usize,
+        ).to_errno_result()
+    }
+}
+
+/// Create Pipe
+///
+/// Create a new pipe and return its read and write end (in this order).
+/// `flags` takes `O_CLOEXEC`, `O_NONBLOCK`, and `O_DIRECT`.
+pub fn pipe2(flags: u32) -> Result<(OwnedFd, OwnedFd), Errno> {
+    let mut fds: [RawFd; 2] = [-1, -1];
+
+    unsafe {
+        super::raw::syscall2(
+            super::arch::native::nr::PIPE2,
+            fds.as_mut_ptr() as usize,
+            flags as usize,
+        ).to_errno_result()?;
+
+        Ok((OwnedFd::from_raw(fds[0]), OwnedFd::from_raw(fds[1])))
+    }
+}
+
+/// Change Working Directory
+///
+/// Change the working directory of the calling process to `path`.
+pub fn chdir(path: &CStr) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall1_readonly(
+            super::arch::native::nr::CHDIR,
+            path.as_ptr() as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Change Working Directory to File-Descriptor
+///
+/// Change the working directory of the calling process to the directory
+/// referred to by `fd`.
+pub fn fchdir(fd: RawFd) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall1_nomem(
+            super::arch::native::nr::FCHDIR,
+            fd as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Create Session
+///
+/// Make the calling process the leader of a new session and process group,
+/// and return the new session ID. Fails with `EPERM` if the calling process
+/// already is a process group leader.
+pub fn setsid() -> Result<Pid, Errno> {
+    unsafe {
+        super::raw::syscall0_nomem(
+            super::arch::native::nr::SETSID,
+        ).to_errno_result().map(|v| v as Pid)
+    }
+}
+
+/// Set Process Group
+///
+/// Move the process `pid` into the process group `pgid`. If `pid` is 0, the
+/// calling process is used. If `pgid` is 0, `pid` is used as process group
+/// ID (i.e., a new process group is created).
+pub fn setpgid(pid: Pid, pgid: Pid) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall2_nomem(
+            super::arch::native::nr::SETPGID,
+            pid as usize,
+            pgid as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Add the signals of the set to the signal mask.
+pub const SIG_BLOCK: u32 = 0;
+/// Remove the signals of the set from the signal mask.
+pub const SIG_UNBLOCK: u32 = 1;
+/// Replace the signal mask with the set.
+pub const SIG_SETMASK: u32 = 2;
+
+/// Change Signal Mask
+///
+/// Change the signal mask of the calling thread according to `how` (see
+/// `SIG_*`) and `set`, and return the previous signal mask. Signal sets are
+/// bitmasks with bit `n - 1` representing signal `n`. If `set` is `None`,
+/// the mask is left unchanged. `SIGKILL` and `SIGSTOP` cannot be blocked
+/// and are silently ignored.
+pub fn rt_sigprocmask(how: u32, set: Option<u64>) -> Result<u64, Errno> {
+    let mut old: u64 = 0;
+
+    unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::RT_SIGPROCMASK,
+            how as usize,
+            set.as_ref().map_or(0, |v| v as *const u64 as usize),
+            &mut old as *mut u64 as usize,
+            core::mem::size_of::<u64>(),
+        ).to_errno_result()?;
+    }
+
+    Ok(old)
+}
+
+/// CPU time in seconds.
+pub const RLIMIT_CPU: u32 = 0;
+/// Maximum file size.
+pub const RLIMIT_FSIZE: u32 = 1;
+/// Maximum size of the data segment.
+pub const RLIMIT_DATA: u32 = 2;
+/// Maximum size of the stack.
+pub const RLIMIT_STACK: u32 = 3;
+/// Maximum size of core dumps.
+pub const RLIMIT_CORE: u32 = 4;
+/// Maximum resident set size (unused).
+pub const RLIMIT_RSS: u32 = 5;
+/// Maximum number of processes of the real user ID.
+pub const RLIMIT_NPROC: u32 = 6;
+/// Maximum file-descriptor number plus one.
+pub const RLIMIT_NOFILE: u32 = 7;
+/// Maximum amount of locked memory.
+pub const RLIMIT_MEMLOCK: u32 = 8;
+/// Maximum size of the address space.
+pub const RLIMIT_AS: u32 = 9;
+/// Maximum number of file locks.
+pub const RLIMIT_LOCKS: u32 = 10;
+/// Maximum number of pending signals.
+pub const RLIMIT_SIGPENDING: u32 = 11;
+/// Maximum size of POSIX message queues.
+pub const RLIMIT_MSGQUEUE: u32 = 12;
+/// Ceiling of the nice value.
+pub const RLIMIT_NICE: u32 = 13;
+/// Ceiling of the real-time priority.
+pub const RLIMIT_RTPRIO: u32 = 14;
+/// CPU time limit of real-time tasks in microseconds.
+pub const RLIMIT_RTTIME: u32 = 15;
+
+/// No limit.
+pub const RLIM_INFINITY: u64 = !0;
+
+/// Resource Limit
+///
+/// This is the `rlimit64` structure of `prlimit64()`, with the soft limit
+/// `cur` and the hard limit `max`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rlimit {
+    pub cur: u64,
+    pub max: u64,
+}
+
+/// Get and Set Resource Limits
+///
+/// Set the resource limit `resource` (see `RLIMIT_*`) of the process `pid`
+/// (or the calling process if 0) to `new`, if given, and return the previous
+/// limit.
+pub fn prlimit64(pid: Pid, resource: u32, new: Option<&Rlimit>) -> Result<Rlimit, Errno> {
+    let mut old = Rlimit::default();
+
+    unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::PRLIMIT64,
+            pid as usize,
+            resource as usize,
+            new.map_or(0, |v| v as *const Rlimit as usize),
+            &mut old as *mut Rlimit as usize,
+        ).to_errno_result()?;
+    }
+
+    Ok(old)
+}
+
+/// Set User IDs
+///
+/// Set the real, effective, and saved user ID of the calling thread. IDs
+/// passed as `u32::MAX` (i.e., -1) are left unchanged.
+///
+/// Note that the kernel changes the credentials of the calling thread only.
+/// C libraries synchronize this across all threads of a process, but this
+/// wrapper does not.
+///
+/// On x86 and arm, this uses `setresuid32(2)`, since `setresuid(2)` only
+/// supports 16-bit IDs there.
+pub fn setresuid(ruid: u32, euid: u32, suid: u32) -> Result<(), Errno> {
+    #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+    let nr = super::arch::native::nr::SETRESUID32;
+    #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+    let nr = super::arch::native::nr::SETRESUID;
+
+    unsafe {
+        super::raw::syscall3_nomem(
+            nr,
+            ruid as usize,
+            euid as usize,
+            suid as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Set Group IDs
+///
+/// Set the real, effective, and saved group ID of the calling thread. See
+/// `setresuid()` for details.
+pub fn setresgid(rgid: u32, egid: u32, sgid: u32) -> Result<(), Errno> {
+    #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+    let nr = super::arch::native::nr::SETRESGID32;
+    #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+    let nr = super::arch::native::nr::SETRESGID;
+
+    unsafe {
+        super::raw::syscall3_nomem(
+            nr,
+            rgid as usize,
+            egid as usize,
+            sgid as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Set Supplementary Groups
+///
+/// Replace the supplementary group IDs of the calling thread with `groups`.
+/// See `setresuid()` for details.
+pub fn setgroups(groups: &[u32]) -> Result<(), Errno> {
+    #[cfg(any(target_arch = "arm", target_arch = "x86"))]
+    let nr = super::arch::native::nr::SETGROUPS32;
+    #[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+    let nr = super::arch::native::nr::SETGROUPS;
+
+    unsafe {
+        super::raw::syscall2_readonly(
+            nr,
+            groups.len(),
+            groups.as_ptr() as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
+
+/// Unshare Execution Context
+///
+/// Disassociate parts of the execution context of the calling task, which
+/// are currently shared with other tasks, or create new namespaces for it.
+/// `flags` takes the `CLONE_*` flags supported by `unshare(2)` (e.g.,
+/// `CLONE_FILES`, `CLONE_FS`, and the `CLONE_NEW*` flags).
+pub fn unshare(flags: u64) -> Result<(), Errno> {
+    unsafe {
+        super::raw::syscall1_nomem(
+            super::arch::native::nr::UNSHARE,
+            flags as usize,
+        ).to_errno_result().map(|_| ())
+    }
+}
 
 /// Open File
 ///
 /// XXX
+///
+/// Only available on architectures with the legacy syscall table. Use
+/// `OPENAT` with `AT_FDCWD` on all other architectures.
+#[cfg(not(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+)))]
 pub use crate::syscall::arch::native::nr::OPEN;
 
 /// Close File Descriptor
@@ -196,25 +2649,353 @@ pub use crate::syscall::arch::native::nr::LSEEK;
 pub use crate::syscall::arch::native::nr::GETPID;
 
 /// XXX
-pub use crate::syscall::arch::native::nr::PIPE2;
+pub use crate::syscall::arch::native::nr::COPY_FILE_RANGE;
 
 /// XXX
-pub use crate::syscall::arch::native::nr::MEMFD_CREATE;
+pub use crate::syscall::arch::native::nr::DUP;
 
 /// XXX
-pub use crate::syscall::arch::native::nr::READLINKAT;
+///
+/// Only available on architectures with the legacy syscall table. Use `DUP3`
+/// on all other architectures.
+#[cfg(not(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+)))]
+pub use crate::syscall::arch::native::nr::DUP2;
 
-/// XXX
-pub use crate::syscall::arch::native::nr::STATX;
+#[cfg(test)]
+mod test {
+    use super::*;
 
-/// XXX
-pub use crate::syscall::arch::native::nr::COPY_FILE_RANGE;
+    fn pipe() -> (RawFd, RawFd) {
+        let (r, w) = pipe2(O_CLOEXEC).unwrap();
+        (r.into_raw(), w.into_raw())
+    }
 
-/// XXX
-pub use crate::syscall::arch::native::nr::DUP;
+    fn memfd() -> RawFd {
+        memfd_create(cstr(b"r-linux-test\0"), MFD_CLOEXEC).unwrap().into_raw()
+    }
 
-/// XXX
-pub use crate::syscall::arch::native::nr::DUP2;
+    fn cstr(v: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(v).unwrap()
+    }
 
-/// XXX
-pub use crate::syscall::arch::native::nr::DUP3;
+    fn close(fd: RawFd) {
+        unsafe { super::close(fd) }.unwrap();
+    }
+
+    #[test]
+    fn rw_check() {
+        //
+        // Verify `read()`, `write()` and their vectored variants via a pipe.
+        //
+
+        let (r, w) = pipe();
+        let mut b0 = [0u8; 16];
+        let mut b1 = [0u8; 16];
+
+        assert_eq!(write(w, b"foo"), Ok(3));
+        assert_eq!(read(r, &mut b0), Ok(3));
+        assert_eq!(&b0[..3], b"foo");
+
+        assert_eq!(writev(w, &[IoVec::new(b"foo"), IoVec::new(b"bar")]), Ok(6));
+        {
+            let mut v = [IoVecMut::new(&mut b0[..2]), IoVecMut::new(&mut b1)];
+            assert_eq!(readv(r, &mut v), Ok(6));
+            assert_eq!(v[0].as_slice(), b"fo");
+            assert_eq!(&v[1].as_mut_slice()[..4], b"obar");
+        }
+
+        assert_eq!(read(!0, &mut b0), Err(super::super::errno::EBADF));
+        assert_eq!(write(w, &[]), Ok(0));
+        assert_eq!(
+            pread64(r, &mut b0, 0),
+            Err(super::super::errno::ESPIPE),
+        );
+
+        close(r);
+        close(w);
+    }
+
+    #[test]
+    fn pread_check() {
+        //
+        // Verify `pread64()` and `pwrite64()` with offsets beyond 4GiB,
+        // which need to be split across registers on 32-bit architectures.
+        //
+
+        let fd = memfd();
+        let mut b0 = [0u8; 6];
+
+        let off = (1i64 << 32) + 7;
+        assert_eq!(pwrite64(fd, b"foobar", off), Ok(6));
+        assert_eq!(pread64(fd, &mut b0, off), Ok(6));
+        assert_eq!(&b0, b"foobar");
+
+        assert_eq!(pread64(fd, &mut b0, 7), Ok(6));
+        assert_eq!(&b0, &[0; 6]);
+        assert_eq!(pread64(fd, &mut b0, off + 6), Ok(0));
+        assert_eq!(
+            pread64(fd, &mut b0, -1),
+            Err(super::super::errno::EINVAL),
+        );
+
+        close(fd);
+    }
+
+    #[test]
+    fn preadv2_check() {
+        //
+        // Verify `preadv2()` and `pwritev2()`, including the use of the
+        // current file position and the passing of `RWF_*` flags.
+        //
+
+        let fd = memfd();
+        let mut b0 = [0u8; 3];
+        let mut b1 = [0u8; 3];
+
+        let off = (1i64 << 32) + 3;
+        let v = [IoVec::new(b"foo"), IoVec::new(b"bar")];
+        assert_eq!(pwritev2(fd, &v, off, RWF_DSYNC), Ok(6));
+        assert_eq!(pwritev2(fd, &v[..1], -1, 0), Ok(3));
+
+        let mut v = [IoVecMut::new(&mut b0), IoVecMut::new(&mut b1)];
+        assert_eq!(preadv2(fd, &mut v, off, 0), Ok(6));
+        assert_eq!(v[0].as_slice(), b"foo");
+        assert_eq!(v[1].as_slice(), b"bar");
+
+        // The file position was advanced by the write at -1, so this reads
+        // the zeroes that follow.
+        let mut v = [IoVecMut::new(&mut b0)];
+        assert_eq!(preadv2(fd, &mut v, -1, 0), Ok(3));
+        assert_eq!(v[0].as_slice(), &[0; 3]);
+        let mut v = [IoVecMut::new(&mut b0)];
+        assert_eq!(preadv2(fd, &mut v, 0, 0), Ok(3));
+        assert_eq!(v[0].as_slice(), b"foo");
+
+        assert_eq!(
+            preadv2(fd, &mut v, 0, 0x80000000),
+            Err(super::super::errno::EOPNOTSUPP),
+        );
+
+        close(fd);
+    }
+
+    #[test]
+    fn openat_check() {
+        //
+        // Verify `openat()` and `openat2()`, including confined path
+        // resolution via `RESOLVE_*`.
+        //
+
+        let flags = O_PATH | O_DIRECTORY | O_CLOEXEC;
+
+        let fd = openat(AT_FDCWD, cstr(b"/proc\0"), flags, 0).unwrap();
+        close(fd.into_raw());
+        let fd = openat(AT_FDCWD, cstr(b"/proc\0"), O_RDONLY | O_CLOEXEC, 0).unwrap();
+        close(fd.into_raw());
+
+        assert_eq!(
+            openat(AT_FDCWD, cstr(b"/proc/self/stat\0"), flags, 0),
+            Err(super::super::errno::ENOTDIR),
+        );
+
+        let dir = openat(AT_FDCWD, cstr(b"/proc\0"), flags, 0).unwrap().into_raw();
+
+        let how = OpenHow::new(O_RDONLY | O_CLOEXEC, 0, RESOLVE_BENEATH);
+        let fd = openat2(dir, cstr(b"self/stat\0"), &how).unwrap();
+        let mut b = [0u8; 1];
+        assert_eq!(read(fd.as_raw(), &mut b), Ok(1));
+        close(fd.into_raw());
+
+        assert_eq!(
+            openat2(dir, cstr(b"../proc/self/stat\0"), &how),
+            Err(super::super::errno::EXDEV),
+        );
+        assert_eq!(
+            openat2(dir, cstr(b"/proc/self/stat\0"), &how),
+            Err(super::super::errno::EXDEV),
+        );
+
+        let how = OpenHow::new(flags, 0, RESOLVE_NO_SYMLINKS);
+        assert_eq!(
+            openat2(dir, cstr(b"self\0"), &how),
+            Err(super::super::errno::ELOOP),
+        );
+
+        let how = OpenHow::new(O_PATH | O_CLOEXEC, 0o644, 0);
+        assert_eq!(
+            openat2(dir, cstr(b"self\0"), &how),
+            Err(super::super::errno::EINVAL),
+        );
+
+        // A larger structure with unknown trailing fields set is rejected
+        // with `E2BIG`, but accepted if they are 0, regardless of its size.
+        let mut ext = [0u8; 8192];
+        ext[..8].copy_from_slice(&((O_PATH | O_CLOEXEC) as u64).to_ne_bytes());
+        ext[31] = 1;
+        assert_eq!(
+            openat2_sized(dir, cstr(b"self\0"), &ext[..32]),
+            Err(super::super::errno::E2BIG),
+        );
+        ext[31] = 0;
+        close(openat2_sized(dir, cstr(b"self\0"), &ext[..32]).unwrap().into_raw());
+        close(openat2_sized(dir, cstr(b"self\0"), &ext).unwrap().into_raw());
+        assert_eq!(
+            openat2_sized(dir, cstr(b"self\0"), &ext[..16]),
+            Err(super::super::errno::EINVAL),
+        );
+
+        assert_eq!(core::mem::size_of::<OpenHow>(), OPEN_HOW_SIZE_VER0);
+
+        close(dir);
+    }
+
+    #[test]
+    fn statx_check() {
+        //
+        // Verify the layout of `Statx` and that `statx()` reports the filled
+        // in fields.
+        //
+
+        assert_eq!(core::mem::size_of::<StatxTimestamp>(), 16);
+        assert_eq!(core::mem::size_of::<Statx>(), 256);
+
+        let fd = memfd();
+        assert_eq!(write(fd, b"foobar"), Ok(6));
+
+        let st = statx(fd, Default::default(), AT_EMPTY_PATH, STATX_BASIC_STATS).unwrap();
+        assert!(st.has(STATX_TYPE | STATX_MODE | STATX_SIZE));
+        assert_eq!(st.mode & S_IFMT, S_IFREG);
+        assert_eq!(st.size, 6);
+        assert_eq!(st.nlink, 0);
+
+        let st = statx(AT_FDCWD, cstr(b"/proc/self\0"), AT_SYMLINK_NOFOLLOW, STATX_TYPE).unwrap();
+        assert!(st.has(STATX_TYPE));
+        assert_eq!(st.mode & S_IFMT, S_IFLNK);
+        let st = statx(AT_FDCWD, cstr(b"/proc/self\0"), AT_STATX_DONT_SYNC, STATX_TYPE).unwrap();
+        assert_eq!(st.mode & S_IFMT, S_IFDIR);
+
+        let st = statx(AT_FDCWD, cstr(b"/\0"), 0, STATX_MNT_ID).unwrap();
+        assert!(!st.has(STATX_MNT_ID) || st.mnt_id != 0);
+        assert!(!st.has_attributes(STATX_ATTR_IMMUTABLE));
+
+        assert_eq!(
+            statx(fd, Default::default(), 0, STATX_TYPE),
+            Err(super::super::errno::ENOENT),
+        );
+        assert_eq!(
+            statx(AT_FDCWD, cstr(b"/\0"), 0, 0x80000000), // STATX__RESERVED
+            Err(super::super::errno::EINVAL),
+        );
+
+        close(fd);
+    }
+
+    #[test]
+    fn memfd_check() {
+        //
+        // Verify `memfd_create()` and the sealing API.
+        //
+
+        let fd = memfd_create(cstr(b"r-linux-memfd\0"), MFD_CLOEXEC).unwrap().into_raw();
+        assert_eq!(get_seals(fd), Ok(Seals::SEAL));
+        assert_eq!(add_seals(fd, Seals::WRITE), Err(super::super::errno::EPERM));
+        close(fd);
+
+        let fd = memfd_create(cstr(b"r-linux-memfd\0"), MFD_CLOEXEC | MFD_ALLOW_SEALING).unwrap().into_raw();
+        assert_eq!(get_seals(fd), Ok(Seals::empty()));
+        assert_eq!(write(fd, b"foo"), Ok(3));
+
+        add_seals(fd, Seals::SHRINK | Seals::GROW).unwrap();
+        let seals = get_seals(fd).unwrap();
+        assert!(seals.contains(Seals::SHRINK | Seals::GROW));
+        assert!(!seals.contains(Seals::WRITE));
+        assert_eq!(pwrite64(fd, b"bar", 0), Ok(3));
+        assert_eq!(write(fd, b"bar"), Err(super::super::errno::EPERM));
+
+        add_seals(fd, Seals::IMMUTABLE).unwrap();
+        assert_eq!(get_seals(fd), Ok(Seals::IMMUTABLE));
+        assert_eq!(pwrite64(fd, b"foo", 0), Err(super::super::errno::EPERM));
+        assert_eq!(add_seals(fd, Seals::EXEC), Err(super::super::errno::EPERM));
+
+        let mut b = [0u8; 3];
+        assert_eq!(pread64(fd, &mut b, 0), Ok(3));
+        assert_eq!(&b, b"bar");
+        close(fd);
+
+        assert_eq!(get_seals(0xffff), Err(super::super::errno::EBADF));
+        let mut name = [b'a'; 251];
+        name[250] = 0;
+        assert_eq!(memfd_create(cstr(&name), MFD_CLOEXEC), Err(super::super::errno::EINVAL));
+        name[249] = 0;
+        close(memfd_create(cstr(&name[..250]), MFD_CLOEXEC).unwrap().into_raw());
+        assert_eq!(
+            memfd_create(cstr(b"r-linux-memfd\0"), MFD_EXEC | MFD_NOEXEC_SEAL),
+            Err(super::super::errno::EINVAL),
+        );
+        assert_eq!(MFD_HUGE_2MB >> MFD_HUGE_SHIFT & MFD_HUGE_MASK, 21);
+    }
+
+    #[test]
+    fn wait_check() {
+        //
+        // Fork a child that blocks, and verify its state changes can be
+        // observed via `waitid()` and `wait4()`.
+        //
+
+        let pid = match unsafe { fork() }.unwrap() {
+            None => loop {
+                let v = core::sync::atomic::AtomicU32::new(0);
+                let _ = futex_wait(&v, 0, true);
+            },
+            Some(v) => v,
+        };
+        let pidfd = pidfd_open(pid, 0).unwrap();
+        let fd = pidfd.as_raw();
+
+        assert_eq!(waitid(P_PID, pid as u32, WEXITED | WNOHANG, None), Ok(None));
+        assert_eq!(wait4(pid, WNOHANG, None), Ok(None));
+
+        pidfd_send_signal(fd, SIGSTOP, 0).unwrap();
+        let info = waitid(P_PID, pid as u32, WSTOPPED, None).unwrap().unwrap();
+        assert_eq!(info.pid, pid);
+        assert_eq!(info.exit_status(), Some(ExitStatus::Stopped(SIGSTOP)));
+
+        pidfd_send_signal(fd, SIGCONT, 0).unwrap();
+        let info = waitid(P_PIDFD, fd as u32, WCONTINUED, None).unwrap().unwrap();
+        assert_eq!(info.exit_status(), Some(ExitStatus::Continued));
+
+        pidfd_send_signal(fd, SIGKILL, 0).unwrap();
+        let mut ru = Rusage::default();
+        assert_eq!(
+            wait4(pid, __WALL, Some(&mut ru)),
+            Ok(Some((pid, ExitStatus::Killed(SIGKILL)))),
+        );
+        assert!(ru.maxrss > 0);
+        assert_eq!(wait4(pid, 0, None), Err(super::super::errno::ECHILD));
+    }
+
+    #[test]
+    fn exit_status_check() {
+        //
+        // Verify decoding of `wait4()` status values.
+        //
+
+        assert_eq!(ExitStatus::from_wait_status(0), Some(ExitStatus::Exited(0)));
+        assert_eq!(ExitStatus::from_wait_status(71 << 8), Some(ExitStatus::Exited(71)));
+        assert_eq!(ExitStatus::from_wait_status(9), Some(ExitStatus::Killed(9)));
+        assert_eq!(ExitStatus::from_wait_status(0x86), Some(ExitStatus::Dumped(6)));
+        assert_eq!(ExitStatus::from_wait_status(0x137f), Some(ExitStatus::Stopped(19)));
+        assert_eq!(ExitStatus::from_wait_status(0x3057f), Some(ExitStatus::Stopped(5)));
+        assert_eq!(ExitStatus::from_wait_status(0xffff), Some(ExitStatus::Continued));
+        assert_eq!(ExitStatus::from_wait_status(0x109), None);
+
+        assert!(ExitStatus::Exited(0).success());
+        assert!(!ExitStatus::Exited(1).success());
+        assert_eq!(ExitStatus::Exited(1).code(), Some(1));
+        assert_eq!(ExitStatus::Dumped(6).signal(), Some(6));
+        assert_eq!(ExitStatus::Stopped(19).signal(), None);
+    }
+}
@@ -0,0 +1,564 @@
+//! System Calls on aarch64
+//!
+//! This implements the syscall entries for aarch64. One function for each
+//! possible number of arguments is provided: syscall0 to syscall6.
+//!
+//! The implementation uses the aarch64-`svc #0` supervisor call to enter the
+//! kernel. The immediate of the instruction is ignored by linux, but `0` is
+//! the documented value.
+//!
+//! Arguments are passed as:
+//!     Nr: x8
+//!     Args: x0, x1, x2, x3, x4, x5
+//! Return value is in:
+//!     Ret: x0
+//!
+//! The regular entry-points are not marked as `readonly`. That is, the system
+//! calls are allowed to modify memory. Alternative entry-points are provided
+//! for system calls with less side-effects: `syscallN_readonly()` tells the
+//! compiler that the system call does not write to memory of the calling
+//! process, and `syscallN_nomem()` tells it that the system call does not
+//! access memory of the calling process at all (e.g., `getpid()`). This allows
+//! the compiler to keep values in registers across the system call. Using
+//! these entry-points for system calls that do access memory is undefined
+//! behavior.
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall0"]
+pub unsafe fn syscall0(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        lateout("x0") r,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall1"]
+pub unsafe fn syscall1(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall2"]
+pub unsafe fn syscall2(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall3"]
+pub unsafe fn syscall3(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall4"]
+pub unsafe fn syscall4(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall5"]
+pub unsafe fn syscall5(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+#[export_name = "r_linux_asm_syscall6"]
+pub unsafe fn syscall6(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        in("x5") arg5,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+// Read-Only Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call does not write to any memory of the calling
+// process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        lateout("x0") r,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        in("x5") arg5,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+// No-Memory Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call neither reads nor writes any memory of the
+// calling process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        lateout("x0") r,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "svc #0",
+        in("x8") nr,
+        inlateout("x0") arg0 => r,
+        in("x1") arg1,
+        in("x2") arg2,
+        in("x3") arg3,
+        in("x4") arg4,
+        in("x5") arg5,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
@@ -0,0 +1,677 @@
+//! System Calls on arm
+//!
+//! This implements the syscall entries for arm. One function for each
+//! possible number of arguments is provided: syscall0 to syscall6.
+//!
+//! The implementation uses the EABI calling convention with the `svc #0`
+//! (formerly `swi 0`) supervisor call to enter the kernel. The obsolete OABI,
+//! which encodes the system call number in the instruction, is not supported.
+//!
+//! Arguments are passed as:
+//!     Nr: r7
+//!     Args: r0, r1, r2, r3, r4, r5
+//! Return value is in:
+//!     Ret: r0
+//!
+//! The regular entry-points are not marked as `readonly`. That is, the system
+//! calls are allowed to modify memory. Alternative entry-points are provided
+//! for system calls with less side-effects: `syscallN_readonly()` tells the
+//! compiler that the system call does not write to memory of the calling
+//! process, and `syscallN_nomem()` tells it that the system call does not
+//! access memory of the calling process at all (e.g., `getpid()`). This allows
+//! the compiler to keep values in registers across the system call. Using
+//! these entry-points for system calls that do access memory is undefined
+//! behavior.
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall0"]
+pub unsafe fn syscall0(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    // In Thumb mode, `r7` is used as frame pointer, and LLVM does not allow
+    // it as operand to inline assembly. Hence, we let LLVM pick a register
+    // for the system call number and a scratch register, save `r7` in the
+    // latter, and swap in the system call number manually. Once the kernel
+    // returns, `r7` is restored. Note that the scratch register must be a
+    // non-late output, so it is never allocated to one of the inputs.
+    //
+    // In ARM mode this is not required, but it is just two register moves, so
+    // we use the same code for both modes.
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        lateout("r0") r,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall1"]
+pub unsafe fn syscall1(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall2"]
+pub unsafe fn syscall2(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall3"]
+pub unsafe fn syscall3(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall4"]
+pub unsafe fn syscall4(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall5"]
+pub unsafe fn syscall5(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+#[export_name = "r_linux_asm_syscall6"]
+pub unsafe fn syscall6(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        in("r5") arg5,
+        options(nostack, preserves_flags)
+    );
+
+    r
+}
+
+// Read-Only Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call does not write to any memory of the calling
+// process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for details
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        lateout("r0") r,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        in("r5") arg5,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+// No-Memory Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call neither reads nor writes any memory of the
+// calling process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for details
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        lateout("r0") r,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "arm")]
+#[inline]
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    // see syscall0() for `r7` handling
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        nr = in(reg) nr,
+        tmp = out(reg) _,
+        inlateout("r0") arg0 => r,
+        in("r1") arg1,
+        in("r2") arg2,
+        in("r3") arg3,
+        in("r4") arg4,
+        in("r5") arg5,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
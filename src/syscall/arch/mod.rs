@@ -8,6 +8,10 @@
 //! module to get a link to the architecture native to your compilation.
 //! Alternatively, use `cfg(target_arch = "xyz")` to test for the architecture
 //! of your choice.
+//!
+//! The `x32` ABI is an exception, since it uses `target_arch = "x86_64"` with
+//! `target_pointer_width = "32"`. Make sure to test for both if you need to
+//! tell `x32` and `x86_64` apart.
 
 // Target Architecture
 //
@@ -15,11 +19,18 @@
 // spurious linker errors in dependent crates, we check for supported
 // architectures here and error out right away.
 #[cfg(not(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
     target_arch = "x86",
     target_arch = "x86_64",
 )))]
 compile_error!("Target architecture not supported.");
 
+pub mod aarch64;
+pub mod arm;
+pub mod riscv64;
+pub mod x32;
 pub mod x86;
 pub mod x86_64;
 
@@ -44,12 +55,40 @@ pub mod native {
     pub use super::x86_64::*;
 }
 
+#[cfg(all(not(doctest), target_arch = "aarch64"))]
+pub mod native {
+    pub use super::aarch64::*;
+}
+
+#[cfg(all(not(doctest), target_arch = "arm"))]
+pub mod native {
+    pub use super::arm::*;
+}
+
+#[cfg(all(not(doctest), target_arch = "riscv64"))]
+pub mod native {
+    pub use super::riscv64::*;
+}
+
+#[cfg(all(
+    not(doctest),
+    target_arch = "x86_64",
+    target_pointer_width = "32",
+))]
+pub mod native {
+    pub use super::x32::*;
+}
+
 #[cfg(all(not(doctest), target_arch = "x86"))]
 pub mod native {
     pub use super::x86::*;
 }
 
-#[cfg(all(not(doctest), target_arch = "x86_64"))]
+#[cfg(all(
+    not(doctest),
+    target_arch = "x86_64",
+    target_pointer_width = "64",
+))]
 pub mod native {
     pub use super::x86_64::*;
 }
@@ -75,6 +114,11 @@ mod test {
         // simply check for their hard-coded `nr::EXIT` symbols here.
         //
 
+        assert_eq!(aarch64::nr::EXIT, 93);
+        assert_eq!(arm::nr::EXIT, 1);
+        assert_eq!(arm::nr::ARM_SET_TLS, 0xf0005);
+        assert_eq!(riscv64::nr::EXIT, 93);
+        assert_eq!(x32::nr::EXIT, 0x40000000 | 60);
         assert_eq!(x86::nr::EXIT, 1);
         assert_eq!(x86_64::nr::EXIT, 60);
     }
@@ -150,4 +194,42 @@ mod test {
         assert!(core::mem::size_of::<usize>() >= 4);
         assert!(core::mem::align_of::<usize>() >= 4);
     }
+
+    #[cfg(all(target_arch = "x86", feature = "vsyscall"))]
+    #[test]
+    fn vsyscall_check() {
+        //
+        // Fetch `__kernel_vsyscall` from the auxiliary vector and verify that
+        // system calls produce the same results via `int$0x80` and via the
+        // vsyscall entry. `mmap2` is used to cover the 6-argument variant.
+        //
+
+        extern "C" {
+            fn getauxval(t: core::ffi::c_ulong) -> core::ffi::c_ulong;
+        }
+
+        let entry = unsafe { getauxval(32) } as usize;
+        assert_ne!(entry, 0);
+
+        let pid0 = unsafe { x86::syscall::syscall0(x86::nr::GETPID) };
+        unsafe { x86::syscall::set_vsyscall(entry) };
+        let pid1 = unsafe { x86::syscall::syscall0(x86::nr::GETPID) };
+        assert_eq!(pid0, pid1);
+
+        let map = unsafe {
+            x86::syscall::syscall6(
+                x86::nr::MMAP2,
+                0,
+                4096,
+                0x1 | 0x2, // PROT_READ | PROT_WRITE
+                0x02 | 0x20, // MAP_PRIVATE | MAP_ANONYMOUS
+                usize::MAX, // fd: -1
+                0,
+            )
+        };
+        assert!(map < usize::MAX - 4095);
+        unsafe { *(map as *mut u8) = 71 };
+        let r = unsafe { x86::syscall::syscall2(x86::nr::MUNMAP, map, 4096) };
+        assert_eq!(r, 0);
+    }
 }
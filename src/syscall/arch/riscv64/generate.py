@@ -0,0 +1 @@
+../x86/generate.py
\ No newline at end of file
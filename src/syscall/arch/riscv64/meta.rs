@@ -0,0 +1,311 @@
+// This code is generated.
+use crate::syscall::meta::{Arg::*, Syscall};
+
+pub const TABLE: &[Syscall] = &[
+    Syscall { name: "io_setup", nr: 0, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_destroy", nr: 1, args: Some(&[Value]) },
+    Syscall { name: "io_submit", nr: 2, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "io_cancel", nr: 3, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "io_getevents", nr: 4, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "setxattr", nr: 5, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "lsetxattr", nr: 6, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "fsetxattr", nr: 7, args: Some(&[Fd, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "getxattr", nr: 8, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "lgetxattr", nr: 9, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "fgetxattr", nr: 10, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "listxattr", nr: 11, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "llistxattr", nr: 12, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "flistxattr", nr: 13, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "removexattr", nr: 14, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lremovexattr", nr: 15, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fremovexattr", nr: 16, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "getcwd", nr: 17, args: Some(&[Ptr, Value]) },
+    Syscall { name: "lookup_dcookie", nr: 18, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "eventfd2", nr: 19, args: Some(&[Value, Flags]) },
+    Syscall { name: "epoll_create1", nr: 20, args: Some(&[Flags]) },
+    Syscall { name: "epoll_ctl", nr: 21, args: Some(&[Fd, Value, Fd, Ptr]) },
+    Syscall { name: "epoll_pwait", nr: 22, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "dup", nr: 23, args: Some(&[Fd]) },
+    Syscall { name: "dup3", nr: 24, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "fcntl", nr: 25, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "inotify_init1", nr: 26, args: Some(&[Flags]) },
+    Syscall { name: "inotify_add_watch", nr: 27, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "inotify_rm_watch", nr: 28, args: Some(&[Fd, Value]) },
+    Syscall { name: "ioctl", nr: 29, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "ioprio_set", nr: 30, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "ioprio_get", nr: 31, args: Some(&[Value, Value]) },
+    Syscall { name: "flock", nr: 32, args: Some(&[Fd, Value]) },
+    Syscall { name: "mknodat", nr: 33, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "mkdirat", nr: 34, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "unlinkat", nr: 35, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "symlinkat", nr: 36, args: Some(&[Ptr, Fd, Ptr]) },
+    Syscall { name: "linkat", nr: 37, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "umount2", nr: 39, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "mount", nr: 40, args: Some(&[Ptr, Ptr, Ptr, Flags, Ptr]) },
+    Syscall { name: "pivot_root", nr: 41, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "nfsservctl", nr: 42, args: None },
+    Syscall { name: "statfs", nr: 43, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstatfs", nr: 44, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "truncate", nr: 45, args: Some(&[Ptr, Value]) },
+    Syscall { name: "ftruncate", nr: 46, args: Some(&[Fd, Value]) },
+    Syscall { name: "fallocate", nr: 47, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "faccessat", nr: 48, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "chdir", nr: 49, args: Some(&[Ptr]) },
+    Syscall { name: "fchdir", nr: 50, args: Some(&[Fd]) },
+    Syscall { name: "chroot", nr: 51, args: Some(&[Ptr]) },
+    Syscall { name: "fchmod", nr: 52, args: Some(&[Fd, Value]) },
+    Syscall { name: "fchmodat", nr: 53, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "fchownat", nr: 54, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "fchown", nr: 55, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "openat", nr: 56, args: Some(&[Fd, Ptr, Flags, Value]) },
+    Syscall { name: "close", nr: 57, args: Some(&[Fd]) },
+    Syscall { name: "vhangup", nr: 58, args: Some(&[]) },
+    Syscall { name: "pipe2", nr: 59, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "quotactl", nr: 60, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "getdents64", nr: 61, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "lseek", nr: 62, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "read", nr: 63, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "write", nr: 64, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "readv", nr: 65, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "writev", nr: 66, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "pread64", nr: 67, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "pwrite64", nr: 68, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "preadv", nr: 69, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "pwritev", nr: 70, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "sendfile", nr: 71, args: Some(&[Fd, Fd, Ptr, Value]) },
+    Syscall { name: "pselect6", nr: 72, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "ppoll", nr: 73, args: Some(&[Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "signalfd4", nr: 74, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "vmsplice", nr: 75, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "splice", nr: 76, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "tee", nr: 77, args: Some(&[Fd, Fd, Value, Flags]) },
+    Syscall { name: "readlinkat", nr: 78, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "newfstatat", nr: 79, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "fstat", nr: 80, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "sync", nr: 81, args: Some(&[]) },
+    Syscall { name: "fsync", nr: 82, args: Some(&[Fd]) },
+    Syscall { name: "fdatasync", nr: 83, args: Some(&[Fd]) },
+    Syscall { name: "sync_file_range", nr: 84, args: Some(&[Fd, Value, Value, Flags]) },
+    Syscall { name: "timerfd_create", nr: 85, args: Some(&[Value, Flags]) },
+    Syscall { name: "timerfd_settime", nr: 86, args: Some(&[Fd, Flags, Ptr, Ptr]) },
+    Syscall { name: "timerfd_gettime", nr: 87, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "utimensat", nr: 88, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "acct", nr: 89, args: Some(&[Ptr]) },
+    Syscall { name: "capget", nr: 90, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "capset", nr: 91, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "personality", nr: 92, args: Some(&[Value]) },
+    Syscall { name: "exit", nr: 93, args: Some(&[Value]) },
+    Syscall { name: "exit_group", nr: 94, args: Some(&[Value]) },
+    Syscall { name: "waitid", nr: 95, args: Some(&[Value, Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "set_tid_address", nr: 96, args: Some(&[Ptr]) },
+    Syscall { name: "unshare", nr: 97, args: Some(&[Flags]) },
+    Syscall { name: "futex", nr: 98, args: Some(&[Ptr, Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "set_robust_list", nr: 99, args: Some(&[Ptr, Value]) },
+    Syscall { name: "get_robust_list", nr: 100, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "nanosleep", nr: 101, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getitimer", nr: 102, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setitimer", nr: 103, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "kexec_load", nr: 104, args: Some(&[Value, Value, Ptr, Flags]) },
+    Syscall { name: "init_module", nr: 105, args: Some(&[Ptr, Value, Ptr]) },
+    Syscall { name: "delete_module", nr: 106, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "timer_create", nr: 107, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "timer_gettime", nr: 108, args: Some(&[Value, Ptr]) },
+    Syscall { name: "timer_getoverrun", nr: 109, args: Some(&[Value]) },
+    Syscall { name: "timer_settime", nr: 110, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timer_delete", nr: 111, args: Some(&[Value]) },
+    Syscall { name: "clock_settime", nr: 112, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_gettime", nr: 113, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_getres", nr: 114, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_nanosleep", nr: 115, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "syslog", nr: 116, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "ptrace", nr: 117, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "sched_setparam", nr: 118, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_setscheduler", nr: 119, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getscheduler", nr: 120, args: Some(&[Value]) },
+    Syscall { name: "sched_getparam", nr: 121, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_setaffinity", nr: 122, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getaffinity", nr: 123, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_yield", nr: 124, args: Some(&[]) },
+    Syscall { name: "sched_get_priority_max", nr: 125, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_min", nr: 126, args: Some(&[Value]) },
+    Syscall { name: "sched_rr_get_interval", nr: 127, args: Some(&[Value, Ptr]) },
+    Syscall { name: "restart_syscall", nr: 128, args: Some(&[]) },
+    Syscall { name: "kill", nr: 129, args: Some(&[Value, Value]) },
+    Syscall { name: "tkill", nr: 130, args: Some(&[Value, Value]) },
+    Syscall { name: "tgkill", nr: 131, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "sigaltstack", nr: 132, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "rt_sigsuspend", nr: 133, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rt_sigaction", nr: 134, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigprocmask", nr: 135, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigpending", nr: 136, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rt_sigtimedwait", nr: 137, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigqueueinfo", nr: 138, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "rt_sigreturn", nr: 139, args: Some(&[]) },
+    Syscall { name: "setpriority", nr: 140, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getpriority", nr: 141, args: Some(&[Value, Value]) },
+    Syscall { name: "reboot", nr: 142, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "setregid", nr: 143, args: Some(&[Value, Value]) },
+    Syscall { name: "setgid", nr: 144, args: Some(&[Value]) },
+    Syscall { name: "setreuid", nr: 145, args: Some(&[Value, Value]) },
+    Syscall { name: "setuid", nr: 146, args: Some(&[Value]) },
+    Syscall { name: "setresuid", nr: 147, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresuid", nr: 148, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "setresgid", nr: 149, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresgid", nr: 150, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "setfsuid", nr: 151, args: Some(&[Value]) },
+    Syscall { name: "setfsgid", nr: 152, args: Some(&[Value]) },
+    Syscall { name: "times", nr: 153, args: Some(&[Ptr]) },
+    Syscall { name: "setpgid", nr: 154, args: Some(&[Value, Value]) },
+    Syscall { name: "getpgid", nr: 155, args: Some(&[Value]) },
+    Syscall { name: "getsid", nr: 156, args: Some(&[Value]) },
+    Syscall { name: "setsid", nr: 157, args: Some(&[]) },
+    Syscall { name: "getgroups", nr: 158, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setgroups", nr: 159, args: Some(&[Value, Ptr]) },
+    Syscall { name: "uname", nr: 160, args: Some(&[Ptr]) },
+    Syscall { name: "sethostname", nr: 161, args: Some(&[Ptr, Value]) },
+    Syscall { name: "setdomainname", nr: 162, args: Some(&[Ptr, Value]) },
+    Syscall { name: "getrlimit", nr: 163, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setrlimit", nr: 164, args: Some(&[Value, Ptr]) },
+    Syscall { name: "getrusage", nr: 165, args: Some(&[Value, Ptr]) },
+    Syscall { name: "umask", nr: 166, args: Some(&[Value]) },
+    Syscall { name: "prctl", nr: 167, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "getcpu", nr: 168, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "gettimeofday", nr: 169, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "settimeofday", nr: 170, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "adjtimex", nr: 171, args: Some(&[Ptr]) },
+    Syscall { name: "getpid", nr: 172, args: Some(&[]) },
+    Syscall { name: "getppid", nr: 173, args: Some(&[]) },
+    Syscall { name: "getuid", nr: 174, args: Some(&[]) },
+    Syscall { name: "geteuid", nr: 175, args: Some(&[]) },
+    Syscall { name: "getgid", nr: 176, args: Some(&[]) },
+    Syscall { name: "getegid", nr: 177, args: Some(&[]) },
+    Syscall { name: "gettid", nr: 178, args: Some(&[]) },
+    Syscall { name: "sysinfo", nr: 179, args: Some(&[Ptr]) },
+    Syscall { name: "mq_open", nr: 180, args: Some(&[Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "mq_unlink", nr: 181, args: Some(&[Ptr]) },
+    Syscall { name: "mq_timedsend", nr: 182, args: Some(&[Fd, Ptr, Value, Value, Ptr]) },
+    Syscall { name: "mq_timedreceive", nr: 183, args: Some(&[Fd, Ptr, Value, Ptr, Ptr]) },
+    Syscall { name: "mq_notify", nr: 184, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "mq_getsetattr", nr: 185, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "msgget", nr: 186, args: Some(&[Value, Flags]) },
+    Syscall { name: "msgctl", nr: 187, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "msgrcv", nr: 188, args: Some(&[Value, Ptr, Value, Value, Flags]) },
+    Syscall { name: "msgsnd", nr: 189, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "semget", nr: 190, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "semctl", nr: 191, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "semtimedop", nr: 192, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "semop", nr: 193, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "shmget", nr: 194, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "shmctl", nr: 195, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "shmat", nr: 196, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "shmdt", nr: 197, args: Some(&[Ptr]) },
+    Syscall { name: "socket", nr: 198, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "socketpair", nr: 199, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "bind", nr: 200, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "listen", nr: 201, args: Some(&[Fd, Value]) },
+    Syscall { name: "accept", nr: 202, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "connect", nr: 203, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "getsockname", nr: 204, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "getpeername", nr: 205, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "sendto", nr: 206, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "recvfrom", nr: 207, args: Some(&[Fd, Ptr, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "setsockopt", nr: 208, args: Some(&[Fd, Value, Value, Ptr, Value]) },
+    Syscall { name: "getsockopt", nr: 209, args: Some(&[Fd, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "shutdown", nr: 210, args: Some(&[Fd, Value]) },
+    Syscall { name: "sendmsg", nr: 211, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "recvmsg", nr: 212, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "readahead", nr: 213, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "brk", nr: 214, args: Some(&[Value]) },
+    Syscall { name: "munmap", nr: 215, args: Some(&[Value, Value]) },
+    Syscall { name: "mremap", nr: 216, args: Some(&[Value, Value, Value, Flags, Value]) },
+    Syscall { name: "add_key", nr: 217, args: Some(&[Ptr, Ptr, Ptr, Value, Value]) },
+    Syscall { name: "request_key", nr: 218, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "keyctl", nr: 219, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "clone", nr: 220, args: Some(&[Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "execve", nr: 221, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "mmap", nr: 222, args: Some(&[Value, Value, Value, Flags, Fd, Value]) },
+    Syscall { name: "fadvise64", nr: 223, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "swapon", nr: 224, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "swapoff", nr: 225, args: Some(&[Ptr]) },
+    Syscall { name: "mprotect", nr: 226, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "msync", nr: 227, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "mlock", nr: 228, args: Some(&[Value, Value]) },
+    Syscall { name: "munlock", nr: 229, args: Some(&[Value, Value]) },
+    Syscall { name: "mlockall", nr: 230, args: Some(&[Flags]) },
+    Syscall { name: "munlockall", nr: 231, args: Some(&[]) },
+    Syscall { name: "mincore", nr: 232, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "madvise", nr: 233, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "remap_file_pages", nr: 234, args: Some(&[Value, Value, Value, Value, Flags]) },
+    Syscall { name: "mbind", nr: 235, args: Some(&[Value, Value, Value, Ptr, Value, Flags]) },
+    Syscall { name: "get_mempolicy", nr: 236, args: Some(&[Ptr, Ptr, Value, Value, Flags]) },
+    Syscall { name: "set_mempolicy", nr: 237, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "migrate_pages", nr: 238, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "move_pages", nr: 239, args: Some(&[Value, Value, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "rt_tgsigqueueinfo", nr: 240, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "perf_event_open", nr: 241, args: Some(&[Ptr, Value, Value, Fd, Flags]) },
+    Syscall { name: "accept4", nr: 242, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "recvmmsg", nr: 243, args: Some(&[Fd, Ptr, Value, Flags, Ptr]) },
+    Syscall { name: "riscv_flush_icache", nr: 259, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "wait4", nr: 260, args: Some(&[Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "prlimit64", nr: 261, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "fanotify_init", nr: 262, args: Some(&[Flags, Flags]) },
+    Syscall { name: "fanotify_mark", nr: 263, args: Some(&[Fd, Flags, Value, Fd, Ptr]) },
+    Syscall { name: "name_to_handle_at", nr: 264, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "open_by_handle_at", nr: 265, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "clock_adjtime", nr: 266, args: Some(&[Value, Ptr]) },
+    Syscall { name: "syncfs", nr: 267, args: Some(&[Fd]) },
+    Syscall { name: "setns", nr: 268, args: Some(&[Fd, Value]) },
+    Syscall { name: "sendmmsg", nr: 269, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_readv", nr: 270, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_writev", nr: 271, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "kcmp", nr: 272, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "finit_module", nr: 273, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "sched_setattr", nr: 274, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "sched_getattr", nr: 275, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "renameat2", nr: 276, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "seccomp", nr: 277, args: Some(&[Value, Flags, Ptr]) },
+    Syscall { name: "getrandom", nr: 278, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "memfd_create", nr: 279, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "bpf", nr: 280, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "execveat", nr: 281, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "userfaultfd", nr: 282, args: Some(&[Flags]) },
+    Syscall { name: "membarrier", nr: 283, args: Some(&[Value, Flags, Value]) },
+    Syscall { name: "mlock2", nr: 284, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "copy_file_range", nr: 285, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "preadv2", nr: 286, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pwritev2", nr: 287, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pkey_mprotect", nr: 288, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "pkey_alloc", nr: 289, args: Some(&[Flags, Value]) },
+    Syscall { name: "pkey_free", nr: 290, args: Some(&[Value]) },
+    Syscall { name: "statx", nr: 291, args: Some(&[Fd, Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "io_pgetevents", nr: 292, args: Some(&[Value, Value, Value, Ptr, Ptr, Ptr]) },
+    Syscall { name: "rseq", nr: 293, args: Some(&[Ptr, Value, Flags, Value]) },
+    Syscall { name: "kexec_file_load", nr: 294, args: Some(&[Fd, Fd, Value, Ptr, Flags]) },
+    Syscall { name: "pidfd_send_signal", nr: 424, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "io_uring_setup", nr: 425, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_uring_enter", nr: 426, args: Some(&[Fd, Value, Value, Flags, Ptr, Value]) },
+    Syscall { name: "io_uring_register", nr: 427, args: Some(&[Fd, Value, Ptr, Value]) },
+    Syscall { name: "open_tree", nr: 428, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "move_mount", nr: 429, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "fsopen", nr: 430, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "fsconfig", nr: 431, args: Some(&[Fd, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fsmount", nr: 432, args: Some(&[Fd, Flags, Flags]) },
+    Syscall { name: "fspick", nr: 433, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "pidfd_open", nr: 434, args: Some(&[Value, Flags]) },
+    Syscall { name: "clone3", nr: 435, args: Some(&[Ptr, Value]) },
+    Syscall { name: "close_range", nr: 436, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "openat2", nr: 437, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "pidfd_getfd", nr: 438, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "faccessat2", nr: 439, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_madvise", nr: 440, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "epoll_pwait2", nr: 441, args: Some(&[Fd, Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "mount_setattr", nr: 442, args: Some(&[Fd, Ptr, Flags, Ptr, Value]) },
+    Syscall { name: "quotactl_fd", nr: 443, args: Some(&[Fd, Value, Value, Ptr]) },
+    Syscall { name: "landlock_create_ruleset", nr: 444, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "landlock_add_rule", nr: 445, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "landlock_restrict_self", nr: 446, args: Some(&[Fd, Flags]) },
+    Syscall { name: "memfd_secret", nr: 447, args: Some(&[Flags]) },
+    Syscall { name: "process_mrelease", nr: 448, args: Some(&[Fd, Flags]) },
+    Syscall { name: "futex_waitv", nr: 449, args: Some(&[Ptr, Value, Flags, Ptr, Value]) },
+    Syscall { name: "set_mempolicy_home_node", nr: 450, args: Some(&[Value, Value, Value, Flags]) },
+];
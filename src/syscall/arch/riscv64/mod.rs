@@ -0,0 +1,13 @@
+//! Architecture Definitions for riscv64
+//!
+//! This module provides the linux-kernel API definitions specific
+//! to riscv64.
+//!
+//! No documentation is provided for the individual symbols and definitions.
+//! They are meant to match the official API of the linux kernel. Either see
+//! the official linux kernel documentation for help, or look at the interfaces
+//! exposed by the `api` module.
+
+pub mod meta;
+pub mod nr;
+pub mod syscall;
@@ -0,0 +1,213 @@
+"""System Call Metadata
+
+This module is used by the code generator of all architectures (see
+`x86/generate.py`). It generates the system call metadata tables (name,
+number, and the kinds of all argument registers) from the system call tables
+of an architecture and the system call prototypes of the linux kernel.
+
+The prototypes are taken from `include/linux/syscalls.h`, which declares the
+`sys_*()` entry points of all generic system calls. Architecture-specific
+entry points are not declared there, so the generator provides them
+separately for each architecture. The entry point of each system call is
+taken from the system call table of the architecture, if available.
+Otherwise, the prototype is looked up by the name of the system call.
+
+Arguments are classified by their type and name. On 32-bit architectures,
+64-bit arguments are split across two registers (low-word first, since all
+supported 32-bit architectures are little-endian). Some architectures
+additionally require such register pairs to start at an even register, in
+which case an unused padding register is inserted.
+"""
+
+
+import re
+import sys
+
+
+# Generic system calls with unnamed file-descriptor arguments. The socket
+# calls are declared without argument names, so we cannot detect their
+# file-descriptor arguments otherwise.
+UNNAMED_FD = [
+    "sys_accept",
+    "sys_accept4",
+    "sys_bind",
+    "sys_connect",
+    "sys_getpeername",
+    "sys_getsockname",
+    "sys_listen",
+    "sys_recv",
+    "sys_recvfrom",
+    "sys_send",
+    "sys_sendto",
+    "sys_shutdown",
+]
+
+# Typedefs that hide a pointer.
+POINTER_TYPES = [
+    "__sighandler_t",
+    "cap_user_data_t",
+    "cap_user_header_t",
+]
+
+# Types that are 64-bit wide on all architectures.
+WIDE_TYPES = [
+    "__s64",
+    "__u64",
+    "loff_t",
+    "s64",
+    "u64",
+    "uint64_t",
+]
+
+
+def protos_parse(*, data, config, extra):
+    """Parse Prototypes
+
+    The syscall header contains prototypes in the style of:
+        asmlinkage long sys_read(unsigned int fd, char __user *buf,
+                                 size_t count);
+    Prototypes can span multiple lines. Alternative prototypes are guarded
+    by `#ifdef CONFIG_*` conditionals, which are evaluated with the
+    configuration symbols in `config`. Any other conditional is considered
+    true. The prototypes in `extra` are parsed the same way and take
+    precedence over the ones in the header.
+    """
+
+    res = {}
+    stack = []
+    text = []
+
+    for line in data.decode().splitlines():
+        line = line.strip()
+
+        m = re.match(r"#\s*(ifdef|ifndef|if|else|elif|endif)\b\s*(\w*)", line)
+        if m:
+            if m.group(1) in ["ifdef", "ifndef"]:
+                v = (m.group(2) in config) == (m.group(1) == "ifdef")
+                stack.append(v)
+            elif m.group(1) == "if":
+                stack.append(True)
+            elif m.group(1) in ["else", "elif"]:
+                stack[-1] = not stack[-1]
+            elif m.group(1) == "endif":
+                stack.pop()
+            continue
+
+        if all(stack):
+            text.append(line)
+
+    text = " ".join(text + extra)
+    text = re.sub(r"/\*.*?\*/", " ", text)
+
+    for m in re.finditer(r"\blong\s+(sys_\w+)\s*\(([^)]*)\)\s*;", text):
+        params = [v.strip() for v in m.group(2).split(",")]
+        if params == ["void"]:
+            params = []
+        res[m.group(1)] = params
+
+    return res
+
+
+def meta_resolve(*, protos, name, entry):
+    """Resolve Prototype
+
+    Find the prototype of a system call. The entry point of the system call
+    table is preferred, with any ABI specific prefixes and suffixes stripped.
+    Otherwise, the name of the system call is used, stripping suffixes used
+    for 32-bit and 64-bit variants. Return `None` if the system call is not
+    implemented or no prototype is known.
+    """
+
+    if entry is None or entry == "sys_ni_syscall":
+        return None
+
+    candidates = [entry]
+    candidates.append(re.sub(r"^compat_sys_", "sys_", entry))
+    candidates.append(re.sub(r"^sys_ia32_", "sys_", entry))
+    candidates.append(re.sub(r"_wrapper$", "", entry))
+    candidates.append("sys_" + name)
+    candidates.append("sys_" + re.sub(r"(_time64|64|32)$", "", name))
+
+    for v in candidates:
+        if v in protos:
+            return v
+
+    print("No prototype for:", name, entry, file=sys.stderr)
+    return None
+
+
+def meta_args(*, protos, proto, bits, align64):
+    """Classify Arguments
+
+    Classify the arguments of the prototype `proto` and return the kinds of
+    all argument registers.
+    """
+
+    res = []
+
+    for i, param in enumerate(protos[proto]):
+        tokens = re.findall(r"[\w]+|\*", param)
+        named = len(tokens) > 1 and tokens[-1] != "*" and \
+            tokens[-1] not in ["int", "long", "short", "char", "unsigned"]
+        name = tokens[-1] if named else None
+        types = tokens[:-1] if named else tokens
+
+        if "*" in types or any(v in POINTER_TYPES for v in types):
+            kind = "Ptr"
+        elif any(v in WIDE_TYPES for v in types) or \
+                " ".join(types).endswith("long long"):
+            kind = "Wide"
+        elif name is None:
+            kind = "Fd" if i == 0 and proto in UNNAMED_FD else "Value"
+        elif name in ["fildes", "mqdes", "fdin", "fdout"] or \
+                name.endswith("fd") or name.startswith("fd_"):
+            kind = "Fd"
+        elif "flag" in name or "flg" in name or name == "options":
+            kind = "Flags"
+        else:
+            kind = "Value"
+
+        if kind == "Wide" and bits == 32:
+            if align64 and len(res) % 2 == 1:
+                res.append("Pad")
+            res += ["Lo64", "Hi64"]
+        elif kind == "Wide":
+            res.append("Value")
+        else:
+            res.append(kind)
+
+    return res
+
+
+def meta_emit(*, systbl, protos, bits, align64):
+    """Emit Rustified Metadata Table
+
+    Emit rust code as expected by the crate, which contains the metadata
+    table of all system calls, and return it as string. `systbl` is a list of
+    `[nr, name, entry]` entries, ordered by number. `entry` is `None` if the
+    system call is not implemented.
+    """
+
+    res = [
+        "// This code is generated.",
+        "use crate::syscall::meta::{Arg::*, Syscall};",
+        "",
+        "pub const TABLE: &[Syscall] = &[",
+    ]
+    for nr, name, entry in systbl:
+        proto = meta_resolve(protos=protos, name=name, entry=entry)
+        if proto is None:
+            args = "None"
+        else:
+            kinds = meta_args(
+                protos=protos,
+                proto=proto,
+                bits=bits,
+                align64=align64,
+            )
+            args = "Some(&[" + ", ".join(kinds) + "])"
+        nr = f"{nr:#x}" if nr > 0xffff else f"{nr}"
+        res.append(f"    Syscall {{ name: \"{name}\", nr: {nr}, args: {args} }},")
+    res.append("];")
+
+    return "".join(v + "\n" for v in res)
@@ -0,0 +1,356 @@
+// This code is generated.
+use crate::syscall::meta::{Arg::*, Syscall};
+
+pub const TABLE: &[Syscall] = &[
+    Syscall { name: "read", nr: 0x40000000, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "write", nr: 0x40000001, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "open", nr: 0x40000002, args: Some(&[Ptr, Flags, Value]) },
+    Syscall { name: "close", nr: 0x40000003, args: Some(&[Fd]) },
+    Syscall { name: "stat", nr: 0x40000004, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstat", nr: 0x40000005, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "lstat", nr: 0x40000006, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "poll", nr: 0x40000007, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "lseek", nr: 0x40000008, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "mmap", nr: 0x40000009, args: Some(&[Value, Value, Value, Flags, Fd, Value]) },
+    Syscall { name: "mprotect", nr: 0x4000000a, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "munmap", nr: 0x4000000b, args: Some(&[Value, Value]) },
+    Syscall { name: "brk", nr: 0x4000000c, args: Some(&[Value]) },
+    Syscall { name: "rt_sigprocmask", nr: 0x4000000e, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "pread64", nr: 0x40000011, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "pwrite64", nr: 0x40000012, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "access", nr: 0x40000015, args: Some(&[Ptr, Value]) },
+    Syscall { name: "pipe", nr: 0x40000016, args: Some(&[Ptr]) },
+    Syscall { name: "select", nr: 0x40000017, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "sched_yield", nr: 0x40000018, args: Some(&[]) },
+    Syscall { name: "mremap", nr: 0x40000019, args: Some(&[Value, Value, Value, Flags, Value]) },
+    Syscall { name: "msync", nr: 0x4000001a, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "mincore", nr: 0x4000001b, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "madvise", nr: 0x4000001c, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "shmget", nr: 0x4000001d, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "shmat", nr: 0x4000001e, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "shmctl", nr: 0x4000001f, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "dup", nr: 0x40000020, args: Some(&[Fd]) },
+    Syscall { name: "dup2", nr: 0x40000021, args: Some(&[Fd, Fd]) },
+    Syscall { name: "pause", nr: 0x40000022, args: Some(&[]) },
+    Syscall { name: "nanosleep", nr: 0x40000023, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getitimer", nr: 0x40000024, args: Some(&[Value, Ptr]) },
+    Syscall { name: "alarm", nr: 0x40000025, args: Some(&[Value]) },
+    Syscall { name: "setitimer", nr: 0x40000026, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "getpid", nr: 0x40000027, args: Some(&[]) },
+    Syscall { name: "sendfile", nr: 0x40000028, args: Some(&[Fd, Fd, Ptr, Value]) },
+    Syscall { name: "socket", nr: 0x40000029, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "connect", nr: 0x4000002a, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "accept", nr: 0x4000002b, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "sendto", nr: 0x4000002c, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "shutdown", nr: 0x40000030, args: Some(&[Fd, Value]) },
+    Syscall { name: "bind", nr: 0x40000031, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "listen", nr: 0x40000032, args: Some(&[Fd, Value]) },
+    Syscall { name: "getsockname", nr: 0x40000033, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "getpeername", nr: 0x40000034, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "socketpair", nr: 0x40000035, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "clone", nr: 0x40000038, args: Some(&[Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fork", nr: 0x40000039, args: Some(&[]) },
+    Syscall { name: "vfork", nr: 0x4000003a, args: Some(&[]) },
+    Syscall { name: "exit", nr: 0x4000003c, args: Some(&[Value]) },
+    Syscall { name: "wait4", nr: 0x4000003d, args: Some(&[Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "kill", nr: 0x4000003e, args: Some(&[Value, Value]) },
+    Syscall { name: "uname", nr: 0x4000003f, args: Some(&[Ptr]) },
+    Syscall { name: "semget", nr: 0x40000040, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "semop", nr: 0x40000041, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "semctl", nr: 0x40000042, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "shmdt", nr: 0x40000043, args: Some(&[Ptr]) },
+    Syscall { name: "msgget", nr: 0x40000044, args: Some(&[Value, Flags]) },
+    Syscall { name: "msgsnd", nr: 0x40000045, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "msgrcv", nr: 0x40000046, args: Some(&[Value, Ptr, Value, Value, Flags]) },
+    Syscall { name: "msgctl", nr: 0x40000047, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "fcntl", nr: 0x40000048, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "flock", nr: 0x40000049, args: Some(&[Fd, Value]) },
+    Syscall { name: "fsync", nr: 0x4000004a, args: Some(&[Fd]) },
+    Syscall { name: "fdatasync", nr: 0x4000004b, args: Some(&[Fd]) },
+    Syscall { name: "truncate", nr: 0x4000004c, args: Some(&[Ptr, Value]) },
+    Syscall { name: "ftruncate", nr: 0x4000004d, args: Some(&[Fd, Value]) },
+    Syscall { name: "getdents", nr: 0x4000004e, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "getcwd", nr: 0x4000004f, args: Some(&[Ptr, Value]) },
+    Syscall { name: "chdir", nr: 0x40000050, args: Some(&[Ptr]) },
+    Syscall { name: "fchdir", nr: 0x40000051, args: Some(&[Fd]) },
+    Syscall { name: "rename", nr: 0x40000052, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mkdir", nr: 0x40000053, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rmdir", nr: 0x40000054, args: Some(&[Ptr]) },
+    Syscall { name: "creat", nr: 0x40000055, args: Some(&[Ptr, Value]) },
+    Syscall { name: "link", nr: 0x40000056, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "unlink", nr: 0x40000057, args: Some(&[Ptr]) },
+    Syscall { name: "symlink", nr: 0x40000058, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "readlink", nr: 0x40000059, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "chmod", nr: 0x4000005a, args: Some(&[Ptr, Value]) },
+    Syscall { name: "fchmod", nr: 0x4000005b, args: Some(&[Fd, Value]) },
+    Syscall { name: "chown", nr: 0x4000005c, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "fchown", nr: 0x4000005d, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "lchown", nr: 0x4000005e, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "umask", nr: 0x4000005f, args: Some(&[Value]) },
+    Syscall { name: "gettimeofday", nr: 0x40000060, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getrlimit", nr: 0x40000061, args: Some(&[Value, Ptr]) },
+    Syscall { name: "getrusage", nr: 0x40000062, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sysinfo", nr: 0x40000063, args: Some(&[Ptr]) },
+    Syscall { name: "times", nr: 0x40000064, args: Some(&[Ptr]) },
+    Syscall { name: "getuid", nr: 0x40000066, args: Some(&[]) },
+    Syscall { name: "syslog", nr: 0x40000067, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "getgid", nr: 0x40000068, args: Some(&[]) },
+    Syscall { name: "setuid", nr: 0x40000069, args: Some(&[Value]) },
+    Syscall { name: "setgid", nr: 0x4000006a, args: Some(&[Value]) },
+    Syscall { name: "geteuid", nr: 0x4000006b, args: Some(&[]) },
+    Syscall { name: "getegid", nr: 0x4000006c, args: Some(&[]) },
+    Syscall { name: "setpgid", nr: 0x4000006d, args: Some(&[Value, Value]) },
+    Syscall { name: "getppid", nr: 0x4000006e, args: Some(&[]) },
+    Syscall { name: "getpgrp", nr: 0x4000006f, args: Some(&[]) },
+    Syscall { name: "setsid", nr: 0x40000070, args: Some(&[]) },
+    Syscall { name: "setreuid", nr: 0x40000071, args: Some(&[Value, Value]) },
+    Syscall { name: "setregid", nr: 0x40000072, args: Some(&[Value, Value]) },
+    Syscall { name: "getgroups", nr: 0x40000073, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setgroups", nr: 0x40000074, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setresuid", nr: 0x40000075, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresuid", nr: 0x40000076, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "setresgid", nr: 0x40000077, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresgid", nr: 0x40000078, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "getpgid", nr: 0x40000079, args: Some(&[Value]) },
+    Syscall { name: "setfsuid", nr: 0x4000007a, args: Some(&[Value]) },
+    Syscall { name: "setfsgid", nr: 0x4000007b, args: Some(&[Value]) },
+    Syscall { name: "getsid", nr: 0x4000007c, args: Some(&[Value]) },
+    Syscall { name: "capget", nr: 0x4000007d, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "capset", nr: 0x4000007e, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "rt_sigsuspend", nr: 0x40000082, args: Some(&[Ptr, Value]) },
+    Syscall { name: "utime", nr: 0x40000084, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mknod", nr: 0x40000085, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "personality", nr: 0x40000087, args: Some(&[Value]) },
+    Syscall { name: "ustat", nr: 0x40000088, args: Some(&[Value, Ptr]) },
+    Syscall { name: "statfs", nr: 0x40000089, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstatfs", nr: 0x4000008a, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "sysfs", nr: 0x4000008b, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getpriority", nr: 0x4000008c, args: Some(&[Value, Value]) },
+    Syscall { name: "setpriority", nr: 0x4000008d, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "sched_setparam", nr: 0x4000008e, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_getparam", nr: 0x4000008f, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_setscheduler", nr: 0x40000090, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getscheduler", nr: 0x40000091, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_max", nr: 0x40000092, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_min", nr: 0x40000093, args: Some(&[Value]) },
+    Syscall { name: "sched_rr_get_interval", nr: 0x40000094, args: Some(&[Value, Ptr]) },
+    Syscall { name: "mlock", nr: 0x40000095, args: Some(&[Value, Value]) },
+    Syscall { name: "munlock", nr: 0x40000096, args: Some(&[Value, Value]) },
+    Syscall { name: "mlockall", nr: 0x40000097, args: Some(&[Flags]) },
+    Syscall { name: "munlockall", nr: 0x40000098, args: Some(&[]) },
+    Syscall { name: "vhangup", nr: 0x40000099, args: Some(&[]) },
+    Syscall { name: "modify_ldt", nr: 0x4000009a, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "pivot_root", nr: 0x4000009b, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "prctl", nr: 0x4000009d, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "arch_prctl", nr: 0x4000009e, args: Some(&[Value, Value]) },
+    Syscall { name: "adjtimex", nr: 0x4000009f, args: Some(&[Ptr]) },
+    Syscall { name: "setrlimit", nr: 0x400000a0, args: Some(&[Value, Ptr]) },
+    Syscall { name: "chroot", nr: 0x400000a1, args: Some(&[Ptr]) },
+    Syscall { name: "sync", nr: 0x400000a2, args: Some(&[]) },
+    Syscall { name: "acct", nr: 0x400000a3, args: Some(&[Ptr]) },
+    Syscall { name: "settimeofday", nr: 0x400000a4, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mount", nr: 0x400000a5, args: Some(&[Ptr, Ptr, Ptr, Flags, Ptr]) },
+    Syscall { name: "umount2", nr: 0x400000a6, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "swapon", nr: 0x400000a7, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "swapoff", nr: 0x400000a8, args: Some(&[Ptr]) },
+    Syscall { name: "reboot", nr: 0x400000a9, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "sethostname", nr: 0x400000aa, args: Some(&[Ptr, Value]) },
+    Syscall { name: "setdomainname", nr: 0x400000ab, args: Some(&[Ptr, Value]) },
+    Syscall { name: "iopl", nr: 0x400000ac, args: Some(&[Value]) },
+    Syscall { name: "ioperm", nr: 0x400000ad, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "init_module", nr: 0x400000af, args: Some(&[Ptr, Value, Ptr]) },
+    Syscall { name: "delete_module", nr: 0x400000b0, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "quotactl", nr: 0x400000b3, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "getpmsg", nr: 0x400000b5, args: None },
+    Syscall { name: "putpmsg", nr: 0x400000b6, args: None },
+    Syscall { name: "afs_syscall", nr: 0x400000b7, args: None },
+    Syscall { name: "tuxcall", nr: 0x400000b8, args: None },
+    Syscall { name: "security", nr: 0x400000b9, args: None },
+    Syscall { name: "gettid", nr: 0x400000ba, args: Some(&[]) },
+    Syscall { name: "readahead", nr: 0x400000bb, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "setxattr", nr: 0x400000bc, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "lsetxattr", nr: 0x400000bd, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "fsetxattr", nr: 0x400000be, args: Some(&[Fd, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "getxattr", nr: 0x400000bf, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "lgetxattr", nr: 0x400000c0, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "fgetxattr", nr: 0x400000c1, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "listxattr", nr: 0x400000c2, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "llistxattr", nr: 0x400000c3, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "flistxattr", nr: 0x400000c4, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "removexattr", nr: 0x400000c5, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lremovexattr", nr: 0x400000c6, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fremovexattr", nr: 0x400000c7, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "tkill", nr: 0x400000c8, args: Some(&[Value, Value]) },
+    Syscall { name: "time", nr: 0x400000c9, args: Some(&[Ptr]) },
+    Syscall { name: "futex", nr: 0x400000ca, args: Some(&[Ptr, Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "sched_setaffinity", nr: 0x400000cb, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getaffinity", nr: 0x400000cc, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "io_destroy", nr: 0x400000cf, args: Some(&[Value]) },
+    Syscall { name: "io_getevents", nr: 0x400000d0, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "io_cancel", nr: 0x400000d2, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "lookup_dcookie", nr: 0x400000d4, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "epoll_create", nr: 0x400000d5, args: Some(&[Value]) },
+    Syscall { name: "remap_file_pages", nr: 0x400000d8, args: Some(&[Value, Value, Value, Value, Flags]) },
+    Syscall { name: "getdents64", nr: 0x400000d9, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "set_tid_address", nr: 0x400000da, args: Some(&[Ptr]) },
+    Syscall { name: "restart_syscall", nr: 0x400000db, args: Some(&[]) },
+    Syscall { name: "semtimedop", nr: 0x400000dc, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "fadvise64", nr: 0x400000dd, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "timer_settime", nr: 0x400000df, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timer_gettime", nr: 0x400000e0, args: Some(&[Value, Ptr]) },
+    Syscall { name: "timer_getoverrun", nr: 0x400000e1, args: Some(&[Value]) },
+    Syscall { name: "timer_delete", nr: 0x400000e2, args: Some(&[Value]) },
+    Syscall { name: "clock_settime", nr: 0x400000e3, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_gettime", nr: 0x400000e4, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_getres", nr: 0x400000e5, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_nanosleep", nr: 0x400000e6, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "exit_group", nr: 0x400000e7, args: Some(&[Value]) },
+    Syscall { name: "epoll_wait", nr: 0x400000e8, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "epoll_ctl", nr: 0x400000e9, args: Some(&[Fd, Value, Fd, Ptr]) },
+    Syscall { name: "tgkill", nr: 0x400000ea, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "utimes", nr: 0x400000eb, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mbind", nr: 0x400000ed, args: Some(&[Value, Value, Value, Ptr, Value, Flags]) },
+    Syscall { name: "set_mempolicy", nr: 0x400000ee, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "get_mempolicy", nr: 0x400000ef, args: Some(&[Ptr, Ptr, Value, Value, Flags]) },
+    Syscall { name: "mq_open", nr: 0x400000f0, args: Some(&[Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "mq_unlink", nr: 0x400000f1, args: Some(&[Ptr]) },
+    Syscall { name: "mq_timedsend", nr: 0x400000f2, args: Some(&[Fd, Ptr, Value, Value, Ptr]) },
+    Syscall { name: "mq_timedreceive", nr: 0x400000f3, args: Some(&[Fd, Ptr, Value, Ptr, Ptr]) },
+    Syscall { name: "mq_getsetattr", nr: 0x400000f5, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "add_key", nr: 0x400000f8, args: Some(&[Ptr, Ptr, Ptr, Value, Value]) },
+    Syscall { name: "request_key", nr: 0x400000f9, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "keyctl", nr: 0x400000fa, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "ioprio_set", nr: 0x400000fb, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "ioprio_get", nr: 0x400000fc, args: Some(&[Value, Value]) },
+    Syscall { name: "inotify_init", nr: 0x400000fd, args: Some(&[]) },
+    Syscall { name: "inotify_add_watch", nr: 0x400000fe, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "inotify_rm_watch", nr: 0x400000ff, args: Some(&[Fd, Value]) },
+    Syscall { name: "migrate_pages", nr: 0x40000100, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "openat", nr: 0x40000101, args: Some(&[Fd, Ptr, Flags, Value]) },
+    Syscall { name: "mkdirat", nr: 0x40000102, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "mknodat", nr: 0x40000103, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "fchownat", nr: 0x40000104, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "futimesat", nr: 0x40000105, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "newfstatat", nr: 0x40000106, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "unlinkat", nr: 0x40000107, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "renameat", nr: 0x40000108, args: Some(&[Fd, Ptr, Fd, Ptr]) },
+    Syscall { name: "linkat", nr: 0x40000109, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "symlinkat", nr: 0x4000010a, args: Some(&[Ptr, Fd, Ptr]) },
+    Syscall { name: "readlinkat", nr: 0x4000010b, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "fchmodat", nr: 0x4000010c, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "faccessat", nr: 0x4000010d, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "pselect6", nr: 0x4000010e, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "ppoll", nr: 0x4000010f, args: Some(&[Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "unshare", nr: 0x40000110, args: Some(&[Flags]) },
+    Syscall { name: "splice", nr: 0x40000113, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "tee", nr: 0x40000114, args: Some(&[Fd, Fd, Value, Flags]) },
+    Syscall { name: "sync_file_range", nr: 0x40000115, args: Some(&[Fd, Value, Value, Flags]) },
+    Syscall { name: "utimensat", nr: 0x40000118, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "epoll_pwait", nr: 0x40000119, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "signalfd", nr: 0x4000011a, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "timerfd_create", nr: 0x4000011b, args: Some(&[Value, Flags]) },
+    Syscall { name: "eventfd", nr: 0x4000011c, args: Some(&[Value]) },
+    Syscall { name: "fallocate", nr: 0x4000011d, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "timerfd_settime", nr: 0x4000011e, args: Some(&[Fd, Flags, Ptr, Ptr]) },
+    Syscall { name: "timerfd_gettime", nr: 0x4000011f, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "accept4", nr: 0x40000120, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "signalfd4", nr: 0x40000121, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "eventfd2", nr: 0x40000122, args: Some(&[Value, Flags]) },
+    Syscall { name: "epoll_create1", nr: 0x40000123, args: Some(&[Flags]) },
+    Syscall { name: "dup3", nr: 0x40000124, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "pipe2", nr: 0x40000125, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "inotify_init1", nr: 0x40000126, args: Some(&[Flags]) },
+    Syscall { name: "perf_event_open", nr: 0x4000012a, args: Some(&[Ptr, Value, Value, Fd, Flags]) },
+    Syscall { name: "fanotify_init", nr: 0x4000012c, args: Some(&[Flags, Flags]) },
+    Syscall { name: "fanotify_mark", nr: 0x4000012d, args: Some(&[Fd, Flags, Value, Fd, Ptr]) },
+    Syscall { name: "prlimit64", nr: 0x4000012e, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "name_to_handle_at", nr: 0x4000012f, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "open_by_handle_at", nr: 0x40000130, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "clock_adjtime", nr: 0x40000131, args: Some(&[Value, Ptr]) },
+    Syscall { name: "syncfs", nr: 0x40000132, args: Some(&[Fd]) },
+    Syscall { name: "setns", nr: 0x40000134, args: Some(&[Fd, Value]) },
+    Syscall { name: "getcpu", nr: 0x40000135, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "kcmp", nr: 0x40000138, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "finit_module", nr: 0x40000139, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "sched_setattr", nr: 0x4000013a, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "sched_getattr", nr: 0x4000013b, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "renameat2", nr: 0x4000013c, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "seccomp", nr: 0x4000013d, args: Some(&[Value, Flags, Ptr]) },
+    Syscall { name: "getrandom", nr: 0x4000013e, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "memfd_create", nr: 0x4000013f, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "kexec_file_load", nr: 0x40000140, args: Some(&[Fd, Fd, Value, Ptr, Flags]) },
+    Syscall { name: "bpf", nr: 0x40000141, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "userfaultfd", nr: 0x40000143, args: Some(&[Flags]) },
+    Syscall { name: "membarrier", nr: 0x40000144, args: Some(&[Value, Flags, Value]) },
+    Syscall { name: "mlock2", nr: 0x40000145, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "copy_file_range", nr: 0x40000146, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "pkey_mprotect", nr: 0x40000149, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "pkey_alloc", nr: 0x4000014a, args: Some(&[Flags, Value]) },
+    Syscall { name: "pkey_free", nr: 0x4000014b, args: Some(&[Value]) },
+    Syscall { name: "statx", nr: 0x4000014c, args: Some(&[Fd, Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "io_pgetevents", nr: 0x4000014d, args: Some(&[Value, Value, Value, Ptr, Ptr, Ptr]) },
+    Syscall { name: "rseq", nr: 0x4000014e, args: Some(&[Ptr, Value, Flags, Value]) },
+    Syscall { name: "pidfd_send_signal", nr: 0x400001a8, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "io_uring_setup", nr: 0x400001a9, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_uring_enter", nr: 0x400001aa, args: Some(&[Fd, Value, Value, Flags, Ptr, Value]) },
+    Syscall { name: "io_uring_register", nr: 0x400001ab, args: Some(&[Fd, Value, Ptr, Value]) },
+    Syscall { name: "open_tree", nr: 0x400001ac, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "move_mount", nr: 0x400001ad, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "fsopen", nr: 0x400001ae, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "fsconfig", nr: 0x400001af, args: Some(&[Fd, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fsmount", nr: 0x400001b0, args: Some(&[Fd, Flags, Flags]) },
+    Syscall { name: "fspick", nr: 0x400001b1, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "pidfd_open", nr: 0x400001b2, args: Some(&[Value, Flags]) },
+    Syscall { name: "clone3", nr: 0x400001b3, args: Some(&[Ptr, Value]) },
+    Syscall { name: "close_range", nr: 0x400001b4, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "openat2", nr: 0x400001b5, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "pidfd_getfd", nr: 0x400001b6, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "faccessat2", nr: 0x400001b7, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_madvise", nr: 0x400001b8, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "epoll_pwait2", nr: 0x400001b9, args: Some(&[Fd, Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "mount_setattr", nr: 0x400001ba, args: Some(&[Fd, Ptr, Flags, Ptr, Value]) },
+    Syscall { name: "quotactl_fd", nr: 0x400001bb, args: Some(&[Fd, Value, Value, Ptr]) },
+    Syscall { name: "landlock_create_ruleset", nr: 0x400001bc, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "landlock_add_rule", nr: 0x400001bd, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "landlock_restrict_self", nr: 0x400001be, args: Some(&[Fd, Flags]) },
+    Syscall { name: "memfd_secret", nr: 0x400001bf, args: Some(&[Flags]) },
+    Syscall { name: "process_mrelease", nr: 0x400001c0, args: Some(&[Fd, Flags]) },
+    Syscall { name: "futex_waitv", nr: 0x400001c1, args: Some(&[Ptr, Value, Flags, Ptr, Value]) },
+    Syscall { name: "set_mempolicy_home_node", nr: 0x400001c2, args: Some(&[Value, Value, Value, Flags]) },
+    Syscall { name: "rt_sigaction", nr: 0x40000200, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigreturn", nr: 0x40000201, args: Some(&[]) },
+    Syscall { name: "ioctl", nr: 0x40000202, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "readv", nr: 0x40000203, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "writev", nr: 0x40000204, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "recvfrom", nr: 0x40000205, args: Some(&[Fd, Ptr, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "sendmsg", nr: 0x40000206, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "recvmsg", nr: 0x40000207, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "execve", nr: 0x40000208, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "ptrace", nr: 0x40000209, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "rt_sigpending", nr: 0x4000020a, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rt_sigtimedwait", nr: 0x4000020b, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigqueueinfo", nr: 0x4000020c, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sigaltstack", nr: 0x4000020d, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "timer_create", nr: 0x4000020e, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "mq_notify", nr: 0x4000020f, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "kexec_load", nr: 0x40000210, args: Some(&[Value, Value, Ptr, Flags]) },
+    Syscall { name: "waitid", nr: 0x40000211, args: Some(&[Value, Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "set_robust_list", nr: 0x40000212, args: Some(&[Ptr, Value]) },
+    Syscall { name: "get_robust_list", nr: 0x40000213, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "vmsplice", nr: 0x40000214, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "move_pages", nr: 0x40000215, args: Some(&[Value, Value, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "preadv", nr: 0x40000216, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "pwritev", nr: 0x40000217, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "rt_tgsigqueueinfo", nr: 0x40000218, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "recvmmsg", nr: 0x40000219, args: Some(&[Fd, Ptr, Value, Flags, Ptr]) },
+    Syscall { name: "sendmmsg", nr: 0x4000021a, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_readv", nr: 0x4000021b, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_writev", nr: 0x4000021c, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "setsockopt", nr: 0x4000021d, args: Some(&[Fd, Value, Value, Ptr, Value]) },
+    Syscall { name: "getsockopt", nr: 0x4000021e, args: Some(&[Fd, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "io_setup", nr: 0x4000021f, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_submit", nr: 0x40000220, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "execveat", nr: 0x40000221, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "preadv2", nr: 0x40000222, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pwritev2", nr: 0x40000223, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+];
@@ -0,0 +1,352 @@
+// This code is generated.
+pub const READ: usize = 1073741824;
+pub const WRITE: usize = 1073741825;
+pub const OPEN: usize = 1073741826;
+pub const CLOSE: usize = 1073741827;
+pub const STAT: usize = 1073741828;
+pub const FSTAT: usize = 1073741829;
+pub const LSTAT: usize = 1073741830;
+pub const POLL: usize = 1073741831;
+pub const LSEEK: usize = 1073741832;
+pub const MMAP: usize = 1073741833;
+pub const MPROTECT: usize = 1073741834;
+pub const MUNMAP: usize = 1073741835;
+pub const BRK: usize = 1073741836;
+pub const RT_SIGPROCMASK: usize = 1073741838;
+pub const PREAD64: usize = 1073741841;
+pub const PWRITE64: usize = 1073741842;
+pub const ACCESS: usize = 1073741845;
+pub const PIPE: usize = 1073741846;
+pub const SELECT: usize = 1073741847;
+pub const SCHED_YIELD: usize = 1073741848;
+pub const MREMAP: usize = 1073741849;
+pub const MSYNC: usize = 1073741850;
+pub const MINCORE: usize = 1073741851;
+pub const MADVISE: usize = 1073741852;
+pub const SHMGET: usize = 1073741853;
+pub const SHMAT: usize = 1073741854;
+pub const SHMCTL: usize = 1073741855;
+pub const DUP: usize = 1073741856;
+pub const DUP2: usize = 1073741857;
+pub const PAUSE: usize = 1073741858;
+pub const NANOSLEEP: usize = 1073741859;
+pub const GETITIMER: usize = 1073741860;
+pub const ALARM: usize = 1073741861;
+pub const SETITIMER: usize = 1073741862;
+pub const GETPID: usize = 1073741863;
+pub const SENDFILE: usize = 1073741864;
+pub const SOCKET: usize = 1073741865;
+pub const CONNECT: usize = 1073741866;
+pub const ACCEPT: usize = 1073741867;
+pub const SENDTO: usize = 1073741868;
+pub const SHUTDOWN: usize = 1073741872;
+pub const BIND: usize = 1073741873;
+pub const LISTEN: usize = 1073741874;
+pub const GETSOCKNAME: usize = 1073741875;
+pub const GETPEERNAME: usize = 1073741876;
+pub const SOCKETPAIR: usize = 1073741877;
+pub const CLONE: usize = 1073741880;
+pub const FORK: usize = 1073741881;
+pub const VFORK: usize = 1073741882;
+pub const EXIT: usize = 1073741884;
+pub const WAIT4: usize = 1073741885;
+pub const KILL: usize = 1073741886;
+pub const UNAME: usize = 1073741887;
+pub const SEMGET: usize = 1073741888;
+pub const SEMOP: usize = 1073741889;
+pub const SEMCTL: usize = 1073741890;
+pub const SHMDT: usize = 1073741891;
+pub const MSGGET: usize = 1073741892;
+pub const MSGSND: usize = 1073741893;
+pub const MSGRCV: usize = 1073741894;
+pub const MSGCTL: usize = 1073741895;
+pub const FCNTL: usize = 1073741896;
+pub const FLOCK: usize = 1073741897;
+pub const FSYNC: usize = 1073741898;
+pub const FDATASYNC: usize = 1073741899;
+pub const TRUNCATE: usize = 1073741900;
+pub const FTRUNCATE: usize = 1073741901;
+pub const GETDENTS: usize = 1073741902;
+pub const GETCWD: usize = 1073741903;
+pub const CHDIR: usize = 1073741904;
+pub const FCHDIR: usize = 1073741905;
+pub const RENAME: usize = 1073741906;
+pub const MKDIR: usize = 1073741907;
+pub const RMDIR: usize = 1073741908;
+pub const CREAT: usize = 1073741909;
+pub const LINK: usize = 1073741910;
+pub const UNLINK: usize = 1073741911;
+pub const SYMLINK: usize = 1073741912;
+pub const READLINK: usize = 1073741913;
+pub const CHMOD: usize = 1073741914;
+pub const FCHMOD: usize = 1073741915;
+pub const CHOWN: usize = 1073741916;
+pub const FCHOWN: usize = 1073741917;
+pub const LCHOWN: usize = 1073741918;
+pub const UMASK: usize = 1073741919;
+pub const GETTIMEOFDAY: usize = 1073741920;
+pub const GETRLIMIT: usize = 1073741921;
+pub const GETRUSAGE: usize = 1073741922;
+pub const SYSINFO: usize = 1073741923;
+pub const TIMES: usize = 1073741924;
+pub const GETUID: usize = 1073741926;
+pub const SYSLOG: usize = 1073741927;
+pub const GETGID: usize = 1073741928;
+pub const SETUID: usize = 1073741929;
+pub const SETGID: usize = 1073741930;
+pub const GETEUID: usize = 1073741931;
+pub const GETEGID: usize = 1073741932;
+pub const SETPGID: usize = 1073741933;
+pub const GETPPID: usize = 1073741934;
+pub const GETPGRP: usize = 1073741935;
+pub const SETSID: usize = 1073741936;
+pub const SETREUID: usize = 1073741937;
+pub const SETREGID: usize = 1073741938;
+pub const GETGROUPS: usize = 1073741939;
+pub const SETGROUPS: usize = 1073741940;
+pub const SETRESUID: usize = 1073741941;
+pub const GETRESUID: usize = 1073741942;
+pub const SETRESGID: usize = 1073741943;
+pub const GETRESGID: usize = 1073741944;
+pub const GETPGID: usize = 1073741945;
+pub const SETFSUID: usize = 1073741946;
+pub const SETFSGID: usize = 1073741947;
+pub const GETSID: usize = 1073741948;
+pub const CAPGET: usize = 1073741949;
+pub const CAPSET: usize = 1073741950;
+pub const RT_SIGSUSPEND: usize = 1073741954;
+pub const UTIME: usize = 1073741956;
+pub const MKNOD: usize = 1073741957;
+pub const PERSONALITY: usize = 1073741959;
+pub const USTAT: usize = 1073741960;
+pub const STATFS: usize = 1073741961;
+pub const FSTATFS: usize = 1073741962;
+pub const SYSFS: usize = 1073741963;
+pub const GETPRIORITY: usize = 1073741964;
+pub const SETPRIORITY: usize = 1073741965;
+pub const SCHED_SETPARAM: usize = 1073741966;
+pub const SCHED_GETPARAM: usize = 1073741967;
+pub const SCHED_SETSCHEDULER: usize = 1073741968;
+pub const SCHED_GETSCHEDULER: usize = 1073741969;
+pub const SCHED_GET_PRIORITY_MAX: usize = 1073741970;
+pub const SCHED_GET_PRIORITY_MIN: usize = 1073741971;
+pub const SCHED_RR_GET_INTERVAL: usize = 1073741972;
+pub const MLOCK: usize = 1073741973;
+pub const MUNLOCK: usize = 1073741974;
+pub const MLOCKALL: usize = 1073741975;
+pub const MUNLOCKALL: usize = 1073741976;
+pub const VHANGUP: usize = 1073741977;
+pub const MODIFY_LDT: usize = 1073741978;
+pub const PIVOT_ROOT: usize = 1073741979;
+pub const PRCTL: usize = 1073741981;
+pub const ARCH_PRCTL: usize = 1073741982;
+pub const ADJTIMEX: usize = 1073741983;
+pub const SETRLIMIT: usize = 1073741984;
+pub const CHROOT: usize = 1073741985;
+pub const SYNC: usize = 1073741986;
+pub const ACCT: usize = 1073741987;
+pub const SETTIMEOFDAY: usize = 1073741988;
+pub const MOUNT: usize = 1073741989;
+pub const UMOUNT2: usize = 1073741990;
+pub const SWAPON: usize = 1073741991;
+pub const SWAPOFF: usize = 1073741992;
+pub const REBOOT: usize = 1073741993;
+pub const SETHOSTNAME: usize = 1073741994;
+pub const SETDOMAINNAME: usize = 1073741995;
+pub const IOPL: usize = 1073741996;
+pub const IOPERM: usize = 1073741997;
+pub const INIT_MODULE: usize = 1073741999;
+pub const DELETE_MODULE: usize = 1073742000;
+pub const QUOTACTL: usize = 1073742003;
+pub const GETPMSG: usize = 1073742005;
+pub const PUTPMSG: usize = 1073742006;
+pub const AFS_SYSCALL: usize = 1073742007;
+pub const TUXCALL: usize = 1073742008;
+pub const SECURITY: usize = 1073742009;
+pub const GETTID: usize = 1073742010;
+pub const READAHEAD: usize = 1073742011;
+pub const SETXATTR: usize = 1073742012;
+pub const LSETXATTR: usize = 1073742013;
+pub const FSETXATTR: usize = 1073742014;
+pub const GETXATTR: usize = 1073742015;
+pub const LGETXATTR: usize = 1073742016;
+pub const FGETXATTR: usize = 1073742017;
+pub const LISTXATTR: usize = 1073742018;
+pub const LLISTXATTR: usize = 1073742019;
+pub const FLISTXATTR: usize = 1073742020;
+pub const REMOVEXATTR: usize = 1073742021;
+pub const LREMOVEXATTR: usize = 1073742022;
+pub const FREMOVEXATTR: usize = 1073742023;
+pub const TKILL: usize = 1073742024;
+pub const TIME: usize = 1073742025;
+pub const FUTEX: usize = 1073742026;
+pub const SCHED_SETAFFINITY: usize = 1073742027;
+pub const SCHED_GETAFFINITY: usize = 1073742028;
+pub const IO_DESTROY: usize = 1073742031;
+pub const IO_GETEVENTS: usize = 1073742032;
+pub const IO_CANCEL: usize = 1073742034;
+pub const LOOKUP_DCOOKIE: usize = 1073742036;
+pub const EPOLL_CREATE: usize = 1073742037;
+pub const REMAP_FILE_PAGES: usize = 1073742040;
+pub const GETDENTS64: usize = 1073742041;
+pub const SET_TID_ADDRESS: usize = 1073742042;
+pub const RESTART_SYSCALL: usize = 1073742043;
+pub const SEMTIMEDOP: usize = 1073742044;
+pub const FADVISE64: usize = 1073742045;
+pub const TIMER_SETTIME: usize = 1073742047;
+pub const TIMER_GETTIME: usize = 1073742048;
+pub const TIMER_GETOVERRUN: usize = 1073742049;
+pub const TIMER_DELETE: usize = 1073742050;
+pub const CLOCK_SETTIME: usize = 1073742051;
+pub const CLOCK_GETTIME: usize = 1073742052;
+pub const CLOCK_GETRES: usize = 1073742053;
+pub const CLOCK_NANOSLEEP: usize = 1073742054;
+pub const EXIT_GROUP: usize = 1073742055;
+pub const EPOLL_WAIT: usize = 1073742056;
+pub const EPOLL_CTL: usize = 1073742057;
+pub const TGKILL: usize = 1073742058;
+pub const UTIMES: usize = 1073742059;
+pub const MBIND: usize = 1073742061;
+pub const SET_MEMPOLICY: usize = 1073742062;
+pub const GET_MEMPOLICY: usize = 1073742063;
+pub const MQ_OPEN: usize = 1073742064;
+pub const MQ_UNLINK: usize = 1073742065;
+pub const MQ_TIMEDSEND: usize = 1073742066;
+pub const MQ_TIMEDRECEIVE: usize = 1073742067;
+pub const MQ_GETSETATTR: usize = 1073742069;
+pub const ADD_KEY: usize = 1073742072;
+pub const REQUEST_KEY: usize = 1073742073;
+pub const KEYCTL: usize = 1073742074;
+pub const IOPRIO_SET: usize = 1073742075;
+pub const IOPRIO_GET: usize = 1073742076;
+pub const INOTIFY_INIT: usize = 1073742077;
+pub const INOTIFY_ADD_WATCH: usize = 1073742078;
+pub const INOTIFY_RM_WATCH: usize = 1073742079;
+pub const MIGRATE_PAGES: usize = 1073742080;
+pub const OPENAT: usize = 1073742081;
+pub const MKDIRAT: usize = 1073742082;
+pub const MKNODAT: usize = 1073742083;
+pub const FCHOWNAT: usize = 1073742084;
+pub const FUTIMESAT: usize = 1073742085;
+pub const NEWFSTATAT: usize = 1073742086;
+pub const UNLINKAT: usize = 1073742087;
+pub const RENAMEAT: usize = 1073742088;
+pub const LINKAT: usize = 1073742089;
+pub const SYMLINKAT: usize = 1073742090;
+pub const READLINKAT: usize = 1073742091;
+pub const FCHMODAT: usize = 1073742092;
+pub const FACCESSAT: usize = 1073742093;
+pub const PSELECT6: usize = 1073742094;
+pub const PPOLL: usize = 1073742095;
+pub const UNSHARE: usize = 1073742096;
+pub const SPLICE: usize = 1073742099;
+pub const TEE: usize = 1073742100;
+pub const SYNC_FILE_RANGE: usize = 1073742101;
+pub const UTIMENSAT: usize = 1073742104;
+pub const EPOLL_PWAIT: usize = 1073742105;
+pub const SIGNALFD: usize = 1073742106;
+pub const TIMERFD_CREATE: usize = 1073742107;
+pub const EVENTFD: usize = 1073742108;
+pub const FALLOCATE: usize = 1073742109;
+pub const TIMERFD_SETTIME: usize = 1073742110;
+pub const TIMERFD_GETTIME: usize = 1073742111;
+pub const ACCEPT4: usize = 1073742112;
+pub const SIGNALFD4: usize = 1073742113;
+pub const EVENTFD2: usize = 1073742114;
+pub const EPOLL_CREATE1: usize = 1073742115;
+pub const DUP3: usize = 1073742116;
+pub const PIPE2: usize = 1073742117;
+pub const INOTIFY_INIT1: usize = 1073742118;
+pub const PERF_EVENT_OPEN: usize = 1073742122;
+pub const FANOTIFY_INIT: usize = 1073742124;
+pub const FANOTIFY_MARK: usize = 1073742125;
+pub const PRLIMIT64: usize = 1073742126;
+pub const NAME_TO_HANDLE_AT: usize = 1073742127;
+pub const OPEN_BY_HANDLE_AT: usize = 1073742128;
+pub const CLOCK_ADJTIME: usize = 1073742129;
+pub const SYNCFS: usize = 1073742130;
+pub const SETNS: usize = 1073742132;
+pub const GETCPU: usize = 1073742133;
+pub const KCMP: usize = 1073742136;
+pub const FINIT_MODULE: usize = 1073742137;
+pub const SCHED_SETATTR: usize = 1073742138;
+pub const SCHED_GETATTR: usize = 1073742139;
+pub const RENAMEAT2: usize = 1073742140;
+pub const SECCOMP: usize = 1073742141;
+pub const GETRANDOM: usize = 1073742142;
+pub const MEMFD_CREATE: usize = 1073742143;
+pub const KEXEC_FILE_LOAD: usize = 1073742144;
+pub const BPF: usize = 1073742145;
+pub const USERFAULTFD: usize = 1073742147;
+pub const MEMBARRIER: usize = 1073742148;
+pub const MLOCK2: usize = 1073742149;
+pub const COPY_FILE_RANGE: usize = 1073742150;
+pub const PKEY_MPROTECT: usize = 1073742153;
+pub const PKEY_ALLOC: usize = 1073742154;
+pub const PKEY_FREE: usize = 1073742155;
+pub const STATX: usize = 1073742156;
+pub const IO_PGETEVENTS: usize = 1073742157;
+pub const RSEQ: usize = 1073742158;
+pub const PIDFD_SEND_SIGNAL: usize = 1073742248;
+pub const IO_URING_SETUP: usize = 1073742249;
+pub const IO_URING_ENTER: usize = 1073742250;
+pub const IO_URING_REGISTER: usize = 1073742251;
+pub const OPEN_TREE: usize = 1073742252;
+pub const MOVE_MOUNT: usize = 1073742253;
+pub const FSOPEN: usize = 1073742254;
+pub const FSCONFIG: usize = 1073742255;
+pub const FSMOUNT: usize = 1073742256;
+pub const FSPICK: usize = 1073742257;
+pub const PIDFD_OPEN: usize = 1073742258;
+pub const CLONE3: usize = 1073742259;
+pub const CLOSE_RANGE: usize = 1073742260;
+pub const OPENAT2: usize = 1073742261;
+pub const PIDFD_GETFD: usize = 1073742262;
+pub const FACCESSAT2: usize = 1073742263;
+pub const PROCESS_MADVISE: usize = 1073742264;
+pub const EPOLL_PWAIT2: usize = 1073742265;
+pub const MOUNT_SETATTR: usize = 1073742266;
+pub const QUOTACTL_FD: usize = 1073742267;
+pub const LANDLOCK_CREATE_RULESET: usize = 1073742268;
+pub const LANDLOCK_ADD_RULE: usize = 1073742269;
+pub const LANDLOCK_RESTRICT_SELF: usize = 1073742270;
+pub const MEMFD_SECRET: usize = 1073742271;
+pub const PROCESS_MRELEASE: usize = 1073742272;
+pub const FUTEX_WAITV: usize = 1073742273;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 1073742274;
+pub const RT_SIGACTION: usize = 1073742336;
+pub const RT_SIGRETURN: usize = 1073742337;
+pub const IOCTL: usize = 1073742338;
+pub const READV: usize = 1073742339;
+pub const WRITEV: usize = 1073742340;
+pub const RECVFROM: usize = 1073742341;
+pub const SENDMSG: usize = 1073742342;
+pub const RECVMSG: usize = 1073742343;
+pub const EXECVE: usize = 1073742344;
+pub const PTRACE: usize = 1073742345;
+pub const RT_SIGPENDING: usize = 1073742346;
+pub const RT_SIGTIMEDWAIT: usize = 1073742347;
+pub const RT_SIGQUEUEINFO: usize = 1073742348;
+pub const SIGALTSTACK: usize = 1073742349;
+pub const TIMER_CREATE: usize = 1073742350;
+pub const MQ_NOTIFY: usize = 1073742351;
+pub const KEXEC_LOAD: usize = 1073742352;
+pub const WAITID: usize = 1073742353;
+pub const SET_ROBUST_LIST: usize = 1073742354;
+pub const GET_ROBUST_LIST: usize = 1073742355;
+pub const VMSPLICE: usize = 1073742356;
+pub const MOVE_PAGES: usize = 1073742357;
+pub const PREADV: usize = 1073742358;
+pub const PWRITEV: usize = 1073742359;
+pub const RT_TGSIGQUEUEINFO: usize = 1073742360;
+pub const RECVMMSG: usize = 1073742361;
+pub const SENDMMSG: usize = 1073742362;
+pub const PROCESS_VM_READV: usize = 1073742363;
+pub const PROCESS_VM_WRITEV: usize = 1073742364;
+pub const SETSOCKOPT: usize = 1073742365;
+pub const GETSOCKOPT: usize = 1073742366;
+pub const IO_SETUP: usize = 1073742367;
+pub const IO_SUBMIT: usize = 1073742368;
+pub const EXECVEAT: usize = 1073742369;
+pub const PREADV2: usize = 1073742370;
+pub const PWRITEV2: usize = 1073742371;
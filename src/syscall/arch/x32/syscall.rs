@@ -0,0 +1,796 @@
+//! System Calls on x32
+//!
+//! This implements the syscall entries for x32. One function for each
+//! possible number of arguments is provided: syscall0 to syscall6.
+//!
+//! The x32 ABI runs on x86_64 processors with 32-bit pointers, so this uses
+//! the same x86_64-`syscall` instruction to enter the kernel as the x86_64
+//! entries. The system call numbers have `__X32_SYSCALL_BIT` set, which tells
+//! the kernel to use the x32 ABI.
+//!
+//! The kernel uses the full 64-bit registers, but `usize` is only 32-bit wide
+//! on x32. Hence, all arguments are zero-extended before they are passed to
+//! the kernel, as the upper bits of a register are undefined otherwise. The
+//! return value is truncated again, which preserves error-returns, since they
+//! are sign-extended by the kernel.
+//!
+//! Some system calls take 64-bit arguments (e.g., file offsets) in a single
+//! register on x32. These cannot be passed via `usize`, so `syscallN_u64()`
+//! variants are provided, which take the full 64-bit register values.
+//!
+//! Arguments are passed as:
+//!     Nr: rax
+//!     Args: rdi, rsi, rdx, r10, r8, r9
+//! Return value is in:
+//!     Ret: rax
+//! Always clobbered:
+//!     rcx, r11
+//!
+//! The regular entry-points are not marked as `readonly`. That is, the system
+//! calls are allowed to modify memory. Alternative entry-points are provided
+//! for system calls with less side-effects: `syscallN_readonly()` tells the
+//! compiler that the system call does not write to memory of the calling
+//! process, and `syscallN_nomem()` tells it that the system call does not
+//! access memory of the calling process at all (e.g., `getpid()`). This allows
+//! the compiler to keep values in registers across the system call. Using
+//! these entry-points for system calls that do access memory is undefined
+//! behavior.
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall0"]
+pub unsafe fn syscall0(
+    nr: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall1"]
+pub unsafe fn syscall1(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall2"]
+pub unsafe fn syscall2(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall3"]
+pub unsafe fn syscall3(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall4"]
+pub unsafe fn syscall4(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall5"]
+pub unsafe fn syscall5(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+#[export_name = "r_linux_asm_syscall6"]
+pub unsafe fn syscall6(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        in("r9") arg5 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+// Read-Only Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call does not write to any memory of the calling
+// process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        in("r9") arg5 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r as usize
+}
+
+// No-Memory Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call neither reads nor writes any memory of the
+// calling process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0 as u64,
+        in("rsi") arg1 as u64,
+        in("rdx") arg2 as u64,
+        in("r10") arg3 as u64,
+        in("r8") arg4 as u64,
+        in("r9") arg5 as u64,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r as usize
+}
+
+// 64-bit Entries
+//
+// The following entries are identical to the entries above, but take the
+// full 64-bit register values as arguments, rather than zero-extending
+// 32-bit values. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall1()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall1_u64(
+    nr: usize,
+    arg0: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall2_u64(
+    nr: usize,
+    arg0: u64,
+    arg1: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall3_u64(
+    nr: usize,
+    arg0: u64,
+    arg1: u64,
+    arg2: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall4_u64(
+    nr: usize,
+    arg0: u64,
+    arg1: u64,
+    arg2: u64,
+    arg3: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall5_u64(
+    nr: usize,
+    arg0: u64,
+    arg1: u64,
+    arg2: u64,
+    arg3: u64,
+    arg4: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but all arguments are passed as full 64-bit register
+/// values. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+#[inline]
+pub unsafe fn syscall6_u64(
+    nr: usize,
+    arg0: u64,
+    arg1: u64,
+    arg2: u64,
+    arg3: u64,
+    arg4: u64,
+    arg5: u64,
+) -> usize {
+    let mut r: u64;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr as u64 => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        in("r9") arg5,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags)
+    );
+
+    r as usize
+}
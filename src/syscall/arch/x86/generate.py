@@ -1,65 +1,310 @@
 #!/usr/bin/python3
-"""Code Generator for x86
-
-This script is used to generate some of the sources of this crate. It currently
-generates the following data:
-
-   * x86 (and x86_64) Syscall Numbers
-     The system call numbers for x86 are kept in a simple table in the linux
-     kernel sources. This table contains the system-call number, the ABI its
-     included in, the system call name, and possibly the system call entry
-     point.
-     We fetch this table from `git.kernel.org`, parse it, and then generate the
-     system call number definitions for the rust crate.
+"""Code Generator for System Calls
+
+This script is used to generate some of the sources of this crate. It supports
+all architectures of this crate and is linked into the directory of each of
+them. It currently generates the following data:
+
+   * Syscall Numbers
+     The linux kernel sources define the system call numbers of each
+     architecture in one of the following ways:
+
+       * x86 (and x86_64 and x32) keep them in a simple table. This table
+         contains the system-call number, the ABI its included in, the system
+         call name, and possibly the system call entry point.
+         The x32 ABI shares the table with x86_64, but only uses the `common`
+         and `x32` entries. All its system call numbers have
+         `__X32_SYSCALL_BIT` set.
+
+       * arm keeps them in a similar table. Only the EABI entries are used,
+         the obsolete OABI is not supported.
+         Additionally, arm provides a small range of architecture-private
+         system calls, which are defined relative to `__ARM_NR_BASE` in the
+         uapi header of the architecture.
+
+       * aarch64 and riscv64 do not have their own syscall table. Instead,
+         they use the generic syscall numbers defined in
+         `asm-generic/unistd.h`, configured via a set of `__ARCH_WANT_*`
+         macros defined by the architecture headers.
+         Architecture-private system calls are allocated relative to
+         `__NR_arch_specific_syscall`.
+
+     We parse these files and then generate the system call number
+     definitions for the rust crate.
+
+   * Syscall Metadata
+     The same tables are combined with the system call prototypes of the
+     kernel to generate a table of all system calls including the kinds of
+     their arguments. See `../sysmeta.py` for details.
+
+By default, all kernel sources are fetched from `git.kernel.org`.
+Alternatively, `--kernel` can point to a local checkout of the linux kernel
+sources. A partial tree with just the vendored files works as well, as long as
+they are placed at their path relative to the root of the kernel sources.
+Single files can be overridden with `--file`. Use `--list` to show the files
+required for an architecture.
+
+With `--check`, the generated sources are compared to the sources committed
+to this repository, and any difference is reported.
 """
 
 
 import argparse
+import difflib
+import os
+import re
 import sys
 import urllib.request
 
+DIR = os.path.join(os.path.dirname(os.path.realpath(__file__)), "..")
+
+sys.path.insert(0, DIR)
+import sysmeta
+
+
+# Paths of the kernel sources used by the generator, relative to the root of
+# the kernel sources.
+PATH_GENERIC = "include/uapi/asm-generic/unistd.h"
+PATH_PROTOS = "include/linux/syscalls.h"
+
+# Prototypes of the x86 specific entry points, shared by all x86 ABIs.
+PROTOS_X86 = [
+    "long sys_arch_prctl(int option, unsigned long arg2);",
+    "long sys_get_thread_area(struct user_desc __user *u_info);",
+    "long sys_iopl(unsigned int level);",
+    "long sys_modify_ldt(int func, void __user *ptr, unsigned long bytecount);",
+    "long sys_rt_sigreturn(void);",
+    "long sys_set_thread_area(struct user_desc __user *u_info);",
+]
+
+# Configuration of each architecture. `kind` selects the format of the
+# syscall table, `bits` is the width of the argument registers, and `align64`
+# tells whether 64-bit arguments are passed in aligned register pairs.
+#
+# For the table-based architectures, `table` lists the files of the syscall
+# table and `abis` the ABIs to include. For the generic table, `defines`
+# mirrors the macros defined by `arch/<arch>/include/uapi/asm/unistd.h` before
+# it includes `asm-generic/unistd.h`, and `specific` lists the
+# architecture-private system calls it defines relative to
+# `__NR_arch_specific_syscall`.
+#
+# Furthermore, `config` lists the configuration symbols that affect the
+# syscall prototypes, and `protos` the prototypes of architecture-specific
+# entry points. The architecture-private system calls of arm are dispatched
+# by `arm_syscall()`, so their prototypes are listed with an artificial
+# `sys_arm_*` name.
+ARCHS = {
+    "aarch64": {
+        "kind": "generic",
+        "bits": 64,
+        "align64": False,
+        "defines": [
+            "__ARCH_WANT_RENAMEAT",
+            "__ARCH_WANT_NEW_STAT",
+            "__ARCH_WANT_SET_GET_RLIMIT",
+            "__ARCH_WANT_TIME32_SYSCALLS",
+            "__ARCH_WANT_SYS_CLONE3",
+            "__ARCH_WANT_MEMFD_SECRET",
+        ],
+        "specific": [],
+        "config": [
+            "CONFIG_CLONE_BACKWARDS",
+        ],
+        "protos": [
+            "long sys_mmap(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off);",
+            "long sys_rt_sigreturn(void);",
+        ],
+    },
+    "arm": {
+        "kind": "arm",
+        "bits": 32,
+        "align64": True,
+        "table": [
+            "arch/arm/tools/syscall.tbl",
+            "arch/arm/include/uapi/asm/unistd.h",
+        ],
+        "abis": ["common", "eabi"],
+        "config": [
+            "CONFIG_CLONE_BACKWARDS",
+            "CONFIG_HAVE_UID16",
+            "CONFIG_OLD_SIGACTION",
+            "CONFIG_OLD_SIGSUSPEND3",
+        ],
+        "protos": [
+            "long sys_arm_fadvise64_64(int fd, int advice, loff_t offset, loff_t len);",
+            "long sys_mmap2(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long pgoff);",
+            "long sys_rt_sigreturn(void);",
+            "long sys_sigreturn(void);",
+            "long sys_arm_breakpoint(void);",
+            "long sys_arm_cacheflush(unsigned long start, unsigned long end, int flags);",
+            "long sys_arm_usr26(void);",
+            "long sys_arm_usr32(void);",
+            "long sys_arm_set_tls(unsigned long val);",
+            "long sys_arm_get_tls(void);",
+        ],
+    },
+    "riscv64": {
+        "kind": "generic",
+        "bits": 64,
+        "align64": False,
+        "defines": [
+            "__ARCH_WANT_NEW_STAT",
+            "__ARCH_WANT_SET_GET_RLIMIT",
+            "__ARCH_WANT_SYS_CLONE3",
+            "__ARCH_WANT_MEMFD_SECRET",
+        ],
+        "specific": [
+            ["riscv_flush_icache", 15],
+        ],
+        "config": [],
+        "protos": [
+            "long sys_mmap(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off);",
+            "long sys_riscv_flush_icache(uintptr_t start, uintptr_t end, uintptr_t flags);",
+            "long sys_rt_sigreturn(void);",
+        ],
+    },
+    "x32": {
+        "kind": "x86",
+        "bits": 64,
+        "align64": False,
+        "table": ["arch/x86/entry/syscalls/syscall_64.tbl"],
+        "abis": ["common", "x32"],
+        "bit": 0x40000000,
+        "config": [
+            "__ARCH_WANT_SYS_UTIME",
+        ],
+        "protos": PROTOS_X86 + [
+            "long sys_mmap(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off);",
+        ],
+    },
+    "x86": {
+        "kind": "x86",
+        "bits": 32,
+        "align64": False,
+        "table": ["arch/x86/entry/syscalls/syscall_32.tbl"],
+        "abis": ["i386"],
+        "bit": 0,
+        "config": [
+            "CONFIG_CLONE_BACKWARDS",
+            "CONFIG_HAVE_UID16",
+            "CONFIG_OLD_SIGACTION",
+            "CONFIG_OLD_SIGSUSPEND3",
+        ],
+        "protos": PROTOS_X86 + [
+            "long sys_sigreturn(void);",
+            "long sys_vm86(unsigned long cmd, unsigned long arg);",
+            "long sys_vm86old(struct vm86_struct __user *user_vm86);",
+        ],
+    },
+    "x86_64": {
+        "kind": "x86",
+        "bits": 64,
+        "align64": False,
+        "table": ["arch/x86/entry/syscalls/syscall_64.tbl"],
+        "abis": ["common", "64"],
+        "bit": 0,
+        "config": [
+            "__ARCH_WANT_SYS_UTIME",
+        ],
+        "protos": PROTOS_X86 + [
+            "long sys_mmap(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off);",
+        ],
+    },
+}
+
+# Base of the architecture-private system calls of arm.
+ARM_NR_BASE = 0x0f0000
+
+# Cache of all fetched files, indexed by their path.
+CACHE = {}
+
+
+def fetch(*, args, path):
+    """Fetch File
+
+    Fetch a file of the kernel sources. `path` is relative to the root of
+    the kernel sources. The file is taken from the overrides given via
+    `--file`, the local kernel sources given via `--kernel`, or fetched from
+    the official git repository, in that order.
+    """
 
-def systbl_fetch(*, args):
-    """Fetch Syscall Table
+    if path in CACHE:
+        return CACHE[path]
+
+    if path in args.file:
+        print("Reading from:", args.file[path], file=sys.stderr)
+        with open(args.file[path], "rb") as f:
+            data = f.read()
+    elif args.kernel is not None:
+        local = os.path.join(args.kernel, path)
+        print("Reading from:", local, file=sys.stderr)
+        with open(local, "rb") as f:
+            data = f.read()
+    else:
+        host = "git.kernel.org"
+        repo = "pub/scm/linux/kernel/git/torvalds/linux.git"
+        branch = "master"
+
+        url = "".join([
+            "https://",
+            host,
+            "/", repo,
+            "/", "plain",
+            "/", path,
+            "?h=", branch,
+        ])
+
+        print("Fetching from:", url, file=sys.stderr)
+
+        with urllib.request.urlopen(url) as req:
+            data = req.read()
 
-    Fetch the x86[_64] syscall-table from the official git repository.
+    CACHE[path] = data
+    return data
+
+
+def sources(*, arch):
+    """List Sources
+
+    Return the paths of all kernel sources required to generate the sources
+    for `arch`.
     """
 
-    host = "git.kernel.org"
-    repo = "pub/scm/linux/kernel/git/torvalds/linux.git"
-    path = "arch/x86/entry/syscalls"
-    filename = "syscall_32.tbl" if args.arch == "x86" else "syscall_64.tbl"
-    branch = "master"
+    config = ARCHS[arch]
+    if config["kind"] == "generic":
+        res = [PATH_GENERIC]
+    else:
+        res = list(config["table"])
 
-    url = "".join([
-        "https://",
-        host,
-        "/", repo,
-        "/", "plain",
-        "/", path,
-        "/", filename,
-        "?h=", branch,
-    ])
+    return res + [PATH_PROTOS]
 
-    print("Fetching from:", url, file=sys.stderr)
 
-    with urllib.request.urlopen(url) as req:
-        systbl = req.read()
+def systbl_fetch(*, args, arch):
+    """Fetch Syscall Table
 
-    return systbl
+    Fetch all files of the syscall table of `arch`.
+    """
 
+    config = ARCHS[arch]
+    if config["kind"] == "generic":
+        return [fetch(args=args, path=PATH_GENERIC)]
 
-def systbl_parse(*, args, data):
-    """Parse Syscall Table
+    return [fetch(args=args, path=v) for v in config["table"]]
+
+
+def systbl_parse_tbl(*, arch, data):
+    """Parse Table-Based Syscall Table
 
-    The x86 syscall table contains entries in the style of:
+    The x86 and arm syscall tables contain entries in the style of:
         0   common  read    sys_read
     The file can contain comments, and entries might lack the last
-    column.
+    column. The arm table can have an additional column for the OABI entry
+    point.
     """
 
     res = []
-    abis = ["i386"] if args.arch == "x86" else ["common", "64"]
+    config = ARCHS[arch]
+    bit = config.get("bit", 0)
 
     lines = data.decode().splitlines()
     for line in lines:
@@ -70,31 +315,257 @@ def systbl_parse(*, args, data):
         fields = line.split()
         assert len(fields) >= 3
 
-        if fields[1] in abis:
-            res.append(fields)
+        if fields[1] in config["abis"]:
+            entry = fields[3] if len(fields) > 3 else None
+            res.append([int(fields[0]) | bit, fields[2], entry])
+
+    return res
+
+
+def systbl_parse_arm(*, arch, data):
+    """Parse Private Syscalls of arm
+
+    The architecture-private system calls are defined in the uapi header in
+    the style of:
+        #define __ARM_NR_cacheflush (__ARM_NR_BASE+2)
+    """
+
+    res = []
+
+    lines = data.decode().splitlines()
+    for line in lines:
+        m = re.match(
+            r"#define\s+__ARM_NR_(\w+)\s+\(__ARM_NR_BASE\s*\+\s*(\d+)\)",
+            line,
+        )
+        if m:
+            name = "arm_" + m.group(1)
+            res.append([ARM_NR_BASE + int(m.group(2)), name, "sys_" + name])
+
+    return res
+
+
+def systbl_eval(*, expr, defines, bits):
+    """Evaluate Preprocessor Condition
+
+    Evaluate the condition of an `#if` or `#elif` directive. Only the small
+    subset of the C preprocessor used by the generic syscall header is
+    supported: `defined()`, `__BITS_PER_LONG`, integer comparisons and the
+    logical operators.
+    """
+
+    expr = re.sub(
+        r"defined\s*\(\s*(\w+)\s*\)",
+        lambda m: "1" if m.group(1) in defines else "0",
+        expr,
+    )
+    expr = expr.replace("__BITS_PER_LONG", str(bits))
+    expr = expr.replace("&&", " and ").replace("||", " or ")
+    expr = re.sub(r"!(?!=)", " not ", expr)
+
+    return bool(eval(expr, {}, {}))
+
+
+def systbl_parse_generic(*, arch, data):
+    """Parse Generic Syscall Table
+
+    The generic syscall header contains entries in the style of:
+        #define __NR_io_setup 0
+        __SC_COMP(__NR_io_setup, sys_io_setup, compat_sys_io_setup)
+    Entries are guarded by preprocessor conditionals, and some entries are
+    defined as `__NR3264_*` and only later aliased to their final name,
+    depending on the word-size of the architecture. The entry point of each
+    system call is taken from the `__SYSCALL` or `__SC_*` usage, picking the
+    native entry point for the word-size of the architecture.
+    """
+
+    config = ARCHS[arch]
+    defines = set(config["defines"])
+    bits = config["bits"]
+
+    numbers = {}
+    aliases = {}
+    used = {}
+    stack = []
+
+    data = data.decode().replace("\\\n", " ")
+    for line in data.splitlines():
+        line = line.replace("\t", " ").strip()
+        active = all(v[0] for v in stack)
+
+        m = re.match(r"#\s*(\w+)\s*(.*)$", line)
+        if m:
+            directive, rest = m.group(1), m.group(2).split("/*")[0].strip()
+            if directive == "if":
+                v = active and systbl_eval(expr=rest, defines=defines, bits=bits)
+                stack.append([v, v])
+            elif directive in ["ifdef", "ifndef"]:
+                v = (rest in defines) == (directive == "ifdef")
+                v = active and v
+                stack.append([v, v])
+            elif directive == "elif":
+                outer = all(v[0] for v in stack[:-1])
+                v = outer and not stack[-1][1] and \
+                    systbl_eval(expr=rest, defines=defines, bits=bits)
+                stack[-1] = [v, stack[-1][1] or v]
+            elif directive == "else":
+                outer = all(v[0] for v in stack[:-1])
+                v = outer and not stack[-1][1]
+                stack[-1] = [v, True]
+            elif directive == "endif":
+                stack.pop()
+            elif directive == "define" and active:
+                fields = rest.split(None, 1)
+                defines.add(fields[0])
+                if len(fields) == 2 and fields[0].startswith("__NR"):
+                    if re.fullmatch(r"\d+", fields[1]):
+                        numbers[fields[0]] = int(fields[1])
+                    elif re.fullmatch(r"__NR\w+", fields[1]):
+                        aliases[fields[0]] = fields[1]
+            elif directive == "undef" and active:
+                defines.discard(rest)
+            continue
+
+        if not active:
+            continue
+
+        m = re.match(r"__(SYSCALL|SC_\w+)\s*\(\s*(__NR\w+)\s*,([^)]*)\)", line)
+        if m:
+            entries = [v.strip() for v in m.group(3).split(",")]
+            if m.group(1) in ["SC_3264", "SC_COMP_3264"] and bits == 64:
+                used[m.group(2)] = entries[1]
+            else:
+                used[m.group(2)] = entries[0]
 
-    return sorted(res, key=lambda v: int(v[0]))
+    res = []
+    for name, nr in numbers.items():
+        if name.startswith("__NR_") and name in used:
+            res.append([nr, name[len("__NR_"):], used[name]])
+    for name, target in aliases.items():
+        if target in used and target in numbers:
+            res.append([numbers[target], name[len("__NR_"):], used[target]])
+    for name, offset in config["specific"]:
+        nr = numbers["__NR_arch_specific_syscall"] + offset
+        res.append([nr, name, "sys_" + name])
+
+    return res
+
+
+def systbl_parse(*, arch, data):
+    """Parse Syscall Table
+
+    Parse the syscall table of `arch` and return a list of
+    `[nr, name, entry]` entries, ordered by number. `entry` is `None` if the
+    table does not list an entry point.
+    """
+
+    kind = ARCHS[arch]["kind"]
+    if kind == "generic":
+        res = systbl_parse_generic(arch=arch, data=data[0])
+    elif kind == "arm":
+        res = systbl_parse_tbl(arch=arch, data=data[0])
+        res += systbl_parse_arm(arch=arch, data=data[1])
+    else:
+        res = systbl_parse_tbl(arch=arch, data=data[0])
 
+    return sorted(res, key=lambda v: v[0])
 
-def systbl_emit(*, systbl):
+
+def systbl_emit(*, arch, systbl):
     """Emit Rustified Syscall Table
 
     Emit rust code as expected by the crate, which contains the
-    definitions of the system call numbers.
+    definitions of the system call numbers. The architecture-private system
+    calls of arm are emitted separately, following the regular ones.
     """
 
-    print("// This code is generated.")
-    for entry in systbl:
-        print(f"pub const {entry[2].upper()}: usize = {entry[0]};")
+    res = ["// This code is generated."]
+    for nr, name, _ in systbl:
+        if arch != "arm" or nr < ARM_NR_BASE:
+            res.append(f"pub const {name.upper()}: usize = {nr};")
+
+    if arch == "arm":
+        res.append("")
+        res.append("// Architecture-private system calls.")
+        for nr, name, _ in systbl:
+            if nr >= ARM_NR_BASE:
+                res.append(f"pub const {name.upper()}: usize = {nr:#x};")
+
+    return "".join(v + "\n" for v in res)
 
 
-def systbl(args):
-    print("Fetch System Table...", file=sys.stderr)
-    data = systbl_fetch(args=args)
-    print("Parse System Table...", file=sys.stderr)
-    systbl = systbl_parse(args=args, data=data)
-    print("Emit System Table...", file=sys.stderr)
-    systbl_emit(systbl=systbl)
+def generate(*, args, arch, what):
+    """Generate Source
+
+    Generate the source `what` (either `meta` or `systbl`) for `arch` and
+    return it as string.
+    """
+
+    config = ARCHS[arch]
+
+    print(f"Fetch System Table ({arch})...", file=sys.stderr)
+    data = systbl_fetch(args=args, arch=arch)
+    print(f"Parse System Table ({arch})...", file=sys.stderr)
+    systbl = systbl_parse(arch=arch, data=data)
+
+    if what == "systbl":
+        print(f"Emit System Table ({arch})...", file=sys.stderr)
+        return systbl_emit(arch=arch, systbl=systbl)
+
+    print(f"Fetch Prototypes ({arch})...", file=sys.stderr)
+    data = fetch(args=args, path=PATH_PROTOS)
+    print(f"Parse Prototypes ({arch})...", file=sys.stderr)
+    protos = sysmeta.protos_parse(
+        data=data,
+        config=config["config"],
+        extra=config["protos"],
+    )
+    print(f"Emit Metadata ({arch})...", file=sys.stderr)
+    return sysmeta.meta_emit(
+        systbl=systbl,
+        protos=protos,
+        bits=config["bits"],
+        align64=config["align64"],
+    )
+
+
+def check(*, args, archs):
+    """Check Committed Sources
+
+    Generate all sources for the architectures `archs` and compare them to
+    the sources committed to this repository. Differences are printed as
+    unified diff. Return `True` if all sources are up-to-date.
+    """
+
+    res = True
+
+    for arch in archs:
+        for what, filename in [["systbl", "nr.rs"], ["meta", "meta.rs"]]:
+            path = os.path.join(DIR, arch, filename)
+            generated = generate(args=args, arch=arch, what=what)
+
+            with open(path, "r") as f:
+                committed = f.read()
+
+            diff = list(difflib.unified_diff(
+                committed.splitlines(keepends=True),
+                generated.splitlines(keepends=True),
+                fromfile=f"{arch}/{filename}",
+                tofile=f"{arch}/{filename} (generated)",
+            ))
+            if diff:
+                print(f"Outdated: {arch}/{filename}", file=sys.stderr)
+                sys.stdout.writelines(diff)
+                res = False
+
+    return res
+
+
+def parse_file(value):
+    path, sep, local = value.partition("=")
+    if not sep or not path or not local:
+        raise argparse.ArgumentTypeError("expected PATH=LOCAL")
+    return [path, local]
 
 
 def parse_args(argv):
@@ -102,34 +573,80 @@ def parse_args(argv):
         add_help=True,
         allow_abbrev=False,
         argument_default=None,
-        description="Code Generator for x86",
+        description="Code Generator for System Calls",
         prog="generate.py",
     )
 
     parser.add_argument(
         "--arch",
-        choices=["x86", "x86_64"],
-        help="Which architecture to generate for",
-        required=True,
+        action="append",
+        choices=list(ARCHS.keys()),
+        help="Which architecture to generate for (default: all when checking)",
+        type=str,
+    )
+
+    parser.add_argument(
+        "--kernel",
+        help="Path to local kernel sources to use instead of fetching them",
+        metavar="DIR",
         type=str,
     )
 
     parser.add_argument(
+        "--file",
+        action="append",
+        default=[],
+        help="Use LOCAL for the kernel source file PATH",
+        metavar="PATH=LOCAL",
+        type=parse_file,
+    )
+
+    mode = parser.add_mutually_exclusive_group(required=True)
+
+    mode.add_argument(
         "--generate",
-        choices=["systbl"],
+        choices=["meta", "systbl"],
         help="What to generate",
-        required=True,
         type=str,
     )
 
-    return parser.parse_args(argv[1:])
+    mode.add_argument(
+        "--check",
+        action="store_true",
+        help="Compare the committed sources to the generated sources",
+    )
+
+    mode.add_argument(
+        "--list",
+        action="store_true",
+        help="List the kernel source files required",
+    )
+
+    args = parser.parse_args(argv[1:])
+    args.file = dict(args.file)
+
+    if args.generate is not None and \
+            (args.arch is None or len(args.arch) != 1):
+        parser.error("--generate requires exactly one --arch")
+
+    return args
 
 
 def run(argv):
     args = parse_args(argv)
-
-    if args.generate == "systbl":
-        systbl(args)
+    archs = args.arch or list(ARCHS.keys())
+
+    if args.generate is not None:
+        print(generate(args=args, arch=archs[0], what=args.generate), end="")
+    elif args.check:
+        if not check(args=args, archs=archs):
+            sys.exit(1)
+    elif args.list:
+        paths = []
+        for arch in archs:
+            paths += [v for v in sources(arch=arch) if v not in paths]
+        for v in paths:
+            print(v)
     else:
         raise RuntimeError("Nothing to do")
 
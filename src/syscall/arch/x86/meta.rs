@@ -0,0 +1,445 @@
+// This code is generated.
+use crate::syscall::meta::{Arg::*, Syscall};
+
+pub const TABLE: &[Syscall] = &[
+    Syscall { name: "restart_syscall", nr: 0, args: Some(&[]) },
+    Syscall { name: "exit", nr: 1, args: Some(&[Value]) },
+    Syscall { name: "fork", nr: 2, args: Some(&[]) },
+    Syscall { name: "read", nr: 3, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "write", nr: 4, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "open", nr: 5, args: Some(&[Ptr, Flags, Value]) },
+    Syscall { name: "close", nr: 6, args: Some(&[Fd]) },
+    Syscall { name: "waitpid", nr: 7, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "creat", nr: 8, args: Some(&[Ptr, Value]) },
+    Syscall { name: "link", nr: 9, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "unlink", nr: 10, args: Some(&[Ptr]) },
+    Syscall { name: "execve", nr: 11, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "chdir", nr: 12, args: Some(&[Ptr]) },
+    Syscall { name: "time", nr: 13, args: Some(&[Ptr]) },
+    Syscall { name: "mknod", nr: 14, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "chmod", nr: 15, args: Some(&[Ptr, Value]) },
+    Syscall { name: "lchown", nr: 16, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "break", nr: 17, args: None },
+    Syscall { name: "oldstat", nr: 18, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lseek", nr: 19, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "getpid", nr: 20, args: Some(&[]) },
+    Syscall { name: "mount", nr: 21, args: Some(&[Ptr, Ptr, Ptr, Flags, Ptr]) },
+    Syscall { name: "umount", nr: 22, args: Some(&[Ptr]) },
+    Syscall { name: "setuid", nr: 23, args: Some(&[Value]) },
+    Syscall { name: "getuid", nr: 24, args: Some(&[]) },
+    Syscall { name: "stime", nr: 25, args: Some(&[Ptr]) },
+    Syscall { name: "ptrace", nr: 26, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "alarm", nr: 27, args: Some(&[Value]) },
+    Syscall { name: "oldfstat", nr: 28, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "pause", nr: 29, args: Some(&[]) },
+    Syscall { name: "utime", nr: 30, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "stty", nr: 31, args: None },
+    Syscall { name: "gtty", nr: 32, args: None },
+    Syscall { name: "access", nr: 33, args: Some(&[Ptr, Value]) },
+    Syscall { name: "nice", nr: 34, args: Some(&[Value]) },
+    Syscall { name: "ftime", nr: 35, args: None },
+    Syscall { name: "sync", nr: 36, args: Some(&[]) },
+    Syscall { name: "kill", nr: 37, args: Some(&[Value, Value]) },
+    Syscall { name: "rename", nr: 38, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mkdir", nr: 39, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rmdir", nr: 40, args: Some(&[Ptr]) },
+    Syscall { name: "dup", nr: 41, args: Some(&[Fd]) },
+    Syscall { name: "pipe", nr: 42, args: Some(&[Ptr]) },
+    Syscall { name: "times", nr: 43, args: Some(&[Ptr]) },
+    Syscall { name: "prof", nr: 44, args: None },
+    Syscall { name: "brk", nr: 45, args: Some(&[Value]) },
+    Syscall { name: "setgid", nr: 46, args: Some(&[Value]) },
+    Syscall { name: "getgid", nr: 47, args: Some(&[]) },
+    Syscall { name: "signal", nr: 48, args: Some(&[Value, Ptr]) },
+    Syscall { name: "geteuid", nr: 49, args: Some(&[]) },
+    Syscall { name: "getegid", nr: 50, args: Some(&[]) },
+    Syscall { name: "acct", nr: 51, args: Some(&[Ptr]) },
+    Syscall { name: "umount2", nr: 52, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "lock", nr: 53, args: None },
+    Syscall { name: "ioctl", nr: 54, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "fcntl", nr: 55, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "mpx", nr: 56, args: None },
+    Syscall { name: "setpgid", nr: 57, args: Some(&[Value, Value]) },
+    Syscall { name: "ulimit", nr: 58, args: None },
+    Syscall { name: "oldolduname", nr: 59, args: Some(&[Ptr]) },
+    Syscall { name: "umask", nr: 60, args: Some(&[Value]) },
+    Syscall { name: "chroot", nr: 61, args: Some(&[Ptr]) },
+    Syscall { name: "ustat", nr: 62, args: Some(&[Value, Ptr]) },
+    Syscall { name: "dup2", nr: 63, args: Some(&[Fd, Fd]) },
+    Syscall { name: "getppid", nr: 64, args: Some(&[]) },
+    Syscall { name: "getpgrp", nr: 65, args: Some(&[]) },
+    Syscall { name: "setsid", nr: 66, args: Some(&[]) },
+    Syscall { name: "sigaction", nr: 67, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "sgetmask", nr: 68, args: Some(&[]) },
+    Syscall { name: "ssetmask", nr: 69, args: Some(&[Value]) },
+    Syscall { name: "setreuid", nr: 70, args: Some(&[Value, Value]) },
+    Syscall { name: "setregid", nr: 71, args: Some(&[Value, Value]) },
+    Syscall { name: "sigsuspend", nr: 72, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "sigpending", nr: 73, args: Some(&[Ptr]) },
+    Syscall { name: "sethostname", nr: 74, args: Some(&[Ptr, Value]) },
+    Syscall { name: "setrlimit", nr: 75, args: Some(&[Value, Ptr]) },
+    Syscall { name: "getrlimit", nr: 76, args: Some(&[Value, Ptr]) },
+    Syscall { name: "getrusage", nr: 77, args: Some(&[Value, Ptr]) },
+    Syscall { name: "gettimeofday", nr: 78, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "settimeofday", nr: 79, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getgroups", nr: 80, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setgroups", nr: 81, args: Some(&[Value, Ptr]) },
+    Syscall { name: "select", nr: 82, args: Some(&[Ptr]) },
+    Syscall { name: "symlink", nr: 83, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "oldlstat", nr: 84, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "readlink", nr: 85, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "uselib", nr: 86, args: Some(&[Ptr]) },
+    Syscall { name: "swapon", nr: 87, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "reboot", nr: 88, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "readdir", nr: 89, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "mmap", nr: 90, args: Some(&[Ptr]) },
+    Syscall { name: "munmap", nr: 91, args: Some(&[Value, Value]) },
+    Syscall { name: "truncate", nr: 92, args: Some(&[Ptr, Value]) },
+    Syscall { name: "ftruncate", nr: 93, args: Some(&[Fd, Value]) },
+    Syscall { name: "fchmod", nr: 94, args: Some(&[Fd, Value]) },
+    Syscall { name: "fchown", nr: 95, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "getpriority", nr: 96, args: Some(&[Value, Value]) },
+    Syscall { name: "setpriority", nr: 97, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "profil", nr: 98, args: None },
+    Syscall { name: "statfs", nr: 99, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstatfs", nr: 100, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "ioperm", nr: 101, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "socketcall", nr: 102, args: Some(&[Value, Ptr]) },
+    Syscall { name: "syslog", nr: 103, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "setitimer", nr: 104, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "getitimer", nr: 105, args: Some(&[Value, Ptr]) },
+    Syscall { name: "stat", nr: 106, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lstat", nr: 107, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstat", nr: 108, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "olduname", nr: 109, args: Some(&[Ptr]) },
+    Syscall { name: "iopl", nr: 110, args: Some(&[Value]) },
+    Syscall { name: "vhangup", nr: 111, args: Some(&[]) },
+    Syscall { name: "idle", nr: 112, args: None },
+    Syscall { name: "vm86old", nr: 113, args: Some(&[Ptr]) },
+    Syscall { name: "wait4", nr: 114, args: Some(&[Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "swapoff", nr: 115, args: Some(&[Ptr]) },
+    Syscall { name: "sysinfo", nr: 116, args: Some(&[Ptr]) },
+    Syscall { name: "ipc", nr: 117, args: Some(&[Value, Value, Value, Value, Ptr, Value]) },
+    Syscall { name: "fsync", nr: 118, args: Some(&[Fd]) },
+    Syscall { name: "sigreturn", nr: 119, args: Some(&[]) },
+    Syscall { name: "clone", nr: 120, args: Some(&[Value, Value, Ptr, Value, Ptr]) },
+    Syscall { name: "setdomainname", nr: 121, args: Some(&[Ptr, Value]) },
+    Syscall { name: "uname", nr: 122, args: Some(&[Ptr]) },
+    Syscall { name: "modify_ldt", nr: 123, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "adjtimex", nr: 124, args: Some(&[Ptr]) },
+    Syscall { name: "mprotect", nr: 125, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "sigprocmask", nr: 126, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "create_module", nr: 127, args: None },
+    Syscall { name: "init_module", nr: 128, args: Some(&[Ptr, Value, Ptr]) },
+    Syscall { name: "delete_module", nr: 129, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "get_kernel_syms", nr: 130, args: None },
+    Syscall { name: "quotactl", nr: 131, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "getpgid", nr: 132, args: Some(&[Value]) },
+    Syscall { name: "fchdir", nr: 133, args: Some(&[Fd]) },
+    Syscall { name: "bdflush", nr: 134, args: None },
+    Syscall { name: "sysfs", nr: 135, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "personality", nr: 136, args: Some(&[Value]) },
+    Syscall { name: "afs_syscall", nr: 137, args: None },
+    Syscall { name: "setfsuid", nr: 138, args: Some(&[Value]) },
+    Syscall { name: "setfsgid", nr: 139, args: Some(&[Value]) },
+    Syscall { name: "_llseek", nr: 140, args: Some(&[Fd, Value, Value, Ptr, Value]) },
+    Syscall { name: "getdents", nr: 141, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "_newselect", nr: 142, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "flock", nr: 143, args: Some(&[Fd, Value]) },
+    Syscall { name: "msync", nr: 144, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "readv", nr: 145, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "writev", nr: 146, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "getsid", nr: 147, args: Some(&[Value]) },
+    Syscall { name: "fdatasync", nr: 148, args: Some(&[Fd]) },
+    Syscall { name: "_sysctl", nr: 149, args: None },
+    Syscall { name: "mlock", nr: 150, args: Some(&[Value, Value]) },
+    Syscall { name: "munlock", nr: 151, args: Some(&[Value, Value]) },
+    Syscall { name: "mlockall", nr: 152, args: Some(&[Flags]) },
+    Syscall { name: "munlockall", nr: 153, args: Some(&[]) },
+    Syscall { name: "sched_setparam", nr: 154, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_getparam", nr: 155, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_setscheduler", nr: 156, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getscheduler", nr: 157, args: Some(&[Value]) },
+    Syscall { name: "sched_yield", nr: 158, args: Some(&[]) },
+    Syscall { name: "sched_get_priority_max", nr: 159, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_min", nr: 160, args: Some(&[Value]) },
+    Syscall { name: "sched_rr_get_interval", nr: 161, args: Some(&[Value, Ptr]) },
+    Syscall { name: "nanosleep", nr: 162, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mremap", nr: 163, args: Some(&[Value, Value, Value, Flags, Value]) },
+    Syscall { name: "setresuid", nr: 164, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresuid", nr: 165, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "vm86", nr: 166, args: Some(&[Value, Value]) },
+    Syscall { name: "query_module", nr: 167, args: None },
+    Syscall { name: "poll", nr: 168, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "nfsservctl", nr: 169, args: None },
+    Syscall { name: "setresgid", nr: 170, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresgid", nr: 171, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "prctl", nr: 172, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "rt_sigreturn", nr: 173, args: Some(&[]) },
+    Syscall { name: "rt_sigaction", nr: 174, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigprocmask", nr: 175, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigpending", nr: 176, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rt_sigtimedwait", nr: 177, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigqueueinfo", nr: 178, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "rt_sigsuspend", nr: 179, args: Some(&[Ptr, Value]) },
+    Syscall { name: "pread64", nr: 180, args: Some(&[Fd, Ptr, Value, Lo64, Hi64]) },
+    Syscall { name: "pwrite64", nr: 181, args: Some(&[Fd, Ptr, Value, Lo64, Hi64]) },
+    Syscall { name: "chown", nr: 182, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "getcwd", nr: 183, args: Some(&[Ptr, Value]) },
+    Syscall { name: "capget", nr: 184, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "capset", nr: 185, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "sigaltstack", nr: 186, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "sendfile", nr: 187, args: Some(&[Fd, Fd, Ptr, Value]) },
+    Syscall { name: "getpmsg", nr: 188, args: None },
+    Syscall { name: "putpmsg", nr: 189, args: None },
+    Syscall { name: "vfork", nr: 190, args: Some(&[]) },
+    Syscall { name: "ugetrlimit", nr: 191, args: Some(&[Value, Ptr]) },
+    Syscall { name: "mmap2", nr: 192, args: Some(&[Value, Value, Value, Flags, Fd, Value]) },
+    Syscall { name: "truncate64", nr: 193, args: Some(&[Ptr, Lo64, Hi64]) },
+    Syscall { name: "ftruncate64", nr: 194, args: Some(&[Fd, Lo64, Hi64]) },
+    Syscall { name: "stat64", nr: 195, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lstat64", nr: 196, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstat64", nr: 197, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "lchown32", nr: 198, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "getuid32", nr: 199, args: Some(&[]) },
+    Syscall { name: "getgid32", nr: 200, args: Some(&[]) },
+    Syscall { name: "geteuid32", nr: 201, args: Some(&[]) },
+    Syscall { name: "getegid32", nr: 202, args: Some(&[]) },
+    Syscall { name: "setreuid32", nr: 203, args: Some(&[Value, Value]) },
+    Syscall { name: "setregid32", nr: 204, args: Some(&[Value, Value]) },
+    Syscall { name: "getgroups32", nr: 205, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setgroups32", nr: 206, args: Some(&[Value, Ptr]) },
+    Syscall { name: "fchown32", nr: 207, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "setresuid32", nr: 208, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresuid32", nr: 209, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "setresgid32", nr: 210, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresgid32", nr: 211, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "chown32", nr: 212, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "setuid32", nr: 213, args: Some(&[Value]) },
+    Syscall { name: "setgid32", nr: 214, args: Some(&[Value]) },
+    Syscall { name: "setfsuid32", nr: 215, args: Some(&[Value]) },
+    Syscall { name: "setfsgid32", nr: 216, args: Some(&[Value]) },
+    Syscall { name: "pivot_root", nr: 217, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mincore", nr: 218, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "madvise", nr: 219, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getdents64", nr: 220, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "fcntl64", nr: 221, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "gettid", nr: 224, args: Some(&[]) },
+    Syscall { name: "readahead", nr: 225, args: Some(&[Fd, Lo64, Hi64, Value]) },
+    Syscall { name: "setxattr", nr: 226, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "lsetxattr", nr: 227, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "fsetxattr", nr: 228, args: Some(&[Fd, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "getxattr", nr: 229, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "lgetxattr", nr: 230, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "fgetxattr", nr: 231, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "listxattr", nr: 232, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "llistxattr", nr: 233, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "flistxattr", nr: 234, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "removexattr", nr: 235, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lremovexattr", nr: 236, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fremovexattr", nr: 237, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "tkill", nr: 238, args: Some(&[Value, Value]) },
+    Syscall { name: "sendfile64", nr: 239, args: Some(&[Fd, Fd, Ptr, Value]) },
+    Syscall { name: "futex", nr: 240, args: Some(&[Ptr, Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "sched_setaffinity", nr: 241, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getaffinity", nr: 242, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "set_thread_area", nr: 243, args: Some(&[Ptr]) },
+    Syscall { name: "get_thread_area", nr: 244, args: Some(&[Ptr]) },
+    Syscall { name: "io_setup", nr: 245, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_destroy", nr: 246, args: Some(&[Value]) },
+    Syscall { name: "io_getevents", nr: 247, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "io_submit", nr: 248, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "io_cancel", nr: 249, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "fadvise64", nr: 250, args: Some(&[Fd, Lo64, Hi64, Value, Value]) },
+    Syscall { name: "exit_group", nr: 252, args: Some(&[Value]) },
+    Syscall { name: "lookup_dcookie", nr: 253, args: Some(&[Lo64, Hi64, Ptr, Value]) },
+    Syscall { name: "epoll_create", nr: 254, args: Some(&[Value]) },
+    Syscall { name: "epoll_ctl", nr: 255, args: Some(&[Fd, Value, Fd, Ptr]) },
+    Syscall { name: "epoll_wait", nr: 256, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "remap_file_pages", nr: 257, args: Some(&[Value, Value, Value, Value, Flags]) },
+    Syscall { name: "set_tid_address", nr: 258, args: Some(&[Ptr]) },
+    Syscall { name: "timer_create", nr: 259, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "timer_settime", nr: 260, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timer_gettime", nr: 261, args: Some(&[Value, Ptr]) },
+    Syscall { name: "timer_getoverrun", nr: 262, args: Some(&[Value]) },
+    Syscall { name: "timer_delete", nr: 263, args: Some(&[Value]) },
+    Syscall { name: "clock_settime", nr: 264, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_gettime", nr: 265, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_getres", nr: 266, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_nanosleep", nr: 267, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "statfs64", nr: 268, args: Some(&[Ptr, Value, Ptr]) },
+    Syscall { name: "fstatfs64", nr: 269, args: Some(&[Fd, Value, Ptr]) },
+    Syscall { name: "tgkill", nr: 270, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "utimes", nr: 271, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fadvise64_64", nr: 272, args: Some(&[Fd, Lo64, Hi64, Lo64, Hi64, Value]) },
+    Syscall { name: "vserver", nr: 273, args: None },
+    Syscall { name: "mbind", nr: 274, args: Some(&[Value, Value, Value, Ptr, Value, Flags]) },
+    Syscall { name: "get_mempolicy", nr: 275, args: Some(&[Ptr, Ptr, Value, Value, Flags]) },
+    Syscall { name: "set_mempolicy", nr: 276, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "mq_open", nr: 277, args: Some(&[Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "mq_unlink", nr: 278, args: Some(&[Ptr]) },
+    Syscall { name: "mq_timedsend", nr: 279, args: Some(&[Fd, Ptr, Value, Value, Ptr]) },
+    Syscall { name: "mq_timedreceive", nr: 280, args: Some(&[Fd, Ptr, Value, Ptr, Ptr]) },
+    Syscall { name: "mq_notify", nr: 281, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "mq_getsetattr", nr: 282, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "kexec_load", nr: 283, args: Some(&[Value, Value, Ptr, Flags]) },
+    Syscall { name: "waitid", nr: 284, args: Some(&[Value, Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "add_key", nr: 286, args: Some(&[Ptr, Ptr, Ptr, Value, Value]) },
+    Syscall { name: "request_key", nr: 287, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "keyctl", nr: 288, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "ioprio_set", nr: 289, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "ioprio_get", nr: 290, args: Some(&[Value, Value]) },
+    Syscall { name: "inotify_init", nr: 291, args: Some(&[]) },
+    Syscall { name: "inotify_add_watch", nr: 292, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "inotify_rm_watch", nr: 293, args: Some(&[Fd, Value]) },
+    Syscall { name: "migrate_pages", nr: 294, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "openat", nr: 295, args: Some(&[Fd, Ptr, Flags, Value]) },
+    Syscall { name: "mkdirat", nr: 296, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "mknodat", nr: 297, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "fchownat", nr: 298, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "futimesat", nr: 299, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "fstatat64", nr: 300, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "unlinkat", nr: 301, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "renameat", nr: 302, args: Some(&[Fd, Ptr, Fd, Ptr]) },
+    Syscall { name: "linkat", nr: 303, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "symlinkat", nr: 304, args: Some(&[Ptr, Fd, Ptr]) },
+    Syscall { name: "readlinkat", nr: 305, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "fchmodat", nr: 306, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "faccessat", nr: 307, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "pselect6", nr: 308, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "ppoll", nr: 309, args: Some(&[Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "unshare", nr: 310, args: Some(&[Flags]) },
+    Syscall { name: "set_robust_list", nr: 311, args: Some(&[Ptr, Value]) },
+    Syscall { name: "get_robust_list", nr: 312, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "splice", nr: 313, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "sync_file_range", nr: 314, args: Some(&[Fd, Lo64, Hi64, Lo64, Hi64, Flags]) },
+    Syscall { name: "tee", nr: 315, args: Some(&[Fd, Fd, Value, Flags]) },
+    Syscall { name: "vmsplice", nr: 316, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "move_pages", nr: 317, args: Some(&[Value, Value, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "getcpu", nr: 318, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "epoll_pwait", nr: 319, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "utimensat", nr: 320, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "signalfd", nr: 321, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "timerfd_create", nr: 322, args: Some(&[Value, Flags]) },
+    Syscall { name: "eventfd", nr: 323, args: Some(&[Value]) },
+    Syscall { name: "fallocate", nr: 324, args: Some(&[Fd, Value, Lo64, Hi64, Lo64, Hi64]) },
+    Syscall { name: "timerfd_settime", nr: 325, args: Some(&[Fd, Flags, Ptr, Ptr]) },
+    Syscall { name: "timerfd_gettime", nr: 326, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "signalfd4", nr: 327, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "eventfd2", nr: 328, args: Some(&[Value, Flags]) },
+    Syscall { name: "epoll_create1", nr: 329, args: Some(&[Flags]) },
+    Syscall { name: "dup3", nr: 330, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "pipe2", nr: 331, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "inotify_init1", nr: 332, args: Some(&[Flags]) },
+    Syscall { name: "preadv", nr: 333, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "pwritev", nr: 334, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "rt_tgsigqueueinfo", nr: 335, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "perf_event_open", nr: 336, args: Some(&[Ptr, Value, Value, Fd, Flags]) },
+    Syscall { name: "recvmmsg", nr: 337, args: Some(&[Fd, Ptr, Value, Flags, Ptr]) },
+    Syscall { name: "fanotify_init", nr: 338, args: Some(&[Flags, Flags]) },
+    Syscall { name: "fanotify_mark", nr: 339, args: Some(&[Fd, Flags, Lo64, Hi64, Fd, Ptr]) },
+    Syscall { name: "prlimit64", nr: 340, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "name_to_handle_at", nr: 341, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "open_by_handle_at", nr: 342, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "clock_adjtime", nr: 343, args: Some(&[Value, Ptr]) },
+    Syscall { name: "syncfs", nr: 344, args: Some(&[Fd]) },
+    Syscall { name: "sendmmsg", nr: 345, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "setns", nr: 346, args: Some(&[Fd, Value]) },
+    Syscall { name: "process_vm_readv", nr: 347, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_writev", nr: 348, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "kcmp", nr: 349, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "finit_module", nr: 350, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "sched_setattr", nr: 351, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "sched_getattr", nr: 352, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "renameat2", nr: 353, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "seccomp", nr: 354, args: Some(&[Value, Flags, Ptr]) },
+    Syscall { name: "getrandom", nr: 355, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "memfd_create", nr: 356, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "bpf", nr: 357, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "execveat", nr: 358, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "socket", nr: 359, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "socketpair", nr: 360, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "bind", nr: 361, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "connect", nr: 362, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "listen", nr: 363, args: Some(&[Fd, Value]) },
+    Syscall { name: "accept4", nr: 364, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "getsockopt", nr: 365, args: Some(&[Fd, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "setsockopt", nr: 366, args: Some(&[Fd, Value, Value, Ptr, Value]) },
+    Syscall { name: "getsockname", nr: 367, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "getpeername", nr: 368, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "sendto", nr: 369, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "sendmsg", nr: 370, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "recvfrom", nr: 371, args: Some(&[Fd, Ptr, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "recvmsg", nr: 372, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "shutdown", nr: 373, args: Some(&[Fd, Value]) },
+    Syscall { name: "userfaultfd", nr: 374, args: Some(&[Flags]) },
+    Syscall { name: "membarrier", nr: 375, args: Some(&[Value, Flags, Value]) },
+    Syscall { name: "mlock2", nr: 376, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "copy_file_range", nr: 377, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "preadv2", nr: 378, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pwritev2", nr: 379, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pkey_mprotect", nr: 380, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "pkey_alloc", nr: 381, args: Some(&[Flags, Value]) },
+    Syscall { name: "pkey_free", nr: 382, args: Some(&[Value]) },
+    Syscall { name: "statx", nr: 383, args: Some(&[Fd, Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "arch_prctl", nr: 384, args: Some(&[Value, Value]) },
+    Syscall { name: "io_pgetevents", nr: 385, args: Some(&[Value, Value, Value, Ptr, Ptr, Ptr]) },
+    Syscall { name: "rseq", nr: 386, args: Some(&[Ptr, Value, Flags, Value]) },
+    Syscall { name: "semget", nr: 393, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "semctl", nr: 394, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "shmget", nr: 395, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "shmctl", nr: 396, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "shmat", nr: 397, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "shmdt", nr: 398, args: Some(&[Ptr]) },
+    Syscall { name: "msgget", nr: 399, args: Some(&[Value, Flags]) },
+    Syscall { name: "msgsnd", nr: 400, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "msgrcv", nr: 401, args: Some(&[Value, Ptr, Value, Value, Flags]) },
+    Syscall { name: "msgctl", nr: 402, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "clock_gettime64", nr: 403, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_settime64", nr: 404, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_adjtime64", nr: 405, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_getres_time64", nr: 406, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_nanosleep_time64", nr: 407, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timer_gettime64", nr: 408, args: Some(&[Value, Ptr]) },
+    Syscall { name: "timer_settime64", nr: 409, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timerfd_gettime64", nr: 410, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "timerfd_settime64", nr: 411, args: Some(&[Fd, Flags, Ptr, Ptr]) },
+    Syscall { name: "utimensat_time64", nr: 412, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "pselect6_time64", nr: 413, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "ppoll_time64", nr: 414, args: Some(&[Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "io_pgetevents_time64", nr: 416, args: Some(&[Value, Value, Value, Ptr, Ptr, Ptr]) },
+    Syscall { name: "recvmmsg_time64", nr: 417, args: Some(&[Fd, Ptr, Value, Flags, Ptr]) },
+    Syscall { name: "mq_timedsend_time64", nr: 418, args: Some(&[Fd, Ptr, Value, Value, Ptr]) },
+    Syscall { name: "mq_timedreceive_time64", nr: 419, args: Some(&[Fd, Ptr, Value, Ptr, Ptr]) },
+    Syscall { name: "semtimedop_time64", nr: 420, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "rt_sigtimedwait_time64", nr: 421, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "futex_time64", nr: 422, args: Some(&[Ptr, Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "sched_rr_get_interval_time64", nr: 423, args: Some(&[Value, Ptr]) },
+    Syscall { name: "pidfd_send_signal", nr: 424, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "io_uring_setup", nr: 425, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_uring_enter", nr: 426, args: Some(&[Fd, Value, Value, Flags, Ptr, Value]) },
+    Syscall { name: "io_uring_register", nr: 427, args: Some(&[Fd, Value, Ptr, Value]) },
+    Syscall { name: "open_tree", nr: 428, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "move_mount", nr: 429, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "fsopen", nr: 430, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "fsconfig", nr: 431, args: Some(&[Fd, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fsmount", nr: 432, args: Some(&[Fd, Flags, Flags]) },
+    Syscall { name: "fspick", nr: 433, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "pidfd_open", nr: 434, args: Some(&[Value, Flags]) },
+    Syscall { name: "clone3", nr: 435, args: Some(&[Ptr, Value]) },
+    Syscall { name: "close_range", nr: 436, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "openat2", nr: 437, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "pidfd_getfd", nr: 438, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "faccessat2", nr: 439, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_madvise", nr: 440, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "epoll_pwait2", nr: 441, args: Some(&[Fd, Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "mount_setattr", nr: 442, args: Some(&[Fd, Ptr, Flags, Ptr, Value]) },
+    Syscall { name: "quotactl_fd", nr: 443, args: Some(&[Fd, Value, Value, Ptr]) },
+    Syscall { name: "landlock_create_ruleset", nr: 444, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "landlock_add_rule", nr: 445, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "landlock_restrict_self", nr: 446, args: Some(&[Fd, Flags]) },
+    Syscall { name: "memfd_secret", nr: 447, args: Some(&[Flags]) },
+    Syscall { name: "process_mrelease", nr: 448, args: Some(&[Fd, Flags]) },
+    Syscall { name: "futex_waitv", nr: 449, args: Some(&[Ptr, Value, Flags, Ptr, Value]) },
+    Syscall { name: "set_mempolicy_home_node", nr: 450, args: Some(&[Value, Value, Value, Flags]) },
+];
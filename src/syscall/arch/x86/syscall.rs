@@ -4,9 +4,14 @@
 //! possible number of arguments is provided: syscall0 to syscall6.
 //!
 //! The implementation uses the x86-`int$0x80` software interrupt to enter the
-//! kernel. It would be much faster to use the VDSO entry point, but it does
-//! require access to `%gs` and the TLS mappings, and thus is left for future
-//! improvements (if anyone cares enough for 32bit x86).
+//! kernel by default. It is much faster to use the `__kernel_vsyscall` entry
+//! point of the vDSO, which uses `sysenter` or `syscall` if available. Its
+//! address is passed via `AT_SYSINFO` in the auxiliary vector. If the
+//! `vsyscall` feature is enabled, `set_vsyscall()` can be used to provide this
+//! address, and all entries will call into the vDSO rather than using
+//! `int$0x80`. Until the address is provided, the entries fall back to
+//! `int$0x80`. Unlike C libraries, we do not use the `%gs`-based TLS copy of
+//! the address, but keep it in a global variable.
 //!
 //! Arguments are passed as:
 //!     Nr: eax
@@ -14,9 +19,44 @@
 //! Return value is in:
 //!     Ret: eax
 //!
-//! The entry-points are currently not marked as `readonly`. That is, the
-//! system calls are allowed to modify memory. If necessary, alternative calls
-//! with `readonly` (or maybe even `pure`) can be provided in the future.
+//! The same registers are used for `__kernel_vsyscall`, which preserves all
+//! registers but `eax`. It requires a valid stack, though, and does not
+//! preserve the flags.
+//!
+//! The regular entry-points are not marked as `readonly`. That is, the system
+//! calls are allowed to modify memory. Alternative entry-points are provided
+//! for system calls with less side-effects: `syscallN_readonly()` tells the
+//! compiler that the system call does not write to memory of the calling
+//! process, and `syscallN_nomem()` tells it that the system call does not
+//! access memory of the calling process at all (e.g., `getpid()`). This allows
+//! the compiler to keep values in registers across the system call. Using
+//! these entry-points for system calls that do access memory is undefined
+//! behavior.
+
+#[cfg(all(target_arch = "x86", feature = "vsyscall"))]
+static VSYSCALL: core::sync::atomic::AtomicUsize =
+    core::sync::atomic::AtomicUsize::new(0);
+
+/// Set vsyscall Entry-Point
+///
+/// Provide the address of `__kernel_vsyscall`, as passed via `AT_SYSINFO` in
+/// the auxiliary vector. All following system calls will use this entry point
+/// rather than `int$0x80`. Passing 0 reverts to `int$0x80`.
+///
+/// # Safety
+///
+/// `entry` must be 0 or the address of `__kernel_vsyscall` in the vDSO of the
+/// calling process.
+#[cfg(all(target_arch = "x86", feature = "vsyscall"))]
+pub unsafe fn set_vsyscall(entry: usize) {
+    VSYSCALL.store(entry, core::sync::atomic::Ordering::Relaxed);
+}
+
+#[cfg(all(target_arch = "x86", feature = "vsyscall"))]
+#[inline]
+fn vsyscall() -> usize {
+    VSYSCALL.load(core::sync::atomic::Ordering::Relaxed)
+}
 
 #[cfg(target_arch = "x86")]
 #[inline]
@@ -26,6 +66,19 @@ pub unsafe fn syscall0(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+            );
+            return r;
+        }
+    }
+
     core::arch::asm!(
         "int $0x80",
         inlateout("eax") nr => r,
@@ -44,6 +97,20 @@ pub unsafe fn syscall1(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+            );
+            return r;
+        }
+    }
+
     core::arch::asm!(
         "int $0x80",
         inlateout("eax") nr => r,
@@ -64,6 +131,21 @@ pub unsafe fn syscall2(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+            );
+            return r;
+        }
+    }
+
     core::arch::asm!(
         "int $0x80",
         inlateout("eax") nr => r,
@@ -86,6 +168,22 @@ pub unsafe fn syscall3(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+            );
+            return r;
+        }
+    }
+
     core::arch::asm!(
         "int $0x80",
         inlateout("eax") nr => r,
@@ -110,6 +208,29 @@ pub unsafe fn syscall4(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // The entry is pushed to the stack before `esi` is swapped, since
+            // LLVM might have picked `esi` for it.
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+            );
+            return r;
+        }
+    }
+
     // LLVM reserves `esi` for inline-asm management (to make sure stack
     // management is not corrupted). However, it is completely save to use
     // `esi`, and it is not clobbered by the kernel. GCC allows using it for
@@ -147,6 +268,29 @@ pub unsafe fn syscall5(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall4() for `esi` and entry handling
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+            );
+            return r;
+        }
+    }
+
     // see syscall4() for `esi` handling
     core::arch::asm!(
         "xchg esi, {arg3}",
@@ -178,6 +322,43 @@ pub unsafe fn syscall6(
 ) -> usize {
     let mut r: usize;
 
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // With `ebp` needed for `arg5`, there are not enough registers
+            // left to pass the entry point. Hence, we pass `arg5` and the
+            // entry point in memory. We save `esi` and `ebp`, push `arg3`, the
+            // entry point and `arg5` to the stack, and then load `ebp` and
+            // `esi` from there, so it does not matter which registers LLVM
+            // picked for `arg3` and the memory pointer. The call is done
+            // indirectly via the stack, and afterwards we restore `esi` and
+            // `ebp` again.
+            let mem: [usize; 2] = [arg5, entry];
+            core::arch::asm!(
+                "push esi",
+                "push ebp",
+                "push {arg3}",
+                "push dword ptr [{mem} + 4]",
+                "push dword ptr [{mem}]",
+                "pop ebp",
+                "mov esi, dword ptr [esp + 4]",
+                "call dword ptr [esp]",
+                "lea esp, [esp + 8]",
+                "pop ebp",
+                "pop esi",
+                arg3 = in(reg) arg3,
+                mem = in(reg) mem.as_ptr(),
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+            );
+            return r;
+        }
+    }
+
     // The last argument `arg5` needs to be passed in `ebp`. Again, LLVM does
     // allow us to use it as `in`-register. Hence, we just let LLVM pick a
     // register itself. Since there a none left, it will pick the right one,
@@ -215,3 +396,696 @@ pub unsafe fn syscall6(
 
     r
 }
+
+// Read-Only Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call does not write to any memory of the calling
+// process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall4() for details
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    // see syscall4() for details
+    core::arch::asm!(
+        "xchg esi, {arg3}",
+        "int $0x80",
+        "xchg esi, {arg3}",
+        arg3 = in(reg) arg3,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall4() for `esi` and entry handling
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    // see syscall4() for `esi` handling
+    core::arch::asm!(
+        "xchg esi, {arg3}",
+        "int $0x80",
+        "xchg esi, {arg3}",
+        arg3 = in(reg) arg3,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg4,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall6() for details
+            let mem: [usize; 2] = [arg5, entry];
+            core::arch::asm!(
+                "push esi",
+                "push ebp",
+                "push {arg3}",
+                "push dword ptr [{mem} + 4]",
+                "push dword ptr [{mem}]",
+                "pop ebp",
+                "mov esi, dword ptr [esp + 4]",
+                "call dword ptr [esp]",
+                "lea esp, [esp + 8]",
+                "pop ebp",
+                "pop esi",
+                arg3 = in(reg) arg3,
+                mem = in(reg) mem.as_ptr(),
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    // see syscall6() for details
+    core::arch::asm!(
+        "push {arg3}",
+        "push {arg5}",
+        "push esi",
+        "push ebp",
+        "mov ebp, DWORD PTR [esp + 8]",
+        "mov esi, DWORD PTR [esp + 12]",
+        "int $0x80",
+        "pop ebp",
+        "pop esi",
+        "pop {arg5}",
+        "pop {arg3}",
+        arg3 = in(reg) arg3,
+        arg5 = in(reg) arg5,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg4,
+        options(preserves_flags, readonly)
+    );
+
+    r
+}
+
+// No-Memory Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call neither reads nor writes any memory of the
+// calling process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            core::arch::asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    core::arch::asm!(
+        "int $0x80",
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall4() for details
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    // see syscall4() for details
+    core::arch::asm!(
+        "xchg esi, {arg3}",
+        "int $0x80",
+        "xchg esi, {arg3}",
+        arg3 = in(reg) arg3,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall4() for `esi` and entry handling
+            core::arch::asm!(
+                "push {entry}",
+                "xchg esi, {arg3}",
+                "call dword ptr [esp]",
+                "xchg esi, {arg3}",
+                "lea esp, [esp + 4]",
+                entry = in(reg) entry,
+                arg3 = in(reg) arg3,
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+                options(nomem)
+            );
+            return r;
+        }
+    }
+
+    // see syscall4() for `esi` handling
+    core::arch::asm!(
+        "xchg esi, {arg3}",
+        "int $0x80",
+        "xchg esi, {arg3}",
+        arg3 = in(reg) arg3,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg4,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    #[cfg(feature = "vsyscall")]
+    {
+        let entry = vsyscall();
+        if entry != 0 {
+            // see syscall6() for details
+            //
+            // Since `arg5` and the entry point are passed in memory, this
+            // block reads memory and can only be marked as `readonly`.
+            let mem: [usize; 2] = [arg5, entry];
+            core::arch::asm!(
+                "push esi",
+                "push ebp",
+                "push {arg3}",
+                "push dword ptr [{mem} + 4]",
+                "push dword ptr [{mem}]",
+                "pop ebp",
+                "mov esi, dword ptr [esp + 4]",
+                "call dword ptr [esp]",
+                "lea esp, [esp + 8]",
+                "pop ebp",
+                "pop esi",
+                arg3 = in(reg) arg3,
+                mem = in(reg) mem.as_ptr(),
+                inlateout("eax") nr => r,
+                in("ebx") arg0,
+                in("ecx") arg1,
+                in("edx") arg2,
+                in("edi") arg4,
+                options(readonly)
+            );
+            return r;
+        }
+    }
+
+    // see syscall6() for details
+    core::arch::asm!(
+        "push {arg3}",
+        "push {arg5}",
+        "push esi",
+        "push ebp",
+        "mov ebp, DWORD PTR [esp + 8]",
+        "mov esi, DWORD PTR [esp + 12]",
+        "int $0x80",
+        "pop ebp",
+        "pop esi",
+        "pop {arg5}",
+        "pop {arg3}",
+        arg3 = in(reg) arg3,
+        arg5 = in(reg) arg5,
+        inlateout("eax") nr => r,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg4,
+        options(preserves_flags, nomem)
+    );
+
+    r
+}
@@ -0,0 +1,367 @@
+// This code is generated.
+use crate::syscall::meta::{Arg::*, Syscall};
+
+pub const TABLE: &[Syscall] = &[
+    Syscall { name: "read", nr: 0, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "write", nr: 1, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "open", nr: 2, args: Some(&[Ptr, Flags, Value]) },
+    Syscall { name: "close", nr: 3, args: Some(&[Fd]) },
+    Syscall { name: "stat", nr: 4, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstat", nr: 5, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "lstat", nr: 6, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "poll", nr: 7, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "lseek", nr: 8, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "mmap", nr: 9, args: Some(&[Value, Value, Value, Flags, Fd, Value]) },
+    Syscall { name: "mprotect", nr: 10, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "munmap", nr: 11, args: Some(&[Value, Value]) },
+    Syscall { name: "brk", nr: 12, args: Some(&[Value]) },
+    Syscall { name: "rt_sigaction", nr: 13, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigprocmask", nr: 14, args: Some(&[Value, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigreturn", nr: 15, args: Some(&[]) },
+    Syscall { name: "ioctl", nr: 16, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "pread64", nr: 17, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "pwrite64", nr: 18, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "readv", nr: 19, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "writev", nr: 20, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "access", nr: 21, args: Some(&[Ptr, Value]) },
+    Syscall { name: "pipe", nr: 22, args: Some(&[Ptr]) },
+    Syscall { name: "select", nr: 23, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "sched_yield", nr: 24, args: Some(&[]) },
+    Syscall { name: "mremap", nr: 25, args: Some(&[Value, Value, Value, Flags, Value]) },
+    Syscall { name: "msync", nr: 26, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "mincore", nr: 27, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "madvise", nr: 28, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "shmget", nr: 29, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "shmat", nr: 30, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "shmctl", nr: 31, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "dup", nr: 32, args: Some(&[Fd]) },
+    Syscall { name: "dup2", nr: 33, args: Some(&[Fd, Fd]) },
+    Syscall { name: "pause", nr: 34, args: Some(&[]) },
+    Syscall { name: "nanosleep", nr: 35, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getitimer", nr: 36, args: Some(&[Value, Ptr]) },
+    Syscall { name: "alarm", nr: 37, args: Some(&[Value]) },
+    Syscall { name: "setitimer", nr: 38, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "getpid", nr: 39, args: Some(&[]) },
+    Syscall { name: "sendfile", nr: 40, args: Some(&[Fd, Fd, Ptr, Value]) },
+    Syscall { name: "socket", nr: 41, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "connect", nr: 42, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "accept", nr: 43, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "sendto", nr: 44, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "recvfrom", nr: 45, args: Some(&[Fd, Ptr, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "sendmsg", nr: 46, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "recvmsg", nr: 47, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "shutdown", nr: 48, args: Some(&[Fd, Value]) },
+    Syscall { name: "bind", nr: 49, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "listen", nr: 50, args: Some(&[Fd, Value]) },
+    Syscall { name: "getsockname", nr: 51, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "getpeername", nr: 52, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "socketpair", nr: 53, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "setsockopt", nr: 54, args: Some(&[Fd, Value, Value, Ptr, Value]) },
+    Syscall { name: "getsockopt", nr: 55, args: Some(&[Fd, Value, Value, Ptr, Ptr]) },
+    Syscall { name: "clone", nr: 56, args: Some(&[Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fork", nr: 57, args: Some(&[]) },
+    Syscall { name: "vfork", nr: 58, args: Some(&[]) },
+    Syscall { name: "execve", nr: 59, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "exit", nr: 60, args: Some(&[Value]) },
+    Syscall { name: "wait4", nr: 61, args: Some(&[Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "kill", nr: 62, args: Some(&[Value, Value]) },
+    Syscall { name: "uname", nr: 63, args: Some(&[Ptr]) },
+    Syscall { name: "semget", nr: 64, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "semop", nr: 65, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "semctl", nr: 66, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "shmdt", nr: 67, args: Some(&[Ptr]) },
+    Syscall { name: "msgget", nr: 68, args: Some(&[Value, Flags]) },
+    Syscall { name: "msgsnd", nr: 69, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "msgrcv", nr: 70, args: Some(&[Value, Ptr, Value, Value, Flags]) },
+    Syscall { name: "msgctl", nr: 71, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "fcntl", nr: 72, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "flock", nr: 73, args: Some(&[Fd, Value]) },
+    Syscall { name: "fsync", nr: 74, args: Some(&[Fd]) },
+    Syscall { name: "fdatasync", nr: 75, args: Some(&[Fd]) },
+    Syscall { name: "truncate", nr: 76, args: Some(&[Ptr, Value]) },
+    Syscall { name: "ftruncate", nr: 77, args: Some(&[Fd, Value]) },
+    Syscall { name: "getdents", nr: 78, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "getcwd", nr: 79, args: Some(&[Ptr, Value]) },
+    Syscall { name: "chdir", nr: 80, args: Some(&[Ptr]) },
+    Syscall { name: "fchdir", nr: 81, args: Some(&[Fd]) },
+    Syscall { name: "rename", nr: 82, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mkdir", nr: 83, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rmdir", nr: 84, args: Some(&[Ptr]) },
+    Syscall { name: "creat", nr: 85, args: Some(&[Ptr, Value]) },
+    Syscall { name: "link", nr: 86, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "unlink", nr: 87, args: Some(&[Ptr]) },
+    Syscall { name: "symlink", nr: 88, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "readlink", nr: 89, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "chmod", nr: 90, args: Some(&[Ptr, Value]) },
+    Syscall { name: "fchmod", nr: 91, args: Some(&[Fd, Value]) },
+    Syscall { name: "chown", nr: 92, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "fchown", nr: 93, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "lchown", nr: 94, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "umask", nr: 95, args: Some(&[Value]) },
+    Syscall { name: "gettimeofday", nr: 96, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "getrlimit", nr: 97, args: Some(&[Value, Ptr]) },
+    Syscall { name: "getrusage", nr: 98, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sysinfo", nr: 99, args: Some(&[Ptr]) },
+    Syscall { name: "times", nr: 100, args: Some(&[Ptr]) },
+    Syscall { name: "ptrace", nr: 101, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "getuid", nr: 102, args: Some(&[]) },
+    Syscall { name: "syslog", nr: 103, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "getgid", nr: 104, args: Some(&[]) },
+    Syscall { name: "setuid", nr: 105, args: Some(&[Value]) },
+    Syscall { name: "setgid", nr: 106, args: Some(&[Value]) },
+    Syscall { name: "geteuid", nr: 107, args: Some(&[]) },
+    Syscall { name: "getegid", nr: 108, args: Some(&[]) },
+    Syscall { name: "setpgid", nr: 109, args: Some(&[Value, Value]) },
+    Syscall { name: "getppid", nr: 110, args: Some(&[]) },
+    Syscall { name: "getpgrp", nr: 111, args: Some(&[]) },
+    Syscall { name: "setsid", nr: 112, args: Some(&[]) },
+    Syscall { name: "setreuid", nr: 113, args: Some(&[Value, Value]) },
+    Syscall { name: "setregid", nr: 114, args: Some(&[Value, Value]) },
+    Syscall { name: "getgroups", nr: 115, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setgroups", nr: 116, args: Some(&[Value, Ptr]) },
+    Syscall { name: "setresuid", nr: 117, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresuid", nr: 118, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "setresgid", nr: 119, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getresgid", nr: 120, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "getpgid", nr: 121, args: Some(&[Value]) },
+    Syscall { name: "setfsuid", nr: 122, args: Some(&[Value]) },
+    Syscall { name: "setfsgid", nr: 123, args: Some(&[Value]) },
+    Syscall { name: "getsid", nr: 124, args: Some(&[Value]) },
+    Syscall { name: "capget", nr: 125, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "capset", nr: 126, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "rt_sigpending", nr: 127, args: Some(&[Ptr, Value]) },
+    Syscall { name: "rt_sigtimedwait", nr: 128, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "rt_sigqueueinfo", nr: 129, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "rt_sigsuspend", nr: 130, args: Some(&[Ptr, Value]) },
+    Syscall { name: "sigaltstack", nr: 131, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "utime", nr: 132, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mknod", nr: 133, args: Some(&[Ptr, Value, Value]) },
+    Syscall { name: "uselib", nr: 134, args: None },
+    Syscall { name: "personality", nr: 135, args: Some(&[Value]) },
+    Syscall { name: "ustat", nr: 136, args: Some(&[Value, Ptr]) },
+    Syscall { name: "statfs", nr: 137, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fstatfs", nr: 138, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "sysfs", nr: 139, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "getpriority", nr: 140, args: Some(&[Value, Value]) },
+    Syscall { name: "setpriority", nr: 141, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "sched_setparam", nr: 142, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_getparam", nr: 143, args: Some(&[Value, Ptr]) },
+    Syscall { name: "sched_setscheduler", nr: 144, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getscheduler", nr: 145, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_max", nr: 146, args: Some(&[Value]) },
+    Syscall { name: "sched_get_priority_min", nr: 147, args: Some(&[Value]) },
+    Syscall { name: "sched_rr_get_interval", nr: 148, args: Some(&[Value, Ptr]) },
+    Syscall { name: "mlock", nr: 149, args: Some(&[Value, Value]) },
+    Syscall { name: "munlock", nr: 150, args: Some(&[Value, Value]) },
+    Syscall { name: "mlockall", nr: 151, args: Some(&[Flags]) },
+    Syscall { name: "munlockall", nr: 152, args: Some(&[]) },
+    Syscall { name: "vhangup", nr: 153, args: Some(&[]) },
+    Syscall { name: "modify_ldt", nr: 154, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "pivot_root", nr: 155, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "_sysctl", nr: 156, args: None },
+    Syscall { name: "prctl", nr: 157, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "arch_prctl", nr: 158, args: Some(&[Value, Value]) },
+    Syscall { name: "adjtimex", nr: 159, args: Some(&[Ptr]) },
+    Syscall { name: "setrlimit", nr: 160, args: Some(&[Value, Ptr]) },
+    Syscall { name: "chroot", nr: 161, args: Some(&[Ptr]) },
+    Syscall { name: "sync", nr: 162, args: Some(&[]) },
+    Syscall { name: "acct", nr: 163, args: Some(&[Ptr]) },
+    Syscall { name: "settimeofday", nr: 164, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "mount", nr: 165, args: Some(&[Ptr, Ptr, Ptr, Flags, Ptr]) },
+    Syscall { name: "umount2", nr: 166, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "swapon", nr: 167, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "swapoff", nr: 168, args: Some(&[Ptr]) },
+    Syscall { name: "reboot", nr: 169, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "sethostname", nr: 170, args: Some(&[Ptr, Value]) },
+    Syscall { name: "setdomainname", nr: 171, args: Some(&[Ptr, Value]) },
+    Syscall { name: "iopl", nr: 172, args: Some(&[Value]) },
+    Syscall { name: "ioperm", nr: 173, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "create_module", nr: 174, args: None },
+    Syscall { name: "init_module", nr: 175, args: Some(&[Ptr, Value, Ptr]) },
+    Syscall { name: "delete_module", nr: 176, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "get_kernel_syms", nr: 177, args: None },
+    Syscall { name: "query_module", nr: 178, args: None },
+    Syscall { name: "quotactl", nr: 179, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "nfsservctl", nr: 180, args: None },
+    Syscall { name: "getpmsg", nr: 181, args: None },
+    Syscall { name: "putpmsg", nr: 182, args: None },
+    Syscall { name: "afs_syscall", nr: 183, args: None },
+    Syscall { name: "tuxcall", nr: 184, args: None },
+    Syscall { name: "security", nr: 185, args: None },
+    Syscall { name: "gettid", nr: 186, args: Some(&[]) },
+    Syscall { name: "readahead", nr: 187, args: Some(&[Fd, Value, Value]) },
+    Syscall { name: "setxattr", nr: 188, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "lsetxattr", nr: 189, args: Some(&[Ptr, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "fsetxattr", nr: 190, args: Some(&[Fd, Ptr, Ptr, Value, Flags]) },
+    Syscall { name: "getxattr", nr: 191, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "lgetxattr", nr: 192, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "fgetxattr", nr: 193, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "listxattr", nr: 194, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "llistxattr", nr: 195, args: Some(&[Ptr, Ptr, Value]) },
+    Syscall { name: "flistxattr", nr: 196, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "removexattr", nr: 197, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "lremovexattr", nr: 198, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "fremovexattr", nr: 199, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "tkill", nr: 200, args: Some(&[Value, Value]) },
+    Syscall { name: "time", nr: 201, args: Some(&[Ptr]) },
+    Syscall { name: "futex", nr: 202, args: Some(&[Ptr, Value, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "sched_setaffinity", nr: 203, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "sched_getaffinity", nr: 204, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "set_thread_area", nr: 205, args: None },
+    Syscall { name: "io_setup", nr: 206, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_destroy", nr: 207, args: Some(&[Value]) },
+    Syscall { name: "io_getevents", nr: 208, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "io_submit", nr: 209, args: Some(&[Value, Value, Ptr]) },
+    Syscall { name: "io_cancel", nr: 210, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "get_thread_area", nr: 211, args: None },
+    Syscall { name: "lookup_dcookie", nr: 212, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "epoll_create", nr: 213, args: Some(&[Value]) },
+    Syscall { name: "epoll_ctl_old", nr: 214, args: None },
+    Syscall { name: "epoll_wait_old", nr: 215, args: None },
+    Syscall { name: "remap_file_pages", nr: 216, args: Some(&[Value, Value, Value, Value, Flags]) },
+    Syscall { name: "getdents64", nr: 217, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "set_tid_address", nr: 218, args: Some(&[Ptr]) },
+    Syscall { name: "restart_syscall", nr: 219, args: Some(&[]) },
+    Syscall { name: "semtimedop", nr: 220, args: Some(&[Value, Ptr, Value, Ptr]) },
+    Syscall { name: "fadvise64", nr: 221, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "timer_create", nr: 222, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "timer_settime", nr: 223, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "timer_gettime", nr: 224, args: Some(&[Value, Ptr]) },
+    Syscall { name: "timer_getoverrun", nr: 225, args: Some(&[Value]) },
+    Syscall { name: "timer_delete", nr: 226, args: Some(&[Value]) },
+    Syscall { name: "clock_settime", nr: 227, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_gettime", nr: 228, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_getres", nr: 229, args: Some(&[Value, Ptr]) },
+    Syscall { name: "clock_nanosleep", nr: 230, args: Some(&[Value, Flags, Ptr, Ptr]) },
+    Syscall { name: "exit_group", nr: 231, args: Some(&[Value]) },
+    Syscall { name: "epoll_wait", nr: 232, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "epoll_ctl", nr: 233, args: Some(&[Fd, Value, Fd, Ptr]) },
+    Syscall { name: "tgkill", nr: 234, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "utimes", nr: 235, args: Some(&[Ptr, Ptr]) },
+    Syscall { name: "vserver", nr: 236, args: None },
+    Syscall { name: "mbind", nr: 237, args: Some(&[Value, Value, Value, Ptr, Value, Flags]) },
+    Syscall { name: "set_mempolicy", nr: 238, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "get_mempolicy", nr: 239, args: Some(&[Ptr, Ptr, Value, Value, Flags]) },
+    Syscall { name: "mq_open", nr: 240, args: Some(&[Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "mq_unlink", nr: 241, args: Some(&[Ptr]) },
+    Syscall { name: "mq_timedsend", nr: 242, args: Some(&[Fd, Ptr, Value, Value, Ptr]) },
+    Syscall { name: "mq_timedreceive", nr: 243, args: Some(&[Fd, Ptr, Value, Ptr, Ptr]) },
+    Syscall { name: "mq_notify", nr: 244, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "mq_getsetattr", nr: 245, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "kexec_load", nr: 246, args: Some(&[Value, Value, Ptr, Flags]) },
+    Syscall { name: "waitid", nr: 247, args: Some(&[Value, Value, Ptr, Flags, Ptr]) },
+    Syscall { name: "add_key", nr: 248, args: Some(&[Ptr, Ptr, Ptr, Value, Value]) },
+    Syscall { name: "request_key", nr: 249, args: Some(&[Ptr, Ptr, Ptr, Value]) },
+    Syscall { name: "keyctl", nr: 250, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "ioprio_set", nr: 251, args: Some(&[Value, Value, Value]) },
+    Syscall { name: "ioprio_get", nr: 252, args: Some(&[Value, Value]) },
+    Syscall { name: "inotify_init", nr: 253, args: Some(&[]) },
+    Syscall { name: "inotify_add_watch", nr: 254, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "inotify_rm_watch", nr: 255, args: Some(&[Fd, Value]) },
+    Syscall { name: "migrate_pages", nr: 256, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "openat", nr: 257, args: Some(&[Fd, Ptr, Flags, Value]) },
+    Syscall { name: "mkdirat", nr: 258, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "mknodat", nr: 259, args: Some(&[Fd, Ptr, Value, Value]) },
+    Syscall { name: "fchownat", nr: 260, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "futimesat", nr: 261, args: Some(&[Fd, Ptr, Ptr]) },
+    Syscall { name: "newfstatat", nr: 262, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "unlinkat", nr: 263, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "renameat", nr: 264, args: Some(&[Fd, Ptr, Fd, Ptr]) },
+    Syscall { name: "linkat", nr: 265, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "symlinkat", nr: 266, args: Some(&[Ptr, Fd, Ptr]) },
+    Syscall { name: "readlinkat", nr: 267, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "fchmodat", nr: 268, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "faccessat", nr: 269, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "pselect6", nr: 270, args: Some(&[Value, Ptr, Ptr, Ptr, Ptr, Ptr]) },
+    Syscall { name: "ppoll", nr: 271, args: Some(&[Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "unshare", nr: 272, args: Some(&[Flags]) },
+    Syscall { name: "set_robust_list", nr: 273, args: Some(&[Ptr, Value]) },
+    Syscall { name: "get_robust_list", nr: 274, args: Some(&[Value, Ptr, Ptr]) },
+    Syscall { name: "splice", nr: 275, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "tee", nr: 276, args: Some(&[Fd, Fd, Value, Flags]) },
+    Syscall { name: "sync_file_range", nr: 277, args: Some(&[Fd, Value, Value, Flags]) },
+    Syscall { name: "vmsplice", nr: 278, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "move_pages", nr: 279, args: Some(&[Value, Value, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "utimensat", nr: 280, args: Some(&[Fd, Ptr, Ptr, Flags]) },
+    Syscall { name: "epoll_pwait", nr: 281, args: Some(&[Fd, Ptr, Value, Value, Ptr, Value]) },
+    Syscall { name: "signalfd", nr: 282, args: Some(&[Fd, Ptr, Value]) },
+    Syscall { name: "timerfd_create", nr: 283, args: Some(&[Value, Flags]) },
+    Syscall { name: "eventfd", nr: 284, args: Some(&[Value]) },
+    Syscall { name: "fallocate", nr: 285, args: Some(&[Fd, Value, Value, Value]) },
+    Syscall { name: "timerfd_settime", nr: 286, args: Some(&[Fd, Flags, Ptr, Ptr]) },
+    Syscall { name: "timerfd_gettime", nr: 287, args: Some(&[Fd, Ptr]) },
+    Syscall { name: "accept4", nr: 288, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "signalfd4", nr: 289, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "eventfd2", nr: 290, args: Some(&[Value, Flags]) },
+    Syscall { name: "epoll_create1", nr: 291, args: Some(&[Flags]) },
+    Syscall { name: "dup3", nr: 292, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "pipe2", nr: 293, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "inotify_init1", nr: 294, args: Some(&[Flags]) },
+    Syscall { name: "preadv", nr: 295, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "pwritev", nr: 296, args: Some(&[Fd, Ptr, Value, Value, Value]) },
+    Syscall { name: "rt_tgsigqueueinfo", nr: 297, args: Some(&[Value, Value, Value, Ptr]) },
+    Syscall { name: "perf_event_open", nr: 298, args: Some(&[Ptr, Value, Value, Fd, Flags]) },
+    Syscall { name: "recvmmsg", nr: 299, args: Some(&[Fd, Ptr, Value, Flags, Ptr]) },
+    Syscall { name: "fanotify_init", nr: 300, args: Some(&[Flags, Flags]) },
+    Syscall { name: "fanotify_mark", nr: 301, args: Some(&[Fd, Flags, Value, Fd, Ptr]) },
+    Syscall { name: "prlimit64", nr: 302, args: Some(&[Value, Value, Ptr, Ptr]) },
+    Syscall { name: "name_to_handle_at", nr: 303, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "open_by_handle_at", nr: 304, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "clock_adjtime", nr: 305, args: Some(&[Value, Ptr]) },
+    Syscall { name: "syncfs", nr: 306, args: Some(&[Fd]) },
+    Syscall { name: "sendmmsg", nr: 307, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "setns", nr: 308, args: Some(&[Fd, Value]) },
+    Syscall { name: "getcpu", nr: 309, args: Some(&[Ptr, Ptr, Ptr]) },
+    Syscall { name: "process_vm_readv", nr: 310, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "process_vm_writev", nr: 311, args: Some(&[Value, Ptr, Value, Ptr, Value, Flags]) },
+    Syscall { name: "kcmp", nr: 312, args: Some(&[Value, Value, Value, Value, Value]) },
+    Syscall { name: "finit_module", nr: 313, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "sched_setattr", nr: 314, args: Some(&[Value, Ptr, Flags]) },
+    Syscall { name: "sched_getattr", nr: 315, args: Some(&[Value, Ptr, Value, Flags]) },
+    Syscall { name: "renameat2", nr: 316, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "seccomp", nr: 317, args: Some(&[Value, Flags, Ptr]) },
+    Syscall { name: "getrandom", nr: 318, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "memfd_create", nr: 319, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "kexec_file_load", nr: 320, args: Some(&[Fd, Fd, Value, Ptr, Flags]) },
+    Syscall { name: "bpf", nr: 321, args: Some(&[Value, Ptr, Value]) },
+    Syscall { name: "execveat", nr: 322, args: Some(&[Fd, Ptr, Ptr, Ptr, Flags]) },
+    Syscall { name: "userfaultfd", nr: 323, args: Some(&[Flags]) },
+    Syscall { name: "membarrier", nr: 324, args: Some(&[Value, Flags, Value]) },
+    Syscall { name: "mlock2", nr: 325, args: Some(&[Value, Value, Flags]) },
+    Syscall { name: "copy_file_range", nr: 326, args: Some(&[Fd, Ptr, Fd, Ptr, Value, Flags]) },
+    Syscall { name: "preadv2", nr: 327, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pwritev2", nr: 328, args: Some(&[Fd, Ptr, Value, Value, Value, Flags]) },
+    Syscall { name: "pkey_mprotect", nr: 329, args: Some(&[Value, Value, Value, Value]) },
+    Syscall { name: "pkey_alloc", nr: 330, args: Some(&[Flags, Value]) },
+    Syscall { name: "pkey_free", nr: 331, args: Some(&[Value]) },
+    Syscall { name: "statx", nr: 332, args: Some(&[Fd, Ptr, Flags, Value, Ptr]) },
+    Syscall { name: "io_pgetevents", nr: 333, args: Some(&[Value, Value, Value, Ptr, Ptr, Ptr]) },
+    Syscall { name: "rseq", nr: 334, args: Some(&[Ptr, Value, Flags, Value]) },
+    Syscall { name: "pidfd_send_signal", nr: 424, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "io_uring_setup", nr: 425, args: Some(&[Value, Ptr]) },
+    Syscall { name: "io_uring_enter", nr: 426, args: Some(&[Fd, Value, Value, Flags, Ptr, Value]) },
+    Syscall { name: "io_uring_register", nr: 427, args: Some(&[Fd, Value, Ptr, Value]) },
+    Syscall { name: "open_tree", nr: 428, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "move_mount", nr: 429, args: Some(&[Fd, Ptr, Fd, Ptr, Flags]) },
+    Syscall { name: "fsopen", nr: 430, args: Some(&[Ptr, Flags]) },
+    Syscall { name: "fsconfig", nr: 431, args: Some(&[Fd, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "fsmount", nr: 432, args: Some(&[Fd, Flags, Flags]) },
+    Syscall { name: "fspick", nr: 433, args: Some(&[Fd, Ptr, Flags]) },
+    Syscall { name: "pidfd_open", nr: 434, args: Some(&[Value, Flags]) },
+    Syscall { name: "clone3", nr: 435, args: Some(&[Ptr, Value]) },
+    Syscall { name: "close_range", nr: 436, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "openat2", nr: 437, args: Some(&[Fd, Ptr, Ptr, Value]) },
+    Syscall { name: "pidfd_getfd", nr: 438, args: Some(&[Fd, Fd, Flags]) },
+    Syscall { name: "faccessat2", nr: 439, args: Some(&[Fd, Ptr, Value, Flags]) },
+    Syscall { name: "process_madvise", nr: 440, args: Some(&[Fd, Ptr, Value, Value, Flags]) },
+    Syscall { name: "epoll_pwait2", nr: 441, args: Some(&[Fd, Ptr, Value, Ptr, Ptr, Value]) },
+    Syscall { name: "mount_setattr", nr: 442, args: Some(&[Fd, Ptr, Flags, Ptr, Value]) },
+    Syscall { name: "quotactl_fd", nr: 443, args: Some(&[Fd, Value, Value, Ptr]) },
+    Syscall { name: "landlock_create_ruleset", nr: 444, args: Some(&[Ptr, Value, Flags]) },
+    Syscall { name: "landlock_add_rule", nr: 445, args: Some(&[Fd, Value, Ptr, Flags]) },
+    Syscall { name: "landlock_restrict_self", nr: 446, args: Some(&[Fd, Flags]) },
+    Syscall { name: "memfd_secret", nr: 447, args: Some(&[Flags]) },
+    Syscall { name: "process_mrelease", nr: 448, args: Some(&[Fd, Flags]) },
+    Syscall { name: "futex_waitv", nr: 449, args: Some(&[Ptr, Value, Flags, Ptr, Value]) },
+    Syscall { name: "set_mempolicy_home_node", nr: 450, args: Some(&[Value, Value, Value, Flags]) },
+];
@@ -8,5 +8,6 @@
 //! the official linux kernel documentation for help, or look at the interfaces
 //! exposed by the `api` module.
 
+pub mod meta;
 pub mod nr;
 pub mod syscall;
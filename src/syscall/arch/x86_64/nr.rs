@@ -346,6 +346,18 @@ pub const FSMOUNT: usize = 432;
 pub const FSPICK: usize = 433;
 pub const PIDFD_OPEN: usize = 434;
 pub const CLONE3: usize = 435;
+pub const CLOSE_RANGE: usize = 436;
 pub const OPENAT2: usize = 437;
 pub const PIDFD_GETFD: usize = 438;
 pub const FACCESSAT2: usize = 439;
+pub const PROCESS_MADVISE: usize = 440;
+pub const EPOLL_PWAIT2: usize = 441;
+pub const MOUNT_SETATTR: usize = 442;
+pub const QUOTACTL_FD: usize = 443;
+pub const LANDLOCK_CREATE_RULESET: usize = 444;
+pub const LANDLOCK_ADD_RULE: usize = 445;
+pub const LANDLOCK_RESTRICT_SELF: usize = 446;
+pub const MEMFD_SECRET: usize = 447;
+pub const PROCESS_MRELEASE: usize = 448;
+pub const FUTEX_WAITV: usize = 449;
+pub const SET_MEMPOLICY_HOME_NODE: usize = 450;
@@ -15,11 +15,17 @@
 //! Always clobbered:
 //!     rcx, r11
 //!
-//! The entry-points are currently not marked as `readonly`. That is, the
-//! system calls are allowed to modify memory. If necessary, alternative calls
-//! with `readonly` (or maybe even `pure`) can be provided in the future.
+//! The regular entry-points are not marked as `readonly`. That is, the system
+//! calls are allowed to modify memory. Alternative entry-points are provided
+//! for system calls with less side-effects: `syscallN_readonly()` tells the
+//! compiler that the system call does not write to memory of the calling
+//! process, and `syscallN_nomem()` tells it that the system call does not
+//! access memory of the calling process at all (e.g., `getpid()`). This allows
+//! the compiler to keep values in registers across the system call. Using
+//! these entry-points for system calls that do access memory is undefined
+//! behavior.
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall0"]
 pub unsafe fn syscall0(
@@ -38,7 +44,7 @@ pub unsafe fn syscall0(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall1"]
 pub unsafe fn syscall1(
@@ -59,7 +65,7 @@ pub unsafe fn syscall1(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall2"]
 pub unsafe fn syscall2(
@@ -82,7 +88,7 @@ pub unsafe fn syscall2(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall3"]
 pub unsafe fn syscall3(
@@ -107,7 +113,7 @@ pub unsafe fn syscall3(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall4"]
 pub unsafe fn syscall4(
@@ -134,7 +140,7 @@ pub unsafe fn syscall4(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall5"]
 pub unsafe fn syscall5(
@@ -163,7 +169,7 @@ pub unsafe fn syscall5(
     r
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 #[inline]
 #[export_name = "r_linux_asm_syscall6"]
 pub unsafe fn syscall6(
@@ -193,3 +199,407 @@ pub unsafe fn syscall6(
 
     r
 }
+
+// Read-Only Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call does not write to any memory of the calling
+// process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not write to any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        in("r9") arg5,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, readonly)
+    );
+
+    r
+}
+
+// No-Memory Entries
+//
+// The following entries are identical to the entries above, but tell the
+// compiler that the system call neither reads nor writes any memory of the
+// calling process. See the module documentation for details.
+
+/// # Safety
+///
+/// Like `syscall0()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall1()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall2()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall3()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall4()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall5()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
+
+/// # Safety
+///
+/// Like `syscall6()`, but the system call must not access any memory
+/// of the calling process. See the module documentation for details.
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[inline]
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut r: usize;
+
+    core::arch::asm!(
+        "syscall",
+        inlateout("rax") nr => r,
+        in("rdi") arg0,
+        in("rsi") arg1,
+        in("rdx") arg2,
+        in("r10") arg3,
+        in("r8") arg4,
+        in("r9") arg5,
+        out("rcx") _,
+        out("r11") _,
+        options(nostack, preserves_flags, nomem)
+    );
+
+    r
+}
@@ -0,0 +1,179 @@
+#!/usr/bin/python3
+"""Code Generator for Error Numbers
+
+This script is used to generate some of the sources of this crate. It currently
+generates the following data:
+
+   * Error Numbers
+     Most linux architectures use the error numbers defined in
+     `asm-generic/errno.h` (which includes `asm-generic/errno-base.h`). A few
+     architectures (alpha, mips, parisc, sparc) use their own numbering for
+     everything but the base error numbers, and define some additional error
+     numbers of their own.
+     We fetch these headers from `git.kernel.org`, parse the definitions
+     including their trailing comments, and then generate the error number
+     definitions and description tables for the rust crate.
+"""
+
+
+import argparse
+import re
+import sys
+import urllib.request
+
+
+# Header defining the error numbers of each table. Includes of other
+# `asm-generic` headers are followed.
+ARCHS = {
+    "generic": "include/uapi/asm-generic/errno.h",
+    "alpha": "arch/alpha/include/uapi/asm/errno.h",
+    "mips": "arch/mips/include/uapi/asm/errno.h",
+    "parisc": "arch/parisc/include/uapi/asm/errno.h",
+    "sparc": "arch/sparc/include/uapi/asm/errno.h",
+}
+
+
+def errtbl_fetch(*, path):
+    """Fetch Error Header
+
+    Fetch the error header at `path` from the official git repository.
+    """
+
+    host = "git.kernel.org"
+    repo = "pub/scm/linux/kernel/git/torvalds/linux.git"
+    branch = "master"
+
+    url = "".join([
+        "https://",
+        host,
+        "/", repo,
+        "/", "plain",
+        "/", path,
+        "?h=", branch,
+    ])
+
+    print("Fetching from:", url, file=sys.stderr)
+
+    with urllib.request.urlopen(url) as req:
+        data = req.read()
+
+    return data
+
+
+def errtbl_parse(*, data, res):
+    """Parse Error Header
+
+    The error headers contain entries in the style of:
+        #define EPERM   1   /* Operation not permitted */
+        #define EWOULDBLOCK EAGAIN  /* Operation would block */
+    Aliases might lack the trailing comment. Includes of other `asm-generic`
+    headers are followed recursively, and `#undef` drops earlier entries.
+    """
+
+    for line in data.decode().splitlines():
+        line = line.replace("\t", " ").strip()
+
+        m = re.match(r"#\s*include\s*<(asm-generic/[\w-]+\.h)>", line)
+        if m:
+            path = "include/uapi/" + m.group(1)
+            errtbl_parse(data=errtbl_fetch(path=path), res=res)
+            continue
+
+        m = re.match(r"#\s*undef\s+(E\w+)", line)
+        if m:
+            res.pop(m.group(1), None)
+            continue
+
+        m = re.match(r"#\s*define\s+(E\w+)\s+(\w+)\s*(?:/\*\s*(.*?)\s*\*/)?", line)
+        if m:
+            res[m.group(1)] = [m.group(2), m.group(3)]
+
+    return res
+
+
+def errtbl_resolve(*, errtbl):
+    """Resolve Error Table
+
+    Resolve aliases to their error numbers and descriptions, and order the
+    entries by error number. Aliases are ordered after the entry they refer
+    to, so the first entry of each number carries the canonical name.
+    """
+
+    res = []
+    for name, (value, desc) in errtbl.items():
+        alias = None
+        while not re.fullmatch(r"\d+", value):
+            alias = alias or value
+            desc = desc or errtbl[value][1]
+            value = errtbl[value][0]
+        res.append([int(value), name, alias, desc])
+
+    return sorted(res, key=lambda v: (v[0], v[2] is not None))
+
+
+def errtbl_emit(*, errtbl):
+    """Emit Rustified Error Table
+
+    Emit rust code as expected by the crate, which contains the definitions
+    of the error numbers, as well as a table of all names and descriptions.
+    """
+
+    print("// This code is generated.")
+    for entry in errtbl:
+        value = entry[2] if entry[2] else entry[0]
+        print(f"pub const {entry[1]}: u16 = {value};")
+    print()
+    print("pub const TABLE: &[(u16, &str, &str)] = &[")
+    for entry in errtbl:
+        print(f"    ({entry[1]}, \"{entry[1]}\", \"{entry[3]}\"),")
+    print("];")
+
+
+def errtbl(args):
+    print("Fetch Error Table...", file=sys.stderr)
+    data = errtbl_fetch(path=ARCHS[args.arch])
+    print("Parse Error Table...", file=sys.stderr)
+    errtbl = errtbl_resolve(errtbl=errtbl_parse(data=data, res={}))
+    print("Emit Error Table...", file=sys.stderr)
+    errtbl_emit(errtbl=errtbl)
+
+
+def parse_args(argv):
+    parser = argparse.ArgumentParser(
+        add_help=True,
+        allow_abbrev=False,
+        argument_default=None,
+        description="Code Generator for Error Numbers",
+        prog="generate.py",
+    )
+
+    parser.add_argument(
+        "--arch",
+        choices=list(ARCHS.keys()),
+        help="Which architecture to generate for",
+        required=True,
+        type=str,
+    )
+
+    parser.add_argument(
+        "--generate",
+        choices=["errtbl"],
+        help="What to generate",
+        required=True,
+        type=str,
+    )
+
+    return parser.parse_args(argv[1:])
+
+
+def run(argv):
+    args = parse_args(argv)
+
+    if args.generate == "errtbl":
+        errtbl(args)
+    else:
+        raise RuntimeError("Nothing to do")
+
+
+if __name__ == "__main__":
+    run(sys.argv)
@@ -0,0 +1,274 @@
+// This code is generated.
+pub const EPERM: u16 = 1;
+pub const ENOENT: u16 = 2;
+pub const ESRCH: u16 = 3;
+pub const EINTR: u16 = 4;
+pub const EIO: u16 = 5;
+pub const ENXIO: u16 = 6;
+pub const E2BIG: u16 = 7;
+pub const ENOEXEC: u16 = 8;
+pub const EBADF: u16 = 9;
+pub const ECHILD: u16 = 10;
+pub const EAGAIN: u16 = 11;
+pub const EWOULDBLOCK: u16 = EAGAIN;
+pub const ENOMEM: u16 = 12;
+pub const EACCES: u16 = 13;
+pub const EFAULT: u16 = 14;
+pub const ENOTBLK: u16 = 15;
+pub const EBUSY: u16 = 16;
+pub const EEXIST: u16 = 17;
+pub const EXDEV: u16 = 18;
+pub const ENODEV: u16 = 19;
+pub const ENOTDIR: u16 = 20;
+pub const EISDIR: u16 = 21;
+pub const EINVAL: u16 = 22;
+pub const ENFILE: u16 = 23;
+pub const EMFILE: u16 = 24;
+pub const ENOTTY: u16 = 25;
+pub const ETXTBSY: u16 = 26;
+pub const EFBIG: u16 = 27;
+pub const ENOSPC: u16 = 28;
+pub const ESPIPE: u16 = 29;
+pub const EROFS: u16 = 30;
+pub const EMLINK: u16 = 31;
+pub const EPIPE: u16 = 32;
+pub const EDOM: u16 = 33;
+pub const ERANGE: u16 = 34;
+pub const ENOMSG: u16 = 35;
+pub const EIDRM: u16 = 36;
+pub const ECHRNG: u16 = 37;
+pub const EL2NSYNC: u16 = 38;
+pub const EL3HLT: u16 = 39;
+pub const EL3RST: u16 = 40;
+pub const ELNRNG: u16 = 41;
+pub const EUNATCH: u16 = 42;
+pub const ENOCSI: u16 = 43;
+pub const EL2HLT: u16 = 44;
+pub const EDEADLK: u16 = 45;
+pub const ENOLCK: u16 = 46;
+pub const EBADE: u16 = 50;
+pub const EBADR: u16 = 51;
+pub const EXFULL: u16 = 52;
+pub const ENOANO: u16 = 53;
+pub const EBADRQC: u16 = 54;
+pub const EBADSLT: u16 = 55;
+pub const EDEADLOCK: u16 = 56;
+pub const EBFONT: u16 = 59;
+pub const ENOSTR: u16 = 60;
+pub const ENODATA: u16 = 61;
+pub const ETIME: u16 = 62;
+pub const ENOSR: u16 = 63;
+pub const ENONET: u16 = 64;
+pub const ENOPKG: u16 = 65;
+pub const EREMOTE: u16 = 66;
+pub const ENOLINK: u16 = 67;
+pub const EADV: u16 = 68;
+pub const ESRMNT: u16 = 69;
+pub const ECOMM: u16 = 70;
+pub const EPROTO: u16 = 71;
+pub const EDOTDOT: u16 = 73;
+pub const EMULTIHOP: u16 = 74;
+pub const EBADMSG: u16 = 77;
+pub const ENAMETOOLONG: u16 = 78;
+pub const EOVERFLOW: u16 = 79;
+pub const ENOTUNIQ: u16 = 80;
+pub const EBADFD: u16 = 81;
+pub const EREMCHG: u16 = 82;
+pub const ELIBACC: u16 = 83;
+pub const ELIBBAD: u16 = 84;
+pub const ELIBSCN: u16 = 85;
+pub const ELIBMAX: u16 = 86;
+pub const ELIBEXEC: u16 = 87;
+pub const EILSEQ: u16 = 88;
+pub const ENOSYS: u16 = 89;
+pub const ELOOP: u16 = 90;
+pub const ERESTART: u16 = 91;
+pub const ESTRPIPE: u16 = 92;
+pub const ENOTEMPTY: u16 = 93;
+pub const EUSERS: u16 = 94;
+pub const ENOTSOCK: u16 = 95;
+pub const EDESTADDRREQ: u16 = 96;
+pub const EMSGSIZE: u16 = 97;
+pub const EPROTOTYPE: u16 = 98;
+pub const ENOPROTOOPT: u16 = 99;
+pub const EPROTONOSUPPORT: u16 = 120;
+pub const ESOCKTNOSUPPORT: u16 = 121;
+pub const EOPNOTSUPP: u16 = 122;
+pub const EPFNOSUPPORT: u16 = 123;
+pub const EAFNOSUPPORT: u16 = 124;
+pub const EADDRINUSE: u16 = 125;
+pub const EADDRNOTAVAIL: u16 = 126;
+pub const ENETDOWN: u16 = 127;
+pub const ENETUNREACH: u16 = 128;
+pub const ENETRESET: u16 = 129;
+pub const ECONNABORTED: u16 = 130;
+pub const ECONNRESET: u16 = 131;
+pub const ENOBUFS: u16 = 132;
+pub const EISCONN: u16 = 133;
+pub const ENOTCONN: u16 = 134;
+pub const EUCLEAN: u16 = 135;
+pub const ENOTNAM: u16 = 137;
+pub const ENAVAIL: u16 = 138;
+pub const EISNAM: u16 = 139;
+pub const EREMOTEIO: u16 = 140;
+pub const EINIT: u16 = 141;
+pub const EREMDEV: u16 = 142;
+pub const ESHUTDOWN: u16 = 143;
+pub const ETOOMANYREFS: u16 = 144;
+pub const ETIMEDOUT: u16 = 145;
+pub const ECONNREFUSED: u16 = 146;
+pub const EHOSTDOWN: u16 = 147;
+pub const EHOSTUNREACH: u16 = 148;
+pub const EALREADY: u16 = 149;
+pub const EINPROGRESS: u16 = 150;
+pub const ESTALE: u16 = 151;
+pub const ECANCELED: u16 = 158;
+pub const ENOMEDIUM: u16 = 159;
+pub const EMEDIUMTYPE: u16 = 160;
+pub const ENOKEY: u16 = 161;
+pub const EKEYEXPIRED: u16 = 162;
+pub const EKEYREVOKED: u16 = 163;
+pub const EKEYREJECTED: u16 = 164;
+pub const EOWNERDEAD: u16 = 165;
+pub const ENOTRECOVERABLE: u16 = 166;
+pub const ERFKILL: u16 = 167;
+pub const EHWPOISON: u16 = 168;
+pub const EDQUOT: u16 = 1133;
+
+pub const TABLE: &[(u16, &str, &str)] = &[
+    (EPERM, "EPERM", "Operation not permitted"),
+    (ENOENT, "ENOENT", "No such file or directory"),
+    (ESRCH, "ESRCH", "No such process"),
+    (EINTR, "EINTR", "Interrupted system call"),
+    (EIO, "EIO", "I/O error"),
+    (ENXIO, "ENXIO", "No such device or address"),
+    (E2BIG, "E2BIG", "Argument list too long"),
+    (ENOEXEC, "ENOEXEC", "Exec format error"),
+    (EBADF, "EBADF", "Bad file number"),
+    (ECHILD, "ECHILD", "No child processes"),
+    (EAGAIN, "EAGAIN", "Try again"),
+    (EWOULDBLOCK, "EWOULDBLOCK", "Operation would block"),
+    (ENOMEM, "ENOMEM", "Out of memory"),
+    (EACCES, "EACCES", "Permission denied"),
+    (EFAULT, "EFAULT", "Bad address"),
+    (ENOTBLK, "ENOTBLK", "Block device required"),
+    (EBUSY, "EBUSY", "Device or resource busy"),
+    (EEXIST, "EEXIST", "File exists"),
+    (EXDEV, "EXDEV", "Cross-device link"),
+    (ENODEV, "ENODEV", "No such device"),
+    (ENOTDIR, "ENOTDIR", "Not a directory"),
+    (EISDIR, "EISDIR", "Is a directory"),
+    (EINVAL, "EINVAL", "Invalid argument"),
+    (ENFILE, "ENFILE", "File table overflow"),
+    (EMFILE, "EMFILE", "Too many open files"),
+    (ENOTTY, "ENOTTY", "Not a typewriter"),
+    (ETXTBSY, "ETXTBSY", "Text file busy"),
+    (EFBIG, "EFBIG", "File too large"),
+    (ENOSPC, "ENOSPC", "No space left on device"),
+    (ESPIPE, "ESPIPE", "Illegal seek"),
+    (EROFS, "EROFS", "Read-only file system"),
+    (EMLINK, "EMLINK", "Too many links"),
+    (EPIPE, "EPIPE", "Broken pipe"),
+    (EDOM, "EDOM", "Math argument out of domain of func"),
+    (ERANGE, "ERANGE", "Math result not representable"),
+    (ENOMSG, "ENOMSG", "No message of desired type"),
+    (EIDRM, "EIDRM", "Identifier removed"),
+    (ECHRNG, "ECHRNG", "Channel number out of range"),
+    (EL2NSYNC, "EL2NSYNC", "Level 2 not synchronized"),
+    (EL3HLT, "EL3HLT", "Level 3 halted"),
+    (EL3RST, "EL3RST", "Level 3 reset"),
+    (ELNRNG, "ELNRNG", "Link number out of range"),
+    (EUNATCH, "EUNATCH", "Protocol driver not attached"),
+    (ENOCSI, "ENOCSI", "No CSI structure available"),
+    (EL2HLT, "EL2HLT", "Level 2 halted"),
+    (EDEADLK, "EDEADLK", "Resource deadlock would occur"),
+    (ENOLCK, "ENOLCK", "No record locks available"),
+    (EBADE, "EBADE", "Invalid exchange"),
+    (EBADR, "EBADR", "Invalid request descriptor"),
+    (EXFULL, "EXFULL", "Exchange full"),
+    (ENOANO, "ENOANO", "No anode"),
+    (EBADRQC, "EBADRQC", "Invalid request code"),
+    (EBADSLT, "EBADSLT", "Invalid slot"),
+    (EDEADLOCK, "EDEADLOCK", "Resource deadlock would occur"),
+    (EBFONT, "EBFONT", "Bad font file format"),
+    (ENOSTR, "ENOSTR", "Device not a stream"),
+    (ENODATA, "ENODATA", "No data available"),
+    (ETIME, "ETIME", "Timer expired"),
+    (ENOSR, "ENOSR", "Out of streams resources"),
+    (ENONET, "ENONET", "Machine is not on the network"),
+    (ENOPKG, "ENOPKG", "Package not installed"),
+    (EREMOTE, "EREMOTE", "Object is remote"),
+    (ENOLINK, "ENOLINK", "Link has been severed"),
+    (EADV, "EADV", "Advertise error"),
+    (ESRMNT, "ESRMNT", "Srmount error"),
+    (ECOMM, "ECOMM", "Communication error on send"),
+    (EPROTO, "EPROTO", "Protocol error"),
+    (EDOTDOT, "EDOTDOT", "RFS specific error"),
+    (EMULTIHOP, "EMULTIHOP", "Multihop attempted"),
+    (EBADMSG, "EBADMSG", "Not a data message"),
+    (ENAMETOOLONG, "ENAMETOOLONG", "File name too long"),
+    (EOVERFLOW, "EOVERFLOW", "Value too large for defined data type"),
+    (ENOTUNIQ, "ENOTUNIQ", "Name not unique on network"),
+    (EBADFD, "EBADFD", "File descriptor in bad state"),
+    (EREMCHG, "EREMCHG", "Remote address changed"),
+    (ELIBACC, "ELIBACC", "Can not access a needed shared library"),
+    (ELIBBAD, "ELIBBAD", "Accessing a corrupted shared library"),
+    (ELIBSCN, "ELIBSCN", ".lib section in a.out corrupted"),
+    (ELIBMAX, "ELIBMAX", "Attempting to link in too many shared libraries"),
+    (ELIBEXEC, "ELIBEXEC", "Cannot exec a shared library directly"),
+    (EILSEQ, "EILSEQ", "Illegal byte sequence"),
+    (ENOSYS, "ENOSYS", "Invalid system call number"),
+    (ELOOP, "ELOOP", "Too many symbolic links encountered"),
+    (ERESTART, "ERESTART", "Interrupted system call should be restarted"),
+    (ESTRPIPE, "ESTRPIPE", "Streams pipe error"),
+    (ENOTEMPTY, "ENOTEMPTY", "Directory not empty"),
+    (EUSERS, "EUSERS", "Too many users"),
+    (ENOTSOCK, "ENOTSOCK", "Socket operation on non-socket"),
+    (EDESTADDRREQ, "EDESTADDRREQ", "Destination address required"),
+    (EMSGSIZE, "EMSGSIZE", "Message too long"),
+    (EPROTOTYPE, "EPROTOTYPE", "Protocol wrong type for socket"),
+    (ENOPROTOOPT, "ENOPROTOOPT", "Protocol not available"),
+    (EPROTONOSUPPORT, "EPROTONOSUPPORT", "Protocol not supported"),
+    (ESOCKTNOSUPPORT, "ESOCKTNOSUPPORT", "Socket type not supported"),
+    (EOPNOTSUPP, "EOPNOTSUPP", "Operation not supported on transport endpoint"),
+    (EPFNOSUPPORT, "EPFNOSUPPORT", "Protocol family not supported"),
+    (EAFNOSUPPORT, "EAFNOSUPPORT", "Address family not supported by protocol"),
+    (EADDRINUSE, "EADDRINUSE", "Address already in use"),
+    (EADDRNOTAVAIL, "EADDRNOTAVAIL", "Cannot assign requested address"),
+    (ENETDOWN, "ENETDOWN", "Network is down"),
+    (ENETUNREACH, "ENETUNREACH", "Network is unreachable"),
+    (ENETRESET, "ENETRESET", "Network dropped connection because of reset"),
+    (ECONNABORTED, "ECONNABORTED", "Software caused connection abort"),
+    (ECONNRESET, "ECONNRESET", "Connection reset by peer"),
+    (ENOBUFS, "ENOBUFS", "No buffer space available"),
+    (EISCONN, "EISCONN", "Transport endpoint is already connected"),
+    (ENOTCONN, "ENOTCONN", "Transport endpoint is not connected"),
+    (EUCLEAN, "EUCLEAN", "Structure needs cleaning"),
+    (ENOTNAM, "ENOTNAM", "Not a XENIX named type file"),
+    (ENAVAIL, "ENAVAIL", "No XENIX semaphores available"),
+    (EISNAM, "EISNAM", "Is a named type file"),
+    (EREMOTEIO, "EREMOTEIO", "Remote I/O error"),
+    (EINIT, "EINIT", "Reserved"),
+    (EREMDEV, "EREMDEV", "Error 142"),
+    (ESHUTDOWN, "ESHUTDOWN", "Cannot send after transport endpoint shutdown"),
+    (ETOOMANYREFS, "ETOOMANYREFS", "Too many references: cannot splice"),
+    (ETIMEDOUT, "ETIMEDOUT", "Connection timed out"),
+    (ECONNREFUSED, "ECONNREFUSED", "Connection refused"),
+    (EHOSTDOWN, "EHOSTDOWN", "Host is down"),
+    (EHOSTUNREACH, "EHOSTUNREACH", "No route to host"),
+    (EALREADY, "EALREADY", "Operation already in progress"),
+    (EINPROGRESS, "EINPROGRESS", "Operation now in progress"),
+    (ESTALE, "ESTALE", "Stale file handle"),
+    (ECANCELED, "ECANCELED", "Operation Canceled"),
+    (ENOMEDIUM, "ENOMEDIUM", "No medium found"),
+    (EMEDIUMTYPE, "EMEDIUMTYPE", "Wrong medium type"),
+    (ENOKEY, "ENOKEY", "Required key not available"),
+    (EKEYEXPIRED, "EKEYEXPIRED", "Key has expired"),
+    (EKEYREVOKED, "EKEYREVOKED", "Key has been revoked"),
+    (EKEYREJECTED, "EKEYREJECTED", "Key was rejected by service"),
+    (EOWNERDEAD, "EOWNERDEAD", "Owner died"),
+    (ENOTRECOVERABLE, "ENOTRECOVERABLE", "State not recoverable"),
+    (ERFKILL, "ERFKILL", "Operation not possible due to RF-kill"),
+    (EHWPOISON, "EHWPOISON", "Memory page has hardware error"),
+    (EDQUOT, "EDQUOT", "Quota exceeded"),
+];
@@ -0,0 +1,434 @@
+//! Error Numbers
+//!
+//! The linux kernel returns error information as an integer code between 1
+//! and 4095. Each code has an associated symbolic name (e.g., `ENOENT`) and a
+//! short human-readable description. Most architectures use the same
+//! numbering, defined by the generic kernel headers. However, a few
+//! architectures (alpha, mips, parisc, sparc) inherited their numbering from
+//! other operating systems and only share the base error numbers (1 to 34)
+//! with all other architectures.
+//!
+//! This module provides the `Errno` type, which wraps a valid error number,
+//! as well as symbolic constants for all error numbers of the native
+//! architecture. Furthermore, the raw tables of the different numbering
+//! schemes are always available as sub-modules, regardless of the target
+//! architecture. Each of them provides the error numbers as `u16` constants,
+//! as well as a `TABLE` listing the number, name, and description of each
+//! error. Aliases (e.g., `EWOULDBLOCK` for `EAGAIN`) are listed after the
+//! canonical name of each number.
+//!
+//! Note that the tables for alpha and parisc are not provided, since no
+//! backend for these architectures exists. The generator supports them, so
+//! they can be added when needed.
+
+pub mod generic;
+pub mod mips;
+pub mod sparc;
+
+// Raw error numbers of the native architecture. All supported architectures
+// use the generic numbering, but we select the architecture-specific tables
+// here to be prepared for other backends.
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+use mips as native;
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+use sparc as native;
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+use generic as native;
+
+/// Maximum Error Number
+///
+/// The kernel reserves the top 4095 values of the native integer range for
+/// error returns. Hence, this is the highest error number that can be
+/// returned by a system call.
+pub const MAX: u16 = 4095;
+
+/// Error Number
+///
+/// This type wraps a valid error number. That is, any value between 1 and
+/// `MAX`. The type is layout-compatible with `u16`, and `Option<Errno>` has
+/// the same size as `Errno`.
+///
+/// Symbolic names and descriptions are looked up in the table of the native
+/// architecture. Error numbers unknown to this table are still valid, since
+/// the kernel might gain new error numbers at any time. Their name and
+/// description are simply not available.
+///
+/// With the `std` feature enabled, an error number can be converted into
+/// `std::io::Error`. Use `to_i32()` to get the value commonly used by C
+/// standard libraries.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Errno(core::num::NonZeroU16);
+
+impl Errno {
+    /// Create a new error number
+    ///
+    /// Return `None` if `v` is 0 or greater than `MAX`.
+    pub const fn new(v: u16) -> Option<Errno> {
+        if v > MAX {
+            return None;
+        }
+
+        match core::num::NonZeroU16::new(v) {
+            Some(v) => Some(Errno(v)),
+            None => None,
+        }
+    }
+
+    /// Create a new error number unchecked
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `v` is between 1 and `MAX`.
+    pub const unsafe fn new_unchecked(v: u16) -> Errno {
+        Errno(core::num::NonZeroU16::new_unchecked(v))
+    }
+
+    /// Look up an error number by name
+    ///
+    /// Return the error number with the symbolic name `name` (e.g.,
+    /// `"ENOENT"`) in the table of the native architecture, or `None` if
+    /// there is no such error number. Aliases are supported as well.
+    pub fn from_name(name: &str) -> Option<Errno> {
+        native::TABLE
+            .iter()
+            .find(|v| v.1 == name)
+            .and_then(|v| Errno::new(v.0))
+    }
+
+    /// Return the raw error number
+    pub const fn get(self) -> u16 {
+        self.0.get()
+    }
+
+    /// Return the error number as `i32`
+    ///
+    /// This is the representation commonly used by C standard libraries,
+    /// including the `errno` variable.
+    pub const fn to_i32(self) -> i32 {
+        self.get() as i32
+    }
+
+    /// Return the symbolic name
+    ///
+    /// Return the canonical symbolic name of this error number (e.g.,
+    /// `"ENOENT"`), or `None` if the error number is unknown.
+    pub fn name(self) -> Option<&'static str> {
+        native::TABLE
+            .iter()
+            .find(|v| v.0 == self.get())
+            .map(|v| v.1)
+    }
+
+    /// Return the description
+    ///
+    /// Return the human-readable description of this error number as used
+    /// by the kernel headers (e.g., `"No such file or directory"`), or `None`
+    /// if the error number is unknown.
+    pub fn description(self) -> Option<&'static str> {
+        native::TABLE
+            .iter()
+            .find(|v| v.0 == self.get())
+            .map(|v| v.2)
+    }
+
+    /// Convert from `std::io::Error`
+    ///
+    /// Return the error number of an I/O error, or `None` if it does not
+    /// carry a valid error number.
+    #[cfg(feature = "std")]
+    pub fn from_io_error(e: &std::io::Error) -> Option<Errno> {
+        e.raw_os_error()
+            .and_then(|v| u16::try_from(v).ok())
+            .and_then(Errno::new)
+    }
+}
+
+impl core::fmt::Debug for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+            Some(v) => write!(f, "Errno({})", v),
+            None => write!(f, "Errno({})", self.get()),
+        }
+    }
+}
+
+impl core::fmt::Display for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.description() {
+            Some(v) => write!(f, "{} (os error {})", v, self.get()),
+            None => write!(f, "Unknown error (os error {})", self.get()),
+        }
+    }
+}
+
+impl From<Errno> for u16 {
+    fn from(v: Errno) -> Self {
+        v.get()
+    }
+}
+
+impl From<Errno> for i32 {
+    fn from(v: Errno) -> Self {
+        v.to_i32()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Errno> for std::io::Error {
+    fn from(v: Errno) -> Self {
+        std::io::Error::from_raw_os_error(v.to_i32())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Errno {
+}
+
+macro_rules! errno_constants {
+    ($($name:ident,)*) => {
+        $(
+            pub const $name: Errno = unsafe { Errno::new_unchecked(native::$name) };
+        )*
+    };
+}
+
+// Typed constants for all error numbers shared by all architectures. The
+// architecture-specific extras are available via the raw tables.
+errno_constants! {
+    EPERM,
+    ENOENT,
+    ESRCH,
+    EINTR,
+    EIO,
+    ENXIO,
+    E2BIG,
+    ENOEXEC,
+    EBADF,
+    ECHILD,
+    EAGAIN,
+    ENOMEM,
+    EACCES,
+    EFAULT,
+    ENOTBLK,
+    EBUSY,
+    EEXIST,
+    EXDEV,
+    ENODEV,
+    ENOTDIR,
+    EISDIR,
+    EINVAL,
+    ENFILE,
+    EMFILE,
+    ENOTTY,
+    ETXTBSY,
+    EFBIG,
+    ENOSPC,
+    ESPIPE,
+    EROFS,
+    EMLINK,
+    EPIPE,
+    EDOM,
+    ERANGE,
+    EDEADLK,
+    ENAMETOOLONG,
+    ENOLCK,
+    ENOSYS,
+    ENOTEMPTY,
+    ELOOP,
+    EWOULDBLOCK,
+    ENOMSG,
+    EIDRM,
+    ECHRNG,
+    EL2NSYNC,
+    EL3HLT,
+    EL3RST,
+    ELNRNG,
+    EUNATCH,
+    ENOCSI,
+    EL2HLT,
+    EBADE,
+    EBADR,
+    EXFULL,
+    ENOANO,
+    EBADRQC,
+    EBADSLT,
+    EDEADLOCK,
+    EBFONT,
+    ENOSTR,
+    ENODATA,
+    ETIME,
+    ENOSR,
+    ENONET,
+    ENOPKG,
+    EREMOTE,
+    ENOLINK,
+    EADV,
+    ESRMNT,
+    ECOMM,
+    EPROTO,
+    EMULTIHOP,
+    EDOTDOT,
+    EBADMSG,
+    EOVERFLOW,
+    ENOTUNIQ,
+    EBADFD,
+    EREMCHG,
+    ELIBACC,
+    ELIBBAD,
+    ELIBSCN,
+    ELIBMAX,
+    ELIBEXEC,
+    EILSEQ,
+    ERESTART,
+    ESTRPIPE,
+    EUSERS,
+    ENOTSOCK,
+    EDESTADDRREQ,
+    EMSGSIZE,
+    EPROTOTYPE,
+    ENOPROTOOPT,
+    EPROTONOSUPPORT,
+    ESOCKTNOSUPPORT,
+    EOPNOTSUPP,
+    EPFNOSUPPORT,
+    EAFNOSUPPORT,
+    EADDRINUSE,
+    EADDRNOTAVAIL,
+    ENETDOWN,
+    ENETUNREACH,
+    ENETRESET,
+    ECONNABORTED,
+    ECONNRESET,
+    ENOBUFS,
+    EISCONN,
+    ENOTCONN,
+    ESHUTDOWN,
+    ETOOMANYREFS,
+    ETIMEDOUT,
+    ECONNREFUSED,
+    EHOSTDOWN,
+    EHOSTUNREACH,
+    EALREADY,
+    EINPROGRESS,
+    ESTALE,
+    EUCLEAN,
+    ENOTNAM,
+    ENAVAIL,
+    EISNAM,
+    EREMOTEIO,
+    EDQUOT,
+    ENOMEDIUM,
+    EMEDIUMTYPE,
+    ECANCELED,
+    ENOKEY,
+    EKEYEXPIRED,
+    EKEYREVOKED,
+    EKEYREJECTED,
+    EOWNERDEAD,
+    ENOTRECOVERABLE,
+    ERFKILL,
+    EHWPOISON,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn errno_check() {
+        //
+        // Verify the range checks of `Errno` and its basic accessors.
+        //
+
+        assert_eq!(core::mem::size_of::<Errno>(), 2);
+        assert_eq!(core::mem::size_of::<Option<Errno>>(), 2);
+
+        assert!(Errno::new(0).is_none());
+        assert!(Errno::new(MAX + 1).is_none());
+        assert_eq!(Errno::new(1), Some(EPERM));
+        assert_eq!(Errno::new(MAX).unwrap().get(), MAX);
+
+        assert_eq!(ENOENT.get(), 2);
+        assert_eq!(ENOENT.to_i32(), 2);
+        assert_eq!(u16::from(EINTR), 4);
+        assert_eq!(i32::from(EINTR), 4);
+        assert_eq!(EWOULDBLOCK, EAGAIN);
+        assert_eq!(EDEADLOCK, EDEADLK);
+    }
+
+    #[test]
+    fn name_check() {
+        //
+        // Verify name and description lookups, including aliases and
+        // unknown error numbers.
+        //
+
+        assert_eq!(ENOENT.name(), Some("ENOENT"));
+        assert_eq!(ENOENT.description(), Some("No such file or directory"));
+        assert_eq!(EWOULDBLOCK.name(), Some("EAGAIN"));
+        assert_eq!(Errno::from_name("ENOENT"), Some(ENOENT));
+        assert_eq!(Errno::from_name("EWOULDBLOCK"), Some(EAGAIN));
+        assert_eq!(Errno::from_name("EFOOBAR"), None);
+
+        let e = Errno::new(MAX).unwrap();
+        assert_eq!(e.name(), None);
+        assert_eq!(e.description(), None);
+
+        assert_eq!(format!("{:?}", ENOENT), "Errno(ENOENT)");
+        assert_eq!(format!("{:?}", e), "Errno(4095)");
+        assert_eq!(
+            format!("{}", ENOENT),
+            "No such file or directory (os error 2)",
+        );
+        assert_eq!(format!("{}", e), "Unknown error (os error 4095)");
+    }
+
+    #[test]
+    fn table_check() {
+        //
+        // Verify all tables are consistent: all numbers are valid, the base
+        // error numbers are shared, and every number has a canonical entry
+        // before any of its aliases.
+        //
+
+        for table in [generic::TABLE, mips::TABLE, sparc::TABLE] {
+            for (i, v) in table.iter().enumerate() {
+                assert!(Errno::new(v.0).is_some());
+                assert!(!v.2.is_empty());
+                assert!(table[..i].iter().all(|w| w.1 != v.1));
+                assert!(table[..i].iter().all(|w| w.0 <= v.0));
+            }
+
+            for v in &generic::TABLE[..34] {
+                assert!(table.contains(v));
+            }
+        }
+
+        assert_eq!(mips::EDQUOT, 1133);
+        assert_eq!(mips::EINIT, 141);
+        assert_eq!(sparc::EDQUOT, 69);
+        assert_eq!(sparc::ERREMOTE, 81);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_check() {
+        //
+        // Verify conversions from and to `std::io::Error`.
+        //
+
+        let e = std::io::Error::from(ENOENT);
+        assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+        assert_eq!(e.raw_os_error(), Some(2));
+        assert_eq!(Errno::from_io_error(&e), Some(ENOENT));
+
+        let e = std::io::Error::from(std::io::ErrorKind::Other);
+        assert_eq!(Errno::from_io_error(&e), None);
+    }
+}
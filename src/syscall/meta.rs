@@ -0,0 +1,163 @@
+//! System Call Metadata
+//!
+//! This module provides machine-readable information on all system calls of
+//! an architecture. For each system call, the name, the number, and the kinds
+//! of its argument registers are listed. This allows mapping system call
+//! numbers back to their names, as is needed by tracers, seccomp filters, or
+//! audit decoders.
+//!
+//! The tables are generated from the system call tables and prototypes of the
+//! linux kernel, and are available as `arch::<id>::meta::TABLE` for each
+//! architecture. They are sorted by system call number. The functions of this
+//! module operate on the table of the native architecture.
+
+use super::arch;
+
+/// Argument Kind
+///
+/// This describes how a single argument register of a system call is
+/// interpreted by the kernel. Note that this describes registers, rather
+/// than arguments of the C prototype. On 32-bit architectures, 64-bit
+/// arguments are split across two registers, and might be preceded by an
+/// unused padding register to align the register pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Arg {
+    /// Plain integer value.
+    Value,
+    /// File descriptor.
+    Fd,
+    /// Pointer into the address space of the caller.
+    Ptr,
+    /// Flag set or option selector.
+    Flags,
+    /// Low 32 bits of a 64-bit value.
+    Lo64,
+    /// High 32 bits of a 64-bit value.
+    Hi64,
+    /// Unused register for alignment of a following 64-bit value.
+    Pad,
+}
+
+/// System Call Description
+///
+/// This describes a single system call of an architecture. `args` lists the
+/// kinds of all argument registers used by the system call. It is `None` if
+/// the system call is not implemented by the kernel, or its prototype is not
+/// known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Syscall {
+    pub name: &'static str,
+    pub nr: usize,
+    pub args: Option<&'static [Arg]>,
+}
+
+impl Syscall {
+    /// Number of Argument Registers
+    ///
+    /// Return the number of argument registers used by this system call, or
+    /// `None` if unknown.
+    pub fn n_args(&self) -> Option<usize> {
+        self.args.map(|v| v.len())
+    }
+}
+
+/// Find System Call by Number
+///
+/// Search `table` for the system call with number `nr`. The table must be
+/// sorted by number, as all generated tables are.
+pub fn by_nr(table: &'static [Syscall], nr: usize) -> Option<&'static Syscall> {
+    table
+        .binary_search_by_key(&nr, |v| v.nr)
+        .ok()
+        .map(|v| &table[v])
+}
+
+/// Find System Call by Name
+///
+/// Search `table` for the system call called `name`. The name is given
+/// without any `sys_` prefix, exactly as used by the kernel system call
+/// tables (e.g., `"read"` or `"_llseek"`).
+pub fn by_name(table: &'static [Syscall], name: &str) -> Option<&'static Syscall> {
+    table.iter().find(|v| v.name == name)
+}
+
+/// Map System Call Number to Name
+///
+/// Return the name of the system call with number `nr` on the native
+/// architecture, or `None` if no such system call exists.
+pub fn nr_to_name(nr: usize) -> Option<&'static str> {
+    by_nr(arch::native::meta::TABLE, nr).map(|v| v.name)
+}
+
+/// Map System Call Name to Number
+///
+/// Return the number of the system call called `name` on the native
+/// architecture, or `None` if no such system call exists.
+pub fn name_to_nr(name: &str) -> Option<usize> {
+    by_name(arch::native::meta::TABLE, name).map(|v| v.nr)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify that all tables are sorted, and that they match the system call
+    // numbers of their architecture.
+    #[test]
+    fn table_check() {
+        let tables = [
+            arch::aarch64::meta::TABLE,
+            arch::arm::meta::TABLE,
+            arch::riscv64::meta::TABLE,
+            arch::x32::meta::TABLE,
+            arch::x86::meta::TABLE,
+            arch::x86_64::meta::TABLE,
+        ];
+
+        for table in tables.iter() {
+            assert!(table.windows(2).all(|v| v[0].nr < v[1].nr));
+            assert!(table.iter().all(|v| v.n_args().unwrap_or(0) <= 6));
+        }
+
+        assert_eq!(by_name(arch::aarch64::meta::TABLE, "openat").unwrap().nr, arch::aarch64::nr::OPENAT);
+        assert_eq!(by_name(arch::arm::meta::TABLE, "arm_set_tls").unwrap().nr, arch::arm::nr::ARM_SET_TLS);
+        assert_eq!(by_name(arch::riscv64::meta::TABLE, "clone3").unwrap().nr, arch::riscv64::nr::CLONE3);
+        assert_eq!(by_name(arch::x32::meta::TABLE, "execve").unwrap().nr, arch::x32::nr::EXECVE);
+        assert_eq!(by_name(arch::x86::meta::TABLE, "_llseek").unwrap().nr, arch::x86::nr::_LLSEEK);
+        assert_eq!(by_name(arch::x86_64::meta::TABLE, "getpid").unwrap().nr, arch::x86_64::nr::GETPID);
+    }
+
+    // Verify the argument kinds of some well-known system calls, including
+    // the splitting of 64-bit arguments on 32-bit architectures.
+    #[test]
+    fn args_check() {
+        use Arg::*;
+
+        let read = by_nr(arch::x86_64::meta::TABLE, arch::x86_64::nr::READ).unwrap();
+        assert_eq!(read.name, "read");
+        assert_eq!(read.args, Some(&[Fd, Ptr, Value][..]));
+        assert_eq!(read.n_args(), Some(3));
+
+        let v = by_nr(arch::x86_64::meta::TABLE, arch::x86_64::nr::PREAD64).unwrap();
+        assert_eq!(v.args, Some(&[Fd, Ptr, Value, Value][..]));
+        let v = by_nr(arch::x86::meta::TABLE, arch::x86::nr::PREAD64).unwrap();
+        assert_eq!(v.args, Some(&[Fd, Ptr, Value, Lo64, Hi64][..]));
+        let v = by_nr(arch::arm::meta::TABLE, arch::arm::nr::PREAD64).unwrap();
+        assert_eq!(v.args, Some(&[Fd, Ptr, Value, Pad, Lo64, Hi64][..]));
+
+        let v = by_nr(arch::x86_64::meta::TABLE, arch::x86_64::nr::GETPID).unwrap();
+        assert_eq!(v.n_args(), Some(0));
+        let v = by_name(arch::x86_64::meta::TABLE, "tuxcall").unwrap();
+        assert_eq!(v.args, None);
+    }
+
+    // Verify the lookup helpers of the native architecture.
+    #[test]
+    fn native_check() {
+        assert_eq!(nr_to_name(arch::native::nr::GETPID), Some("getpid"));
+        assert_eq!(name_to_nr("getpid"), Some(arch::native::nr::GETPID));
+        assert_eq!(nr_to_name(usize::MAX), None);
+        assert_eq!(name_to_nr("sys_getpid"), None);
+        assert_eq!(name_to_nr(""), None);
+    }
+}
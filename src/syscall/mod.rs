@@ -7,4 +7,7 @@
 
 pub mod api;
 pub mod arch;
+pub mod errno;
+pub mod meta;
 pub mod raw;
+pub mod vdso;
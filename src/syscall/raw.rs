@@ -7,6 +7,12 @@
 //! For performance reasons, you might want to prefer the matching call,
 //! though.
 //!
+//! Additionally, `syscallN_readonly()` and `syscallN_nomem()` variants are
+//! provided for system calls that do not write to memory of the calling
+//! process, or do not access it at all, respectively. They allow the compiler
+//! to keep values in registers across the system call, which can be useful on
+//! hot paths.
+//!
 //! This implementation is optimized to allow inlining of the system-call
 //! invocation into the calling function. That is, when xLTO is used, the
 //! syscall setup and instruction will be inlined into the caller, and thus
@@ -118,6 +124,18 @@ impl Retval {
             Ok(unsafe { self.unwrap_unchecked() })
         }
     }
+
+    /// Convert into a Result with typed error-code
+    ///
+    /// This works like `to_result()` but wraps the error-code in
+    /// `Errno`, which is what the higher-level system call wrappers return.
+    pub fn to_errno_result(self) -> Result<usize, super::errno::Errno> {
+        if self.is_error() {
+            Err(unsafe { super::errno::Errno::new_unchecked(self.error_unchecked()) })
+        } else {
+            Ok(unsafe { self.unwrap_unchecked() })
+        }
+    }
 }
 
 /// Invoke System Call With 0 Arguments
@@ -302,6 +320,419 @@ pub unsafe fn syscall6(
     )
 }
 
+/// Invoke Read-Only System Call With 0 Arguments
+///
+/// This works like `syscall0()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall0_readonly(
+    nr: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall0_readonly(
+            nr,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 1 Argument
+///
+/// This works like `syscall1()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall1_readonly(
+    nr: usize,
+    arg0: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall1_readonly(
+            nr,
+            arg0,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 2 Arguments
+///
+/// This works like `syscall2()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall2_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall2_readonly(
+            nr,
+            arg0,
+            arg1,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 3 Arguments
+///
+/// This works like `syscall3()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall3_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall3_readonly(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 4 Arguments
+///
+/// This works like `syscall4()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall4_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall4_readonly(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 5 Arguments
+///
+/// This works like `syscall5()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall5_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall5_readonly(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+        )
+    )
+}
+
+/// Invoke Read-Only System Call With 6 Arguments
+///
+/// This works like `syscall6()`, but tells the compiler that the system call
+/// does not write to any memory of the calling process. This allows the
+/// compiler to keep values in registers across the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must not write to any memory of the calling process.
+///   This includes memory passed via pointer arguments.
+pub unsafe fn syscall6_readonly(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall6_readonly(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 0 Arguments
+///
+/// This works like `syscall0()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall0_nomem(
+    nr: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall0_nomem(
+            nr,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 1 Argument
+///
+/// This works like `syscall1()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall1_nomem(
+    nr: usize,
+    arg0: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall1_nomem(
+            nr,
+            arg0,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 2 Arguments
+///
+/// This works like `syscall2()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall2_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall2_nomem(
+            nr,
+            arg0,
+            arg1,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 3 Arguments
+///
+/// This works like `syscall3()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall3_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall3_nomem(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 4 Arguments
+///
+/// This works like `syscall4()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall4_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall4_nomem(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 5 Arguments
+///
+/// This works like `syscall5()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall5_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall5_nomem(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+        )
+    )
+}
+
+/// Invoke Memory-Free System Call With 6 Arguments
+///
+/// This works like `syscall6()`, but tells the compiler that the system call
+/// neither reads nor writes any memory of the calling process (e.g.,
+/// `getpid()`). This allows the compiler to keep values in registers across
+/// the system call.
+///
+/// # Safety
+///
+/// * System calls can have arbitrary side-effects. It is the responsibility of
+///   the caller to consider all effects of a system call and take required
+///   precautions.
+/// * The system call must neither read nor write any memory of the calling
+///   process. This includes memory passed via pointer arguments.
+pub unsafe fn syscall6_nomem(
+    nr: usize,
+    arg0: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Retval {
+    Retval::from_usize(
+        super::arch::native::syscall::syscall6_nomem(
+            nr,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+        )
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -616,27 +1047,24 @@ mod test {
         // `S_IFLNK` flag is set on the result.
         //
 
-        let mut b0: [u32; 1024] = [0; 1024];
+        let p0 = "/proc/self/fd/0\x00";
+        let mut b0 = crate::syscall::api::Statx::default();
 
         let r0 = unsafe {
             syscall5(
                 crate::syscall::arch::native::nr::STATX,
                 core::usize::MAX - 100 + 1, // AT_FDCWD
-                "/proc/self/fd/0".as_ptr() as usize,
+                p0.as_ptr() as usize,
                 0x100, // AT_SYMLINK_NOFOLLOW
                 0x1,   // STATX_TYPE
-                b0.as_mut_ptr() as usize,
+                &mut b0 as *mut _ as usize,
             )
             .unwrap()
         };
         assert_eq!(r0, 0);
-        assert_ne!(b0[0] & 0x1, 0);
+        assert_ne!(b0.mask & 0x1, 0);
         assert_eq!(
-            unsafe {
-                core::ptr::read_unaligned(
-                    (b0.as_ptr() as *const u16).offset(14)
-                )
-            } & 0o170000, // S_IFMT
+            b0.mode & 0o170000, // S_IFMT
             0o120000, // S_IFLNK
         );
     }
@@ -746,4 +1174,124 @@ mod test {
         };
         assert_eq!(r0, 0);
     }
+
+    #[test]
+    fn readonly_check() {
+        //
+        // Test validity of `syscallN_readonly()`.
+        //
+        // Tested syscall: GETPID / WRITE / FCNTL
+        //
+        // We verify the read-only entries give the same results as the
+        // regular entries. Superfluous arguments are ignored by the kernel.
+        // Furthermore, we write to a pipe, which only reads from our memory,
+        // and verify the data arrives.
+        //
+
+        let nr = crate::syscall::arch::native::nr::GETPID;
+        let pid = unsafe { syscall0(nr) };
+        assert_eq!(pid.unwrap() as u32, std::process::id());
+        unsafe {
+            assert_eq!(syscall0_readonly(nr), pid);
+            assert_eq!(syscall1_readonly(nr, 1), pid);
+            assert_eq!(syscall2_readonly(nr, 1, 2), pid);
+            assert_eq!(syscall3_readonly(nr, 1, 2, 3), pid);
+            assert_eq!(syscall4_readonly(nr, 1, 2, 3, 4), pid);
+            assert_eq!(syscall5_readonly(nr, 1, 2, 3, 4, 5), pid);
+            assert_eq!(syscall6_readonly(nr, 1, 2, 3, 4, 5, 6), pid);
+        }
+
+        let mut p0: [u32; 2] = [0, 0];
+        let mut b0: [u8; 16] = [0; 16];
+
+        let r0 = unsafe {
+            syscall2(
+                crate::syscall::arch::native::nr::PIPE2,
+                p0.as_mut_ptr() as usize,
+                0,
+            ).unwrap()
+        };
+        assert_eq!(r0, 0);
+
+        let r0 = unsafe {
+            syscall3_readonly(
+                crate::syscall::arch::native::nr::WRITE,
+                p0[1] as usize,
+                b"foobar".as_ptr() as usize,
+                6,
+            ).unwrap()
+        };
+        assert_eq!(r0, 6);
+
+        let r0 = unsafe {
+            syscall3(
+                crate::syscall::arch::native::nr::READ,
+                p0[0] as usize,
+                b0.as_mut_ptr() as usize,
+                b0.len(),
+            ).unwrap()
+        };
+        assert_eq!(r0, 6);
+        assert_eq!(&b0[..6], b"foobar");
+
+        let r0 = unsafe {
+            syscall2(
+                crate::syscall::arch::native::nr::FCNTL,
+                p0[0] as usize,
+                1, // F_GETFD
+            )
+        };
+        let r1 = unsafe {
+            syscall2_readonly(
+                crate::syscall::arch::native::nr::FCNTL,
+                p0[0] as usize,
+                1, // F_GETFD
+            )
+        };
+        assert_eq!(r0, r1);
+
+        unsafe {
+            syscall1(crate::syscall::arch::native::nr::CLOSE, p0[0] as usize).unwrap();
+            syscall1(crate::syscall::arch::native::nr::CLOSE, p0[1] as usize).unwrap();
+        }
+    }
+
+    #[test]
+    fn nomem_check() {
+        //
+        // Test validity of `syscallN_nomem()`.
+        //
+        // Tested syscall: GETPID / GETTID / GETPPID / CLOSE
+        //
+        // We verify the no-memory entries give the same results as the
+        // regular entries, including error returns. Superfluous arguments
+        // are ignored by the kernel.
+        //
+
+        let nrs = [
+            crate::syscall::arch::native::nr::GETPID,
+            crate::syscall::arch::native::nr::GETTID,
+            crate::syscall::arch::native::nr::GETPPID,
+        ];
+
+        for nr in nrs.iter().cloned() {
+            let r = unsafe { syscall0(nr) };
+            assert!(r.is_success());
+            unsafe {
+                assert_eq!(syscall0_nomem(nr), r);
+                assert_eq!(syscall1_nomem(nr, 1), r);
+                assert_eq!(syscall2_nomem(nr, 1, 2), r);
+                assert_eq!(syscall3_nomem(nr, 1, 2, 3), r);
+                assert_eq!(syscall4_nomem(nr, 1, 2, 3, 4), r);
+                assert_eq!(syscall5_nomem(nr, 1, 2, 3, 4, 5), r);
+                assert_eq!(syscall6_nomem(nr, 1, 2, 3, 4, 5, 6), r);
+            }
+        }
+
+        let nr = crate::syscall::arch::native::nr::CLOSE;
+        let r0 = unsafe { syscall1(nr, !0) };
+        let r1 = unsafe { syscall1_nomem(nr, !0) };
+        assert_eq!(r0.error(), 9); // EBADF
+        assert_eq!(r0, r1);
+    }
 }
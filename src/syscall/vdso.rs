@@ -0,0 +1,811 @@
+//! Virtual Dynamic Shared Object
+//!
+//! The linux kernel maps a small shared object into every process, called the
+//! vDSO. It provides user-space implementations of some system calls, which
+//! can be served without entering the kernel. Most prominently, these are the
+//! time-keeping functions, which just read the kernel clock-pages mapped
+//! alongside the vDSO.
+//!
+//! This module provides a minimal ELF parser to look up symbols in the vDSO,
+//! as well as typed accessors for the accelerated system calls. The accessors
+//! fall back to the real system calls via the `raw` module, if the vDSO or
+//! the respective symbol is not available.
+//!
+//! The vDSO is located via the `AT_SYSINFO_EHDR` entry of the auxiliary
+//! vector. Since this crate does not have access to the auxiliary vector
+//! passed to the process entry-point, it is read from `/proc/self/auxv` on
+//! first use. Freestanding applications can use `init()` to provide the
+//! address explicitly, and thus avoid any file-system access.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use super::errno::{self, Errno};
+
+/// Clock Identifier
+///
+/// Clocks are identified by a signed 32-bit integer. Positive values select
+/// one of the system clocks (see the `CLOCK_*` constants), negative values
+/// encode dynamic clocks like CPU-time clocks of other processes, or
+/// file-descriptor based clocks.
+pub type Clockid = i32;
+
+pub const CLOCK_REALTIME: Clockid = 0;
+pub const CLOCK_MONOTONIC: Clockid = 1;
+pub const CLOCK_PROCESS_CPUTIME_ID: Clockid = 2;
+pub const CLOCK_THREAD_CPUTIME_ID: Clockid = 3;
+pub const CLOCK_MONOTONIC_RAW: Clockid = 4;
+pub const CLOCK_REALTIME_COARSE: Clockid = 5;
+pub const CLOCK_MONOTONIC_COARSE: Clockid = 6;
+pub const CLOCK_BOOTTIME: Clockid = 7;
+pub const CLOCK_REALTIME_ALARM: Clockid = 8;
+pub const CLOCK_BOOTTIME_ALARM: Clockid = 9;
+pub const CLOCK_TAI: Clockid = 11;
+
+/// Kernel Long Integer
+///
+/// The kernel uses `long` for a lot of legacy interfaces. This matches the
+/// native word size on all architectures but `x32`, which uses 64-bit
+/// integers for the kernel interfaces.
+#[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
+pub type KernelLong = isize;
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+pub type KernelLong = i64;
+
+/// Time Specification
+///
+/// This matches `struct __kernel_timespec` of the linux kernel, which uses
+/// 64-bit integers on all architectures. On 32-bit architectures, the `*64`
+/// variants of the time-related system calls are used to operate on this
+/// type.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+/// Time Value
+///
+/// This matches `struct __kernel_old_timeval` of the linux kernel, as used
+/// by `gettimeofday(2)`. Note that on 32-bit architectures the seconds will
+/// overflow in 2038.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timeval {
+    pub tv_sec: KernelLong,
+    pub tv_usec: KernelLong,
+}
+
+// Native vDSO Symbols
+//
+// The vDSO symbols and their version differ between architectures. On 32-bit
+// architectures, we only use the 64-bit time variants, as the others are not
+// y2038-safe. Symbols not provided by an architecture are set to `None` and
+// always use the fallback.
+#[cfg(target_arch = "x86_64")]
+mod native {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = Some("__vdso_time");
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+#[cfg(target_arch = "x86")]
+mod native {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime64");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+#[cfg(target_arch = "arm")]
+mod native {
+    pub const VERSION: &str = "LINUX_2.6";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime64");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = None;
+}
+
+#[cfg(target_arch = "aarch64")]
+mod native {
+    pub const VERSION: &str = "LINUX_2.6.39";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__kernel_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__kernel_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = None;
+}
+
+#[cfg(target_arch = "riscv64")]
+mod native {
+    pub const VERSION: &str = "LINUX_4.15";
+    pub const CLOCK_GETTIME: Option<&str> = Some("__vdso_clock_gettime");
+    pub const GETTIMEOFDAY: Option<&str> = Some("__vdso_gettimeofday");
+    pub const TIME: Option<&str> = None;
+    pub const GETCPU: Option<&str> = Some("__vdso_getcpu");
+}
+
+// The system call used as fallback for `clock_gettime()`. 32-bit
+// architectures need the 64-bit time variant.
+#[cfg(any(target_arch = "arm", target_arch = "x86"))]
+const NR_CLOCK_GETTIME: usize = super::arch::native::nr::CLOCK_GETTIME64;
+#[cfg(not(any(target_arch = "arm", target_arch = "x86")))]
+const NR_CLOCK_GETTIME: usize = super::arch::native::nr::CLOCK_GETTIME;
+
+// ELF Definitions
+//
+// The vDSO always uses the ELF class native to the process, so we only need
+// the definitions for the native word size. Addresses and offsets are always
+// word sized, and thus can use `usize`.
+
+const EI_CLASS: usize = 4;
+#[cfg(target_pointer_width = "32")]
+const ELFCLASS: u8 = 1;
+#[cfg(target_pointer_width = "64")]
+const ELFCLASS: u8 = 2;
+
+const PT_LOAD: u32 = 1;
+const PT_DYNAMIC: u32 = 2;
+
+const DT_NULL: isize = 0;
+const DT_HASH: isize = 4;
+const DT_STRTAB: isize = 5;
+const DT_SYMTAB: isize = 6;
+const DT_GNU_HASH: isize = 0x6ffffef5;
+const DT_VERSYM: isize = 0x6ffffff0;
+const DT_VERDEF: isize = 0x6ffffffc;
+
+const STT_NOTYPE: u8 = 0;
+const STT_FUNC: u8 = 2;
+const STB_GLOBAL: u8 = 1;
+const STB_WEAK: u8 = 2;
+const SHN_UNDEF: u16 = 0;
+
+const VER_FLG_BASE: u16 = 0x1;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfEhdr {
+    e_ident: [u8; 16],
+    e_type: u16,
+    e_machine: u16,
+    e_version: u32,
+    e_entry: usize,
+    e_phoff: usize,
+    e_shoff: usize,
+    e_flags: u32,
+    e_ehsize: u16,
+    e_phentsize: u16,
+    e_phnum: u16,
+    e_shentsize: u16,
+    e_shnum: u16,
+    e_shstrndx: u16,
+}
+
+#[cfg(target_pointer_width = "32")]
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfPhdr {
+    p_type: u32,
+    p_offset: usize,
+    p_vaddr: usize,
+    p_paddr: usize,
+    p_filesz: usize,
+    p_memsz: usize,
+    p_flags: u32,
+    p_align: usize,
+}
+
+#[cfg(target_pointer_width = "64")]
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfPhdr {
+    p_type: u32,
+    p_flags: u32,
+    p_offset: usize,
+    p_vaddr: usize,
+    p_paddr: usize,
+    p_filesz: usize,
+    p_memsz: usize,
+    p_align: usize,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfDyn {
+    d_tag: isize,
+    d_val: usize,
+}
+
+#[cfg(target_pointer_width = "32")]
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfSym {
+    st_name: u32,
+    st_value: usize,
+    st_size: usize,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+}
+
+#[cfg(target_pointer_width = "64")]
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfSym {
+    st_name: u32,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+    st_value: usize,
+    st_size: usize,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfVerdef {
+    vd_version: u16,
+    vd_flags: u16,
+    vd_ndx: u16,
+    vd_cnt: u16,
+    vd_hash: u32,
+    vd_aux: u32,
+    vd_next: u32,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct ElfVerdaux {
+    vda_name: u32,
+    vda_next: u32,
+}
+
+/// Parsed vDSO
+///
+/// This represents a vDSO image mapped into the address space of the
+/// process. It contains the information necessary to look up symbols
+/// exported by the vDSO.
+///
+/// The vDSO is mapped by the kernel on process creation and is never unmapped
+/// for the lifetime of the process. Hence, this object is a plain copy of the
+/// relevant pointers into the image.
+#[derive(Clone, Copy, Debug)]
+pub struct Vdso {
+    bias: usize,
+    symtab: *const ElfSym,
+    strtab: *const u8,
+    nsyms: usize,
+    versym: *const u16,
+    verdef: *const ElfVerdef,
+}
+
+impl Vdso {
+    /// Parse vDSO Image
+    ///
+    /// Parse the vDSO image at the given address, which must be the value of
+    /// the `AT_SYSINFO_EHDR` entry of the auxiliary vector. If the image is
+    /// not a valid ELF image of the native class, or lacks a dynamic symbol
+    /// table, `None` is returned.
+    ///
+    /// # Safety
+    ///
+    /// `ehdr` must point to a mapped ELF image, which must stay mapped for
+    /// as long as the returned object is used.
+    pub unsafe fn from_ehdr(ehdr: *const u8) -> Option<Vdso> {
+        let eh = &*(ehdr as *const ElfEhdr);
+
+        if eh.e_ident[..4] != *b"\x7fELF" || eh.e_ident[EI_CLASS] != ELFCLASS {
+            return None;
+        }
+
+        // Find the load-bias and the dynamic section. The vDSO is prelinked
+        // at an arbitrary address, so we need to compute the offset to the
+        // address it was actually mapped at.
+        let mut bias = None;
+        let mut dynamic = None;
+        for i in 0..eh.e_phnum as usize {
+            let ph = &*(ehdr.add(eh.e_phoff + i * eh.e_phentsize as usize)
+                as *const ElfPhdr);
+
+            if ph.p_type == PT_LOAD && bias.is_none() {
+                bias = Some(
+                    (ehdr as usize)
+                        .wrapping_add(ph.p_offset)
+                        .wrapping_sub(ph.p_vaddr),
+                );
+            } else if ph.p_type == PT_DYNAMIC {
+                dynamic = Some(ehdr.add(ph.p_offset) as *const ElfDyn);
+            }
+        }
+        let bias = bias?;
+        let dynamic = dynamic?;
+
+        let mut hash: *const u32 = core::ptr::null();
+        let mut gnu_hash: *const u32 = core::ptr::null();
+        let mut vdso = Vdso {
+            bias,
+            symtab: core::ptr::null(),
+            strtab: core::ptr::null(),
+            nsyms: 0,
+            versym: core::ptr::null(),
+            verdef: core::ptr::null(),
+        };
+
+        let mut d = dynamic;
+        while (*d).d_tag != DT_NULL {
+            let p = bias.wrapping_add((*d).d_val);
+            match (*d).d_tag {
+                DT_HASH => hash = p as *const u32,
+                DT_GNU_HASH => gnu_hash = p as *const u32,
+                DT_STRTAB => vdso.strtab = p as *const u8,
+                DT_SYMTAB => vdso.symtab = p as *const ElfSym,
+                DT_VERSYM => vdso.versym = p as *const u16,
+                DT_VERDEF => vdso.verdef = p as *const ElfVerdef,
+                _ => {},
+            }
+            d = d.add(1);
+        }
+
+        if vdso.strtab.is_null() || vdso.symtab.is_null() {
+            return None;
+        }
+
+        // The ELF format does not store the number of dynamic symbols
+        // directly. Instead, we have to deduce it from the hash tables. The
+        // classic hash table stores it as number of chain entries. The GNU
+        // hash table requires walking the chain of the highest bucket.
+        if !hash.is_null() {
+            vdso.nsyms = *hash.add(1) as usize;
+        } else if !gnu_hash.is_null() {
+            let nbuckets = *gnu_hash as usize;
+            let symoffset = *gnu_hash.add(1) as usize;
+            let bloom_size = *gnu_hash.add(2) as usize;
+            let buckets = gnu_hash.add(4 + bloom_size * core::mem::size_of::<usize>() / 4);
+            let chain = buckets.add(nbuckets);
+
+            let mut max = 0;
+            for i in 0..nbuckets {
+                max = core::cmp::max(max, *buckets.add(i) as usize);
+            }
+
+            if max < symoffset {
+                vdso.nsyms = symoffset;
+            } else {
+                while *chain.add(max - symoffset) & 1 == 0 {
+                    max += 1;
+                }
+                vdso.nsyms = max + 1;
+            }
+        } else {
+            return None;
+        }
+
+        Some(vdso)
+    }
+
+    // Compare the nul-terminated string at offset `off` of the string table
+    // with `s`.
+    unsafe fn strtab_eq(&self, off: u32, s: &str) -> bool {
+        let p = self.strtab.add(off as usize);
+        for (i, b) in s.bytes().enumerate() {
+            if *p.add(i) != b {
+                return false;
+            }
+        }
+        *p.add(s.len()) == 0
+    }
+
+    // Check whether the symbol at index `idx` has version `version`. If the
+    // vDSO has no version information, any version matches.
+    unsafe fn version_eq(&self, idx: usize, version: &str) -> bool {
+        if self.versym.is_null() || self.verdef.is_null() {
+            return true;
+        }
+
+        let ver = *self.versym.add(idx) & 0x7fff;
+        let mut def = self.verdef;
+        loop {
+            if (*def).vd_flags & VER_FLG_BASE == 0 && (*def).vd_ndx & 0x7fff == ver {
+                let aux = &*((def as *const u8).add((*def).vd_aux as usize)
+                    as *const ElfVerdaux);
+                return self.strtab_eq(aux.vda_name, version);
+            }
+            if (*def).vd_next == 0 {
+                return false;
+            }
+            def = (def as *const u8).add((*def).vd_next as usize) as *const ElfVerdef;
+        }
+    }
+
+    /// Look up Symbol
+    ///
+    /// Look up the function or object `name` with version `version` in the
+    /// dynamic symbol table of the vDSO. If found, its address is returned.
+    /// Otherwise, `None` is returned.
+    pub fn lookup(&self, version: &str, name: &str) -> Option<usize> {
+        for i in 0..self.nsyms {
+            let sym = unsafe { &*self.symtab.add(i) };
+            let kind = sym.st_info & 0xf;
+            let bind = sym.st_info >> 4;
+
+            if (kind != STT_FUNC && kind != STT_NOTYPE)
+                || (bind != STB_GLOBAL && bind != STB_WEAK)
+                || sym.st_shndx == SHN_UNDEF
+            {
+                continue;
+            }
+
+            unsafe {
+                if self.strtab_eq(sym.st_name, name) && self.version_eq(i, version) {
+                    return Some(self.bias.wrapping_add(sym.st_value));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get Native vDSO
+    ///
+    /// Return the vDSO of the calling process. If not yet known, the vDSO is
+    /// located via `/proc/self/auxv`, unless `init()` was called before. If
+    /// the process has no vDSO, `None` is returned.
+    pub fn native() -> Option<Vdso> {
+        let mut ehdr = EHDR.load(Ordering::Relaxed);
+
+        if ehdr == UNKNOWN {
+            ehdr = auxv_sysinfo_ehdr().unwrap_or(ABSENT);
+            EHDR.store(ehdr, Ordering::Relaxed);
+        }
+
+        if ehdr == ABSENT {
+            None
+        } else {
+            unsafe { Vdso::from_ehdr(ehdr as *const u8) }
+        }
+    }
+}
+
+// Markers used in the address caches. Neither can be a valid address of the
+// vDSO or its symbols.
+const UNKNOWN: usize = 0;
+const ABSENT: usize = usize::MAX;
+
+// Cached address of the vDSO ELF header.
+static EHDR: AtomicUsize = AtomicUsize::new(UNKNOWN);
+
+// Cached Symbol
+//
+// This caches the address of a native vDSO symbol. It is resolved on first
+// use, and then cached for the lifetime of the process.
+struct Symbol {
+    name: Option<&'static str>,
+    addr: AtomicUsize,
+}
+
+impl Symbol {
+    const fn new(name: Option<&'static str>) -> Self {
+        Self {
+            name,
+            addr: AtomicUsize::new(UNKNOWN),
+        }
+    }
+
+    fn get(&self) -> Option<usize> {
+        let mut addr = self.addr.load(Ordering::Relaxed);
+
+        if addr == UNKNOWN {
+            addr = self.name
+                .and_then(|name| {
+                    Vdso::native().and_then(|v| v.lookup(native::VERSION, name))
+                })
+                .unwrap_or(ABSENT);
+            self.addr.store(addr, Ordering::Relaxed);
+        }
+
+        if addr == ABSENT {
+            None
+        } else {
+            Some(addr)
+        }
+    }
+}
+
+static SYM_CLOCK_GETTIME: Symbol = Symbol::new(native::CLOCK_GETTIME);
+static SYM_GETTIMEOFDAY: Symbol = Symbol::new(native::GETTIMEOFDAY);
+static SYM_TIME: Symbol = Symbol::new(native::TIME);
+static SYM_GETCPU: Symbol = Symbol::new(native::GETCPU);
+
+// Read `AT_SYSINFO_EHDR` from `/proc/self/auxv`.
+fn auxv_sysinfo_ehdr() -> Option<usize> {
+    const AT_FDCWD: isize = -100;
+    const AT_SYSINFO_EHDR: usize = 33;
+    const O_RDONLY: usize = 0;
+    const O_CLOEXEC: usize = 0o2000000;
+    const PATH: &[u8] = b"/proc/self/auxv\x00";
+
+    let mut buf = [0usize; 128];
+    let mut len = 0;
+    let size = core::mem::size_of_val(&buf);
+
+    let fd = unsafe {
+        super::raw::syscall4(
+            super::arch::native::nr::OPENAT,
+            AT_FDCWD as usize,
+            PATH.as_ptr() as usize,
+            O_RDONLY | O_CLOEXEC,
+            0,
+        ).to_result().ok()?
+    };
+
+    while len < size {
+        let r = unsafe {
+            super::raw::syscall3(
+                super::arch::native::nr::READ,
+                fd,
+                (buf.as_mut_ptr() as usize) + len,
+                size - len,
+            ).to_errno_result()
+        };
+        match r {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e == errno::EINTR => continue,
+            Err(_) => break,
+        }
+    }
+
+    unsafe {
+        super::raw::syscall1(super::arch::native::nr::CLOSE, fd);
+    }
+
+    buf[..len / core::mem::size_of::<usize>()]
+        .chunks_exact(2)
+        .find(|v| v[0] == AT_SYSINFO_EHDR)
+        .map(|v| v[1])
+        .filter(|v| *v != 0)
+}
+
+/// Initialize vDSO
+///
+/// Provide the address of the vDSO, as passed via `AT_SYSINFO_EHDR` in the
+/// auxiliary vector. This avoids reading `/proc/self/auxv` on first use. If
+/// `ehdr` is null, the process is assumed to have no vDSO and all accessors
+/// will use their fallbacks.
+///
+/// This has no effect on symbols that were already resolved. Hence, this
+/// should be called early during process initialization.
+///
+/// # Safety
+///
+/// `ehdr` must be null, or point to the vDSO mapped into the calling process.
+pub unsafe fn init(ehdr: *const u8) {
+    if ehdr.is_null() {
+        EHDR.store(ABSENT, Ordering::Relaxed);
+    } else {
+        EHDR.store(ehdr as usize, Ordering::Relaxed);
+    }
+}
+
+fn clock_gettime_syscall(clock: Clockid) -> Result<Timespec, Errno> {
+    let mut ts = Timespec::default();
+
+    unsafe {
+        super::raw::syscall2(
+            NR_CLOCK_GETTIME,
+            clock as isize as usize,
+            &mut ts as *mut Timespec as usize,
+        ).to_errno_result()?;
+    }
+
+    Ok(ts)
+}
+
+/// Read Clock
+///
+/// Read the current value of the clock `clock`. This uses the vDSO if
+/// available, and falls back to the `clock_gettime(2)` system call otherwise.
+/// On 32-bit architectures, the y2038-safe `clock_gettime64(2)` variants are
+/// used.
+///
+/// If the clock is not supported, `EINVAL` is returned.
+pub fn clock_gettime(clock: Clockid) -> Result<Timespec, Errno> {
+    match SYM_CLOCK_GETTIME.get() {
+        Some(addr) => {
+            let mut ts = Timespec::default();
+            let r = unsafe {
+                let f: extern "C" fn(Clockid, *mut Timespec) -> i32 =
+                    core::mem::transmute(addr);
+                f(clock, &mut ts)
+            };
+            super::raw::Retval::from_usize(r as isize as usize)
+                .to_errno_result()
+                .map(|_| ts)
+        },
+        None => clock_gettime_syscall(clock),
+    }
+}
+
+fn gettimeofday_syscall() -> Result<Timeval, Errno> {
+    let mut tv = Timeval::default();
+
+    unsafe {
+        super::raw::syscall2(
+            super::arch::native::nr::GETTIMEOFDAY,
+            &mut tv as *mut Timeval as usize,
+            0,
+        ).to_errno_result()?;
+    }
+
+    Ok(tv)
+}
+
+/// Read Wall-Clock Time
+///
+/// Read the current wall-clock time with microsecond resolution. This uses
+/// the vDSO if available, and falls back to the `gettimeofday(2)` system
+/// call otherwise. The obsolete timezone information is not supported.
+pub fn gettimeofday() -> Result<Timeval, Errno> {
+    match SYM_GETTIMEOFDAY.get() {
+        Some(addr) => {
+            let mut tv = Timeval::default();
+            let r = unsafe {
+                let f: extern "C" fn(*mut Timeval, *mut u8) -> i32 =
+                    core::mem::transmute(addr);
+                f(&mut tv, core::ptr::null_mut())
+            };
+            super::raw::Retval::from_usize(r as isize as usize)
+                .to_errno_result()
+                .map(|_| tv)
+        },
+        None => gettimeofday_syscall(),
+    }
+}
+
+/// Read Wall-Clock Seconds
+///
+/// Read the current wall-clock time in seconds since the epoch. This uses the
+/// `time()` entry of the vDSO if available. Otherwise, it falls back to
+/// `clock_gettime()` with `CLOCK_REALTIME`. The `time(2)` system call is never
+/// used, since it is not available on all architectures.
+pub fn time() -> Result<i64, Errno> {
+    match SYM_TIME.get() {
+        Some(addr) => {
+            let t = unsafe {
+                let f: extern "C" fn(*mut KernelLong) -> KernelLong =
+                    core::mem::transmute(addr);
+                f(core::ptr::null_mut())
+            };
+            Ok(t as i64)
+        },
+        None => clock_gettime(CLOCK_REALTIME).map(|v| v.tv_sec),
+    }
+}
+
+fn getcpu_syscall() -> Result<(u32, u32), Errno> {
+    let mut cpu: u32 = 0;
+    let mut node: u32 = 0;
+
+    unsafe {
+        super::raw::syscall3(
+            super::arch::native::nr::GETCPU,
+            &mut cpu as *mut u32 as usize,
+            &mut node as *mut u32 as usize,
+            0,
+        ).to_errno_result()?;
+    }
+
+    Ok((cpu, node))
+}
+
+/// Query Current CPU
+///
+/// Return the CPU and NUMA node the calling thread is currently running on,
+/// as a tuple `(cpu, node)`. This uses the vDSO if available, and falls back
+/// to the `getcpu(2)` system call otherwise.
+///
+/// Note that the information might be outdated by the time this function
+/// returns, unless the CPU affinity of the thread is restricted to a single
+/// CPU.
+pub fn getcpu() -> Result<(u32, u32), Errno> {
+    match SYM_GETCPU.get() {
+        Some(addr) => {
+            let mut cpu: u32 = 0;
+            let mut node: u32 = 0;
+            let r = unsafe {
+                let f: extern "C" fn(*mut u32, *mut u32, *mut u8) -> KernelLong =
+                    core::mem::transmute(addr);
+                f(&mut cpu, &mut node, core::ptr::null_mut())
+            };
+            super::raw::Retval::from_usize(r as isize as usize)
+                .to_errno_result()
+                .map(|_| (cpu, node))
+        },
+        None => getcpu_syscall(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    extern "C" {
+        fn getauxval(t: core::ffi::c_ulong) -> core::ffi::c_ulong;
+    }
+
+    #[test]
+    fn auxv_check() {
+        //
+        // Verify that the vDSO is found via `/proc/self/auxv`, and matches
+        // what the C library got passed by the kernel.
+        //
+
+        let ehdr = auxv_sysinfo_ehdr().unwrap();
+        assert_eq!(ehdr, unsafe { getauxval(33) } as usize);
+        assert!(Vdso::native().is_some());
+    }
+
+    #[test]
+    fn lookup_check() {
+        //
+        // Verify that the native symbols are found in the vDSO, but only
+        // with the right version.
+        //
+
+        let vdso = Vdso::native().unwrap();
+        let name = native::CLOCK_GETTIME.unwrap();
+
+        assert!(vdso.lookup(native::VERSION, name).is_some());
+        assert!(vdso.lookup("LINUX_0.0", name).is_none());
+        assert!(vdso.lookup(native::VERSION, "__vdso_foobar").is_none());
+    }
+
+    #[test]
+    fn clock_gettime_check() {
+        //
+        // Compare the vDSO result with the system call. Monotonic clocks
+        // must never go backwards, so the ordering is strict.
+        //
+
+        let t0 = clock_gettime_syscall(CLOCK_MONOTONIC).unwrap();
+        let t1 = clock_gettime(CLOCK_MONOTONIC).unwrap();
+        let t2 = clock_gettime_syscall(CLOCK_MONOTONIC).unwrap();
+        assert!(t0 <= t1);
+        assert!(t1 <= t2);
+        assert!(t1.tv_nsec >= 0 && t1.tv_nsec < 1_000_000_000);
+
+        assert_eq!(clock_gettime(-1), Err(errno::EINVAL));
+    }
+
+    #[test]
+    fn time_check() {
+        //
+        // Compare all wall-clock accessors with each other. They have
+        // different resolution, so allow for some slack.
+        //
+
+        let t0 = time().unwrap();
+        let t1 = gettimeofday().unwrap();
+        let t2 = gettimeofday_syscall().unwrap();
+        let t3 = clock_gettime(CLOCK_REALTIME).unwrap();
+        assert!(t0 <= t1.tv_sec as i64);
+        assert!(t1 <= t2);
+        assert!(t2.tv_sec as i64 <= t3.tv_sec);
+        assert!(t3.tv_sec - t0 <= 1);
+    }
+
+    #[test]
+    fn getcpu_check() {
+        //
+        // Verify the vDSO and system call both succeed. We cannot compare
+        // them directly, since we might be migrated in between.
+        //
+
+        let (cpu0, _) = getcpu().unwrap();
+        let (cpu1, _) = getcpu_syscall().unwrap();
+        assert!(cpu0 < 65536);
+        assert!(cpu1 < 65536);
+    }
+}
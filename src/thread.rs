@@ -0,0 +1,424 @@
+//! Threads
+//!
+//! This module provides the `Thread` type, which runs a closure in a new
+//! thread of the calling process, on a stack owned by the `Thread` object.
+//! It is built on `clone3(2)` and does not require a C library.
+//!
+//! A task created via `clone3(2)` with a separate stack cannot return into
+//! Rust code, since the stack of the new task does not contain any frames.
+//! Instead, `trampoline()` performs the system call in assembly, and calls an
+//! entry function on the new stack, followed by `exit(2)`.
+//!
+//! Note that new threads share the TLS pointer of the calling thread, unless
+//! `CLONE_SETTLS` is used. Hence, code running in threads created by this
+//! module must not use thread-local storage (including any parts of the
+//! standard library relying on it).
+
+use crate::mmap::Mapping;
+use crate::syscall::{api, errno, errno::Errno};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Size of the guard page(s) at the bottom of thread stacks. This is the
+/// smallest page size on all supported architectures, and the kernel rounds
+/// it up to its page size.
+pub(crate) const GUARD_SIZE: usize = 4096;
+
+/// Clone Task on Separate Stack
+///
+/// Create a new task via `clone3(2)` as described by `args`, and run
+/// `entry(data)` in the new task. Once `entry` returns, the new task exits
+/// via `exit(2)` with an exit-code of 0. The calling task gets the task ID of
+/// the new task.
+///
+/// Before calling `entry`, the frame pointer and return address of the new
+/// task are cleared, to terminate stack unwinding.
+///
+/// # Safety
+///
+/// `args` must describe a valid stack for the new task (i.e., `stack` and
+/// `stack_size` must be set), with its end aligned to 16 bytes. The stack
+/// must stay mapped until the new task exited (e.g., via
+/// `CLONE_CHILD_CLEARTID`). All other addresses in `args` must be valid for
+/// the respective flags.
+///
+/// `entry` must be safe to be called with `data` in the new task. If it
+/// shares the address space with the calling task, any data it accesses
+/// must be synchronized accordingly.
+pub unsafe fn trampoline(
+    args: &api::CloneArgs,
+    entry: unsafe extern "C" fn(*mut u8),
+    data: *mut u8,
+) -> Result<api::Pid, Errno> {
+    use crate::syscall::arch::native::nr;
+
+    let r: usize;
+    let a = args as *const api::CloneArgs as usize;
+    let n = core::mem::size_of::<api::CloneArgs>();
+
+    // The new task continues with the same registers as the calling task,
+    // except for the return value and registers clobbered by the system
+    // call. Hence, we pass `entry`, `data`, and `EXIT` in registers not
+    // touched by the system call. The new task starts with its stack pointer
+    // at the end of the stack, so no stack adjustment is needed beyond what
+    // the calling convention requires.
+
+    // This covers x32 as well, where `usize` and pointers are only 32-bit
+    // wide. Hence, all operands are zero-extended, as done by
+    // `syscall::arch::x32::syscall`, since the upper bits of the registers
+    // are undefined otherwise.
+    #[cfg(target_arch = "x86_64")]
+    {
+        let r64: u64;
+
+        core::arch::asm!(
+            "syscall",
+            "test rax, rax",
+            "jnz 2f",
+            "xor ebp, ebp",
+            "mov rdi, r12",
+            "call r13",
+            "xor edi, edi",
+            "mov rax, r14",
+            "syscall",
+            "ud2",
+            "2:",
+            inlateout("rax") nr::CLONE3 as u64 => r64,
+            in("rdi") a as u64,
+            in("rsi") n as u64,
+            in("r12") data as usize as u64,
+            in("r13") entry as usize as u64,
+            in("r14") nr::EXIT as u64,
+            out("rcx") _,
+            out("r11") _,
+        );
+
+        r = r64 as usize;
+    }
+
+    // `esi` and `ebp` cannot be used as operands, and all other registers
+    // are used. Hence, `EXIT` (1) is hard-coded.
+    #[cfg(target_arch = "x86")]
+    core::arch::asm!(
+        "int $0x80",
+        "test eax, eax",
+        "jnz 2f",
+        "xor ebp, ebp",
+        "sub esp, 12",
+        "push edi",
+        "call edx",
+        "xor ebx, ebx",
+        "mov eax, 1",
+        "int $0x80",
+        "ud2",
+        "2:",
+        inlateout("eax") nr::CLONE3 => r,
+        in("ebx") a,
+        in("ecx") n,
+        in("edx") entry,
+        in("edi") data,
+    );
+
+    // See `syscall::arch::arm::syscall` for the handling of `r7`. The
+    // calling task restores it from `tmp`. The new task instead clears it,
+    // as well as `r11`, since either serves as frame pointer, depending on
+    // whether Thumb or ARM code is generated.
+    #[cfg(target_arch = "arm")]
+    core::arch::asm!(
+        "mov {tmp}, r7",
+        "mov r7, {nr}",
+        "svc #0",
+        "mov r7, {tmp}",
+        "cmp r0, #0",
+        "bne 2f",
+        "mov r7, #0",
+        "mov r11, #0",
+        "mov lr, #0",
+        "mov r0, r5",
+        "blx r4",
+        "mov r0, #0",
+        "mov r7, r8",
+        "svc #0",
+        "udf #0",
+        "2:",
+        nr = in(reg) nr::CLONE3,
+        tmp = out(reg) _,
+        inlateout("r0") a => r,
+        in("r1") n,
+        in("r4") entry,
+        in("r5") data,
+        in("r8") nr::EXIT,
+    );
+
+    #[cfg(target_arch = "aarch64")]
+    core::arch::asm!(
+        "svc #0",
+        "cbnz x0, 2f",
+        "mov x29, #0",
+        "mov x30, #0",
+        "mov x0, x10",
+        "blr x9",
+        "mov x0, #0",
+        "mov x8, x20",
+        "svc #0",
+        "udf #0",
+        "2:",
+        in("x8") nr::CLONE3,
+        inlateout("x0") a => r,
+        in("x1") n,
+        in("x9") entry,
+        in("x10") data,
+        in("x20") nr::EXIT,
+    );
+
+    #[cfg(target_arch = "riscv64")]
+    core::arch::asm!(
+        "ecall",
+        "bnez a0, 2f",
+        "li s0, 0",
+        "li ra, 0",
+        "mv a0, t1",
+        "jalr t0",
+        "li a0, 0",
+        "mv a7, s2",
+        "ecall",
+        "unimp",
+        "2:",
+        in("a7") nr::CLONE3,
+        inlateout("a0") a => r,
+        in("a1") n,
+        in("t0") entry,
+        in("t1") data,
+        in("s2") nr::EXIT,
+    );
+
+    crate::syscall::raw::Retval::from_usize(r)
+        .to_errno_result()
+        .map(|v| api::Pid::try_from(v).unwrap())
+}
+
+// Data placed at the end of the stack of a new thread. `tid` is set to the
+// task ID by the kernel via `CLONE_PARENT_SETTID`, and cleared once the
+// thread exited via `CLONE_CHILD_CLEARTID`.
+struct Header<F> {
+    tid: AtomicU32,
+    f: F,
+}
+
+// Guard that terminates the process if dropped. Unwinding must not cross
+// the `extern "C"` boundary of `entry()`, and there is nothing to unwind
+// into, anyway. Hence, a panic in a thread terminates the entire process
+// with the exit-code used for panics by the standard library.
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        api::exit_group(101);
+    }
+}
+
+unsafe extern "C" fn entry<F: FnOnce()>(data: *mut u8) {
+    let guard = AbortOnUnwind;
+    let f = core::ptr::read(core::ptr::addr_of!((*(data as *mut Header<F>)).f));
+    f();
+    core::mem::forget(guard);
+}
+
+/// Thread
+///
+/// This type owns a thread of the calling process, together with its stack.
+/// Dropping it (or calling `join()`) waits for the thread to exit, and then
+/// releases its stack.
+#[derive(Debug)]
+pub struct Thread {
+    tid: api::Pid,
+    header: *const AtomicU32,
+    _stack: Mapping,
+}
+
+// The thread is only accessed via its atomic `tid` field.
+unsafe impl Send for Thread {}
+unsafe impl Sync for Thread {}
+
+impl Thread {
+    /// Spawn Thread
+    ///
+    /// Create a new thread of the calling process, which runs `f` on a new
+    /// stack of `stack_size` bytes. The stack is preceded by a guard page,
+    /// and it also stores `f`, so it must be large enough to hold it. The
+    /// thread shares everything with the calling thread, except for its stack
+    /// and (with the caveat described in the module documentation) its TLS.
+    ///
+    /// If `f` panics, the entire process is terminated via
+    /// `exit_group(2)`, since the thread has no frames to unwind into.
+    pub fn spawn<F>(stack_size: usize, f: F) -> Result<Self, Errno>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let len = stack_size.checked_add(GUARD_SIZE).ok_or(errno::ENOMEM)?;
+        let stack = Mapping::anonymous(
+            len,
+            api::PROT_READ | api::PROT_WRITE,
+        )?;
+        let base = stack.as_ptr() as usize;
+
+        // Place the header at the end of the stack, and let the stack of the
+        // thread start right below it.
+        let align = core::cmp::max(core::mem::align_of::<Header<F>>(), 16);
+        let header = (base + len)
+            .checked_sub(core::mem::size_of::<Header<F>>())
+            .map(|v| v & !(align - 1))
+            .filter(|v| *v > base + GUARD_SIZE)
+            .ok_or(errno::EINVAL)? as *mut Header<F>;
+
+        unsafe {
+            api::mprotect(stack.as_ptr(), GUARD_SIZE, api::PROT_NONE)?;
+            header.write(Header { tid: AtomicU32::new(0), f });
+        }
+
+        let tid_addr = unsafe { core::ptr::addr_of!((*header).tid) } as u64;
+        let args = api::CloneArgs {
+            flags: api::CLONE_VM
+                | api::CLONE_FS
+                | api::CLONE_FILES
+                | api::CLONE_SIGHAND
+                | api::CLONE_THREAD
+                | api::CLONE_SYSVSEM
+                | api::CLONE_PARENT_SETTID
+                | api::CLONE_CHILD_CLEARTID,
+            child_tid: tid_addr,
+            parent_tid: tid_addr,
+            stack: base as u64,
+            stack_size: (header as usize - base) as u64,
+            ..Default::default()
+        };
+
+        match unsafe { trampoline(&args, entry::<F>, header as *mut u8) } {
+            Ok(tid) => Ok(Self {
+                tid,
+                header: unsafe { core::ptr::addr_of!((*header).tid) },
+                _stack: stack,
+            }),
+            Err(e) => {
+                unsafe { core::ptr::drop_in_place(header) };
+                Err(e)
+            },
+        }
+    }
+
+    /// Return the task ID of this thread.
+    pub fn tid(&self) -> api::Pid {
+        self.tid
+    }
+
+    /// Check whether this thread has exited.
+    pub fn is_finished(&self) -> bool {
+        self.tid_word().load(Ordering::Acquire) == 0
+    }
+
+    /// Wait for this thread to exit, and release its stack.
+    pub fn join(self) {
+        self.wait();
+    }
+
+    fn tid_word(&self) -> &AtomicU32 {
+        unsafe { &*self.header }
+    }
+
+    fn wait(&self) {
+        // The kernel wakes the futex via a shared wake-up, so the wait must
+        // not use `FUTEX_PRIVATE_FLAG`. Spurious wake-ups and `EAGAIN` are
+        // handled by re-checking the value.
+        loop {
+            let v = self.tid_word().load(Ordering::Acquire);
+            if v == 0 {
+                break;
+            }
+            let _ = api::futex_wait(self.tid_word(), v, false);
+        }
+    }
+}
+
+impl Drop for Thread {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn spawn_check() {
+        //
+        // Spawn a set of threads and verify they run concurrently with the
+        // calling thread and each other.
+        //
+
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        static GATE: AtomicU32 = AtomicU32::new(0);
+
+        let mut threads = std::vec::Vec::new();
+        for _ in 0..4 {
+            let r = Thread::spawn(64 * 1024, || {
+                COUNT.fetch_add(1, Ordering::SeqCst);
+                while GATE.load(Ordering::SeqCst) == 0 {
+                    let _ = api::futex_wait(&GATE, 0, true);
+                }
+                COUNT.fetch_add(1, Ordering::SeqCst);
+            });
+
+            match r {
+                // Emulators like qemu-user might lack `clone3(2)`.
+                Err(errno::ENOSYS) => return,
+                v => threads.push(v.unwrap()),
+            }
+        }
+
+        for t in &threads {
+            assert!(t.tid() > 0);
+        }
+        while COUNT.load(Ordering::SeqCst) < 4 {
+            core::hint::spin_loop();
+        }
+        assert!(threads.iter().all(|v| !v.is_finished()));
+
+        GATE.store(1, Ordering::SeqCst);
+        api::futex_wake(&GATE, i32::MAX as u32, true).unwrap();
+
+        for t in threads {
+            t.join();
+        }
+        assert_eq!(COUNT.load(Ordering::SeqCst), 8);
+    }
+
+    #[test]
+    fn capture_check() {
+        //
+        // Verify captures of the closure are moved to the thread and dropped
+        // there, and that closures too large for the stack are rejected.
+        //
+
+        let v = std::sync::Arc::new(AtomicUsize::new(0));
+        let w = v.clone();
+
+        let t = match Thread::spawn(16 * 1024, move || {
+            w.store(71, Ordering::SeqCst);
+        }) {
+            // Emulators like qemu-user might lack `clone3(2)`.
+            Err(errno::ENOSYS) => return,
+            v => v.unwrap(),
+        };
+        t.join();
+
+        assert_eq!(v.load(Ordering::SeqCst), 71);
+        assert_eq!(std::sync::Arc::strong_count(&v), 1);
+
+        let b = [0u8; 8192];
+        assert_eq!(
+            Thread::spawn(4096, move || assert_eq!(b[0], 0)).map(|_| ()),
+            Err(errno::EINVAL),
+        );
+    }
+}